@@ -0,0 +1,79 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use keta::{digit_sum_batch, Keta};
+
+const N: u64 = 1_000_000;
+
+fn bench_digit_sum(c: &mut Criterion) {
+    // 割り算を1桁ずつ繰り返す素朴な実装 (表引き導入前の digit_sum)
+    macro_rules! naive {
+        ($n:expr) => {{
+            let mut n = $n;
+            let mut sum = 0u64;
+            while n > 0 {
+                sum += (n % 10) as u64;
+                n /= 10;
+            }
+            sum
+        }};
+    }
+    let input: Vec<u64> = (0..N)
+        .map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15))
+        .collect();
+    let wide: Vec<u128> = input
+        .iter()
+        .map(|&n| (n as u128) << 64 | n as u128)
+        .collect();
+    let mut group = c.benchmark_group("digit_sum");
+    group.bench_function("u64/loop", |b| {
+        b.iter(|| black_box(&input).iter().map(|&n| naive!(n)).sum::<u64>())
+    });
+    group.bench_function("u64/table", |b| {
+        b.iter(|| black_box(&input).iter().map(|n| n.digit_sum()).sum::<u64>())
+    });
+    group.bench_function("u128/loop", |b| {
+        b.iter(|| black_box(&wide).iter().map(|&n| naive!(n)).sum::<u64>())
+    });
+    group.bench_function("u128/table", |b| {
+        b.iter(|| black_box(&wide).iter().map(|n| n.digit_sum()).sum::<u64>())
+    });
+    group.finish();
+}
+
+fn bench_sum_of_digit_sums(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum_of_digit_sums");
+    group.bench_function("naive", |b| {
+        b.iter(|| (0..black_box(N)).map(Keta::digit_sum).sum::<u64>())
+    });
+    group.bench_function("sum_of_digit_sums", |b| {
+        b.iter(|| u64::sum_of_digit_sums(0..black_box(N)))
+    });
+    group.finish();
+}
+
+fn bench_digit_sum_batch(c: &mut Criterion) {
+    // 大きめの ID を想定して u32 全域に散らばる値を使う
+    let input: Vec<u32> = (0..N as u32)
+        .map(|i| i.wrapping_mul(2_654_435_761))
+        .collect();
+    let mut out = vec![0u64; input.len()];
+    let mut group = c.benchmark_group("digit_sum_batch");
+    group.bench_function("scalar", |b| {
+        b.iter(|| {
+            for (o, &n) in out.iter_mut().zip(black_box(&input)) {
+                *o = n.digit_sum();
+            }
+        })
+    });
+    group.bench_function("digit_sum_batch", |b| {
+        b.iter(|| digit_sum_batch(black_box(&input), &mut out))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_digit_sum,
+    bench_sum_of_digit_sums,
+    bench_digit_sum_batch
+);
+criterion_main!(benches);
@@ -0,0 +1,135 @@
+//! 基数を型パラメータで固定するラッパー `Based<T, B>`
+//!
+//! 同じ基数で何度も `_radix` メソッドを呼ぶ場合に、`base` 引数を書かずに済む。
+//! 各メソッドは `Keta` の `_radix` 版に定数の `B` を渡すだけなので、
+//! インライン展開されれば割り算や `pow` は定数の基数に特殊化される。
+//!
+//! # Example
+//! ```
+//! use keta::based::Based;
+//!
+//! let x = Based::<_, 16>::new(255u32);
+//! assert_eq!(x.digits(), vec![15, 15]);
+//! assert_eq!(x.digit_sum(), 30);
+//! assert_eq!(Based::<u32, 2>::new(6).reverse().get(), 3); // 110 -> 011
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::Keta;
+
+/// 基数 `B` (2..=256) を固定した数値
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Based<T, const B: u32>(T);
+
+impl<T: Keta, const B: u32> Based<T, B> {
+    /// 基数 (コンパイル時に 2..=256 であることを検査する)
+    ///
+    /// ```compile_fail
+    /// use keta::based::Based;
+    /// let _ = Based::<u32, 1>::new(5);
+    /// ```
+    pub const BASE: u32 = {
+        assert!(B >= 2 && B <= 256, "base must be in 2..=256");
+        B
+    };
+
+    /// 値を包む
+    #[inline]
+    pub fn new(value: T) -> Self {
+        let _ = Self::BASE;
+        Based(value)
+    }
+
+    /// 中の値を取り出す
+    #[inline]
+    pub fn get(self) -> T {
+        self.0
+    }
+
+    /// 各桁の数字(u8)のベクタに分解する
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn digits(self) -> Vec<u8> {
+        self.0.digits_radix(Self::BASE)
+    }
+
+    /// 数字の列から数値を復元する
+    #[inline]
+    pub fn from_digits(digits: &[u8]) -> Self {
+        Based(T::from_digits_radix(digits, Self::BASE))
+    }
+
+    /// 各桁の和を計算する
+    #[inline]
+    pub fn digit_sum(self) -> u64 {
+        self.0.digit_sum_radix(Self::BASE)
+    }
+
+    /// 各桁の積を計算する
+    #[inline]
+    pub fn digit_product(self) -> u64 {
+        self.0.digit_product_radix(Self::BASE)
+    }
+
+    /// 桁数を返す
+    #[inline]
+    pub fn digits_len(self) -> u32 {
+        self.0.digits_len_radix(Self::BASE)
+    }
+
+    /// 数値の並びを反転させる
+    #[inline]
+    pub fn reverse(self) -> Self {
+        Based(self.0.reverse_radix(Self::BASE))
+    }
+
+    /// 回文数かどうか判定する
+    #[inline]
+    pub fn is_palindrome(self) -> bool {
+        self.0.is_palindrome_radix(Self::BASE)
+    }
+
+    /// 上からi番目の桁を取得する (0-indexed)
+    #[inline]
+    pub fn nth_digit(self, i: u32) -> Option<u8> {
+        self.0.nth_digit_radix(i, Self::BASE)
+    }
+
+    /// 数値を結合する
+    #[inline]
+    pub fn concat(self, other: Self) -> Self {
+        Based(self.0.concat_radix(other.0, Self::BASE))
+    }
+
+    /// 特定の数字が含まれているか判定する
+    #[inline]
+    pub fn contains_digit(self, digit: u8) -> bool {
+        self.0.contains_digit_radix(digit, Self::BASE)
+    }
+
+    /// 特定の数字の出現回数を数える
+    #[inline]
+    pub fn count_digit(self, digit: u8) -> u32 {
+        self.0.count_digit_radix(digit, Self::BASE)
+    }
+
+    /// 桁を並び替えてできる「最大の数値」を返す
+    #[inline]
+    pub fn make_max(self) -> Self {
+        Based(self.0.make_max_radix(Self::BASE))
+    }
+
+    /// 桁を並び替えてできる「最小の数値」を返す
+    #[inline]
+    pub fn make_min(self) -> Self {
+        Based(self.0.make_min_radix(Self::BASE))
+    }
+}
+
+impl<T: Keta, const B: u32> From<T> for Based<T, B> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,236 @@
+//! 多倍長整数 (`num_bigint::BigUint` / `BigInt`) 向けの桁操作 (`bigint` feature)
+//!
+//! `Keta` は `Copy` を要求するため多倍長整数には実装できない。代わりに
+//! 参照で受け取る `KetaBig` トレイトで、桁の分解・和・反転・回文判定・並び替えを提供する。
+//! 桁の分解は `to_radix_be` を使うので、`u128` に収まらない値でも扱える。
+//!
+//! # Example
+//! ```
+//! use keta::KetaBig;
+//! use num_bigint::BigUint;
+//!
+//! // 2^100 = 1267650600228229401496703205376
+//! let n = BigUint::from(2u32).pow(100);
+//! assert_eq!(n.digit_sum(), 115);
+//! assert_eq!(n.digits_len(), 31);
+//! ```
+
+use alloc::vec::Vec;
+use num_bigint::{BigInt, BigUint, Sign};
+
+pub trait KetaBig: Sized {
+    // ============================================================
+    // 10進数ショートカット
+    // ============================================================
+
+    /// 10進数で各桁の数字(u8)のベクタに分解する (負の数は絶対値)
+    fn digits(&self) -> Vec<u8>;
+
+    /// 数字の列から数値を復元する (10進数)
+    ///
+    /// # Panics
+    /// 9 を超える数字が含まれる場合
+    fn from_digits(digits: &[u8]) -> Self;
+
+    /// 10進数での各桁の和を計算する
+    fn digit_sum(&self) -> u64;
+
+    /// 10進数での桁数を返す
+    fn digits_len(&self) -> u64;
+
+    /// 数値の並びを反転させる (10進数, 符号は維持)
+    fn reverse(&self) -> Self;
+
+    /// 回文数かどうか判定する (10進数)
+    fn is_palindrome(&self) -> bool;
+
+    /// 桁を並び替えてできる「最大の数値」を返す (10進数, 負の数は `Keta::make_max` と同様)
+    fn make_max(&self) -> Self;
+
+    /// 桁を並び替えてできる「最小の数値」を返す (10進数, 負の数は `Keta::make_min` と同様)
+    fn make_min(&self) -> Self;
+
+    // ============================================================
+    // n進数対応 (base は 2..=256)
+    // ============================================================
+
+    /// n進数で各桁の数字(u8)のベクタに分解する
+    fn digits_radix(&self, base: u32) -> Vec<u8>;
+
+    /// n進数の数字列から数値を復元する
+    ///
+    /// # Panics
+    /// `base` 以上の数字が含まれる場合
+    fn from_digits_radix(digits: &[u8], base: u32) -> Self;
+
+    /// n進数での各桁の和を計算する
+    fn digit_sum_radix(&self, base: u32) -> u64;
+
+    /// n進数での桁数を返す
+    fn digits_len_radix(&self, base: u32) -> u64;
+
+    /// 数値の並びを反転させる (n進数)
+    fn reverse_radix(&self, base: u32) -> Self;
+
+    /// 回文数かどうか判定する (n進数)
+    fn is_palindrome_radix(&self, base: u32) -> bool;
+
+    /// 桁を並び替えてできる「最大の数値」を返す (n進数)
+    fn make_max_radix(&self, base: u32) -> Self;
+
+    /// 桁を並び替えてできる「最小の数値」を返す (n進数)
+    fn make_min_radix(&self, base: u32) -> Self;
+}
+
+impl KetaBig for BigUint {
+    // --- 10-base Shortcuts ---
+
+    fn digits(&self) -> Vec<u8> {
+        self.digits_radix(10)
+    }
+
+    fn from_digits(digits: &[u8]) -> Self {
+        Self::from_digits_radix(digits, 10)
+    }
+
+    fn digit_sum(&self) -> u64 {
+        self.digit_sum_radix(10)
+    }
+
+    fn digits_len(&self) -> u64 {
+        self.digits_len_radix(10)
+    }
+
+    fn reverse(&self) -> Self {
+        self.reverse_radix(10)
+    }
+
+    fn is_palindrome(&self) -> bool {
+        self.is_palindrome_radix(10)
+    }
+
+    fn make_max(&self) -> Self {
+        self.make_max_radix(10)
+    }
+
+    fn make_min(&self) -> Self {
+        self.make_min_radix(10)
+    }
+
+    // --- Radix Implementations ---
+
+    fn digits_radix(&self, base: u32) -> Vec<u8> {
+        self.to_radix_be(base)
+    }
+
+    fn from_digits_radix(digits: &[u8], base: u32) -> Self {
+        BigUint::from_radix_be(digits, base).expect("digit out of range for base")
+    }
+
+    fn digit_sum_radix(&self, base: u32) -> u64 {
+        self.to_radix_be(base).iter().map(|&d| d as u64).sum()
+    }
+
+    fn digits_len_radix(&self, base: u32) -> u64 {
+        self.to_radix_be(base).len() as u64
+    }
+
+    fn reverse_radix(&self, base: u32) -> Self {
+        let mut d = self.to_radix_be(base);
+        d.reverse();
+        Self::from_digits_radix(&d, base)
+    }
+
+    fn is_palindrome_radix(&self, base: u32) -> bool {
+        let d = self.to_radix_be(base);
+        d.iter().eq(d.iter().rev())
+    }
+
+    fn make_max_radix(&self, base: u32) -> Self {
+        let mut d = self.to_radix_be(base);
+        d.sort_unstable_by(|a, b| b.cmp(a));
+        Self::from_digits_radix(&d, base)
+    }
+
+    fn make_min_radix(&self, base: u32) -> Self {
+        let mut d = self.to_radix_be(base);
+        d.sort_unstable();
+        Self::from_digits_radix(&d, base)
+    }
+}
+
+impl KetaBig for BigInt {
+    // --- 10-base Shortcuts ---
+
+    fn digits(&self) -> Vec<u8> {
+        self.digits_radix(10)
+    }
+
+    fn from_digits(digits: &[u8]) -> Self {
+        Self::from_digits_radix(digits, 10)
+    }
+
+    fn digit_sum(&self) -> u64 {
+        self.digit_sum_radix(10)
+    }
+
+    fn digits_len(&self) -> u64 {
+        self.digits_len_radix(10)
+    }
+
+    fn reverse(&self) -> Self {
+        self.reverse_radix(10)
+    }
+
+    fn is_palindrome(&self) -> bool {
+        self.is_palindrome_radix(10)
+    }
+
+    fn make_max(&self) -> Self {
+        self.make_max_radix(10)
+    }
+
+    fn make_min(&self) -> Self {
+        self.make_min_radix(10)
+    }
+
+    // --- Radix Implementations (絶対値に委譲) ---
+
+    fn digits_radix(&self, base: u32) -> Vec<u8> {
+        self.magnitude().digits_radix(base)
+    }
+
+    fn from_digits_radix(digits: &[u8], base: u32) -> Self {
+        BigInt::from(BigUint::from_digits_radix(digits, base))
+    }
+
+    fn digit_sum_radix(&self, base: u32) -> u64 {
+        self.magnitude().digit_sum_radix(base)
+    }
+
+    fn digits_len_radix(&self, base: u32) -> u64 {
+        self.magnitude().digits_len_radix(base)
+    }
+
+    fn reverse_radix(&self, base: u32) -> Self {
+        BigInt::from_biguint(self.sign(), self.magnitude().reverse_radix(base))
+    }
+
+    fn is_palindrome_radix(&self, base: u32) -> bool {
+        self.magnitude().is_palindrome_radix(base)
+    }
+
+    fn make_max_radix(&self, base: u32) -> Self {
+        match self.sign() {
+            Sign::Minus => BigInt::from_biguint(Sign::Minus, self.magnitude().make_min_radix(base)),
+            _ => BigInt::from(self.magnitude().make_max_radix(base)),
+        }
+    }
+
+    fn make_min_radix(&self, base: u32) -> Self {
+        match self.sign() {
+            Sign::Minus => BigInt::from_biguint(Sign::Minus, self.magnitude().make_max_radix(base)),
+            _ => BigInt::from(self.magnitude().make_min_radix(base)),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! コンパイル時に評価できる `const fn` 版の桁操作 (10進数, `u64`)
+//!
+//! トレイトのメソッドはまだ `const` にできないため、同じアルゴリズムを
+//! 自由関数として提供する。結果は `Keta` の各メソッドと一致する。
+//!
+//! # Example
+//! ```
+//! use keta::cst::digit_sum_u64;
+//!
+//! const TABLE: [u64; 256] = {
+//!     let mut t = [0; 256];
+//!     let mut i = 0;
+//!     while i < 256 {
+//!         t[i] = digit_sum_u64(i as u64);
+//!         i += 1;
+//!     }
+//!     t
+//! };
+//! assert_eq!(TABLE[199], 19);
+//! ```
+
+/// `Keta::digit_sum` の `const fn` 版
+pub const fn digit_sum_u64(n: u64) -> u64 {
+    let mut n = n;
+    let mut sum = 0;
+    while n > 0 {
+        sum += n % 10;
+        n /= 10;
+    }
+    sum
+}
+
+/// `Keta::digit_product` の `const fn` 版
+pub const fn digit_product_u64(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    let mut n = n;
+    let mut prod = 1;
+    while n > 0 {
+        prod *= n % 10;
+        n /= 10;
+    }
+    prod
+}
+
+/// `Keta::digits_len` の `const fn` 版
+pub const fn digits_len_u64(n: u64) -> u32 {
+    if n == 0 {
+        return 1;
+    }
+    n.ilog10() + 1
+}
+
+/// `Keta::reverse` の `const fn` 版
+pub const fn reverse_u64(n: u64) -> u64 {
+    let mut n = n;
+    let mut ret = 0;
+    while n > 0 {
+        ret = ret * 10 + n % 10;
+        n /= 10;
+    }
+    ret
+}
+
+/// `Keta::is_palindrome` の `const fn` 版
+pub const fn is_palindrome_u64(n: u64) -> bool {
+    n == reverse_u64(n)
+}
@@ -0,0 +1,108 @@
+//! 数字を1桁ずつ積み上げて数値を組み立てるビルダー `Digits`
+//!
+//! `Vec<u8>` を直接操作して `from_digits_radix` に渡す代わりに、数字の検査と
+//! オーバーフローの検査を `Digits` にまとめる。生成器やパーサーで桁を足したり
+//! 取り除いたりしながら数を組み立てるときに使う。
+//!
+//! # Example
+//! ```
+//! use keta::Digits;
+//!
+//! let mut d = Digits::new(10);
+//! d.push(1);
+//! d.push(2);
+//! d.push(3);
+//! assert_eq!(d.build::<u32>(), Some(123));
+//! d.push_front(4);
+//! assert_eq!(d.build::<u32>(), Some(4123));
+//! assert_eq!(d.build::<u8>(), None);
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::Keta;
+
+/// 組み立て途中の数字の列 (上位桁から)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Digits {
+    base: u32,
+    buf: Vec<u8>,
+}
+
+impl Digits {
+    /// 空のビルダーを作る
+    ///
+    /// # Panics
+    /// `base` が `2..=256` の範囲外の場合
+    pub fn new(base: u32) -> Self {
+        assert!(
+            (2..=256).contains(&base),
+            "base must be in 2..=256 (got {})",
+            base
+        );
+        Digits {
+            base,
+            buf: Vec::new(),
+        }
+    }
+
+    /// 組み立てる数の基数
+    pub fn base(&self) -> u32 {
+        self.base
+    }
+
+    /// 積んだ数字の個数
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// 数字を1つも積んでいないか
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// 最下位に数字を追加する
+    ///
+    /// # Panics
+    /// `digit` が `base` 以上の場合
+    pub fn push(&mut self, digit: u8) {
+        self.check(digit);
+        self.buf.push(digit);
+    }
+
+    /// 最上位に数字を追加する
+    ///
+    /// # Panics
+    /// `digit` が `base` 以上の場合
+    pub fn push_front(&mut self, digit: u8) {
+        self.check(digit);
+        self.buf.insert(0, digit);
+    }
+
+    /// 最下位の数字を取り除いて返す (空なら `None`)
+    pub fn pop(&mut self) -> Option<u8> {
+        self.buf.pop()
+    }
+
+    /// 積んだ数字のスライス (上位桁から)
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// 積んだ数字から数値を作る (型の範囲を超える場合は `None`, 空なら 0)
+    ///
+    /// 先頭の 0 は数値としては失われる。
+    pub fn build<T: Keta>(&self) -> Option<T> {
+        T::try_from_digits_radix(&self.buf, self.base)
+    }
+
+    #[track_caller]
+    fn check(&self, digit: u8) {
+        assert!(
+            (digit as u32) < self.base,
+            "digit {} out of range for base {}",
+            digit,
+            self.base
+        );
+    }
+}
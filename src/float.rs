@@ -0,0 +1,163 @@
+//! 浮動小数点数 (`f32` / `f64`) 向けの桁の取り出し (`std` feature)
+//!
+//! 浮動小数点数は整数ではないので `Keta` は実装できない。代わりに `KetaFloat` で、
+//! 整数部分の桁と小数部分の先頭 k 桁を取り出す。負の数は絶対値を使い、
+//! 無限大と NaN は空のベクタを返す。
+//!
+//! # Example
+//! ```
+//! use keta::KetaFloat;
+//!
+//! assert_eq!(3.14159.digits_of_int_part(), vec![3]);
+//! assert_eq!(3.14159.frac_digits(10, 3), vec![1, 4, 1]);
+//! assert_eq!((-0.75f32).frac_digits(2, 4), vec![1, 1, 0, 0]);
+//! ```
+
+use alloc::{string::String, vec::Vec};
+
+use crate::Keta;
+
+pub trait KetaFloat: Copy {
+    // ============================================================
+    // 10進数ショートカット
+    // ============================================================
+
+    /// 整数部分を10進数で各桁の数字(u8)のベクタに分解する (負の数は絶対値, 無限大と NaN は空)
+    ///
+    /// 整数部分は誤差なく分解する (`1e20` は `1` と 20 個の `0`)。
+    fn digits_of_int_part(self) -> Vec<u8>;
+
+    // ============================================================
+    // n進数対応 (base は 2..=256)
+    // ============================================================
+
+    /// 整数部分をn進数で各桁の数字(u8)のベクタに分解する
+    fn digits_of_int_part_radix(self, base: u32) -> Vec<u8>;
+
+    /// 小数部分のn進数での先頭 `k` 桁を返す (負の数は絶対値, 無限大と NaN は空)
+    ///
+    /// 10進数では `{}` で表示したときの小数部 (元の値に戻る最短の表記) を使うので、
+    /// `0.57` は `5, 7, 0, ...` になる。その他の基数では2進数の値を誤差なく展開する。
+    /// どちらも `k` 桁目より後は切り捨てる (四捨五入しない)。
+    fn frac_digits(self, base: u32, k: usize) -> Vec<u8>;
+}
+
+#[track_caller]
+fn valid_base(base: u32) -> u64 {
+    assert!(
+        (2..=256).contains(&base),
+        "base must be in 2..=256 (got {})",
+        base
+    );
+    base as u64
+}
+
+impl KetaFloat for f64 {
+    fn digits_of_int_part(self) -> Vec<u8> {
+        self.digits_of_int_part_radix(10)
+    }
+
+    fn digits_of_int_part_radix(self, base: u32) -> Vec<u8> {
+        let b = valid_base(base);
+        if !self.is_finite() {
+            return Vec::new();
+        }
+        let t = self.abs().trunc();
+        if t < 2f64.powi(128) {
+            return (t as u128).digits_radix(base);
+        }
+        // 2^128 以上は仮数 * 2^指数 を 32 ビットずつの limb (下位から) に展開して割っていく
+        let bits = t.to_bits();
+        let exp = ((bits >> 52) & 0x7ff) as u32 - 1075;
+        let mant = (bits & ((1 << 52) - 1)) | (1 << 52);
+        let mut limbs = alloc::vec![0u32; (exp / 32) as usize];
+        let mut v = (mant as u128) << (exp % 32);
+        while v > 0 {
+            limbs.push(v as u32);
+            v >>= 32;
+        }
+        let mut ret = Vec::new();
+        while !limbs.is_empty() {
+            let mut rem = 0u64;
+            for l in limbs.iter_mut().rev() {
+                let cur = (rem << 32) | *l as u64;
+                *l = (cur / b) as u32;
+                rem = cur % b;
+            }
+            ret.push(rem as u8);
+            while limbs.last() == Some(&0) {
+                limbs.pop();
+            }
+        }
+        ret.reverse();
+        ret
+    }
+
+    fn frac_digits(self, base: u32, k: usize) -> Vec<u8> {
+        let b = valid_base(base);
+        if !self.is_finite() {
+            return Vec::new();
+        }
+        if b == 10 {
+            return shortest_frac_digits(alloc::format!("{}", self.abs()), k);
+        }
+        // 小数部分 = 仮数 / 2^s を 32 ビットずつの limb (下位から) で持ち、base 倍して
+        // 2^s 以上にはみ出した部分を1桁ずつ取り出す
+        let bits = self.abs().fract().to_bits();
+        let field = ((bits >> 52) & 0x7ff) as usize;
+        let mant = bits & ((1 << 52) - 1);
+        let (mant, s) = if field == 0 {
+            (mant, 1074)
+        } else {
+            (mant | (1 << 52), 1075 - field)
+        };
+        let (w, o) = (s / 32, s % 32);
+        // base <= 256 なので積は 2^(s+8) 未満に収まり、limb w と w+1 だけにはみ出す
+        let mut limbs = alloc::vec![0u32; w + 2];
+        limbs[0] = mant as u32;
+        limbs[1] = (mant >> 32) as u32;
+        (0..k)
+            .map(|_| {
+                let mut carry = 0u64;
+                for l in limbs.iter_mut() {
+                    let v = *l as u64 * b + carry;
+                    *l = v as u32;
+                    carry = v >> 32;
+                }
+                let d = (((limbs[w + 1] as u64) << 32) | limbs[w] as u64) >> o;
+                limbs[w] &= ((1u64 << o) - 1) as u32;
+                limbs[w + 1] = 0;
+                d as u8
+            })
+            .collect()
+    }
+}
+
+// 10進数の小数部を `{}` の表記 (指数表記にはならない) から k 桁取り出す (足りない分は 0)
+fn shortest_frac_digits(s: String, k: usize) -> Vec<u8> {
+    let frac = s.split_once('.').map_or("", |(_, f)| f);
+    frac.bytes()
+        .map(|c| c - b'0')
+        .chain(core::iter::repeat(0))
+        .take(k)
+        .collect()
+}
+
+// f32 -> f64 の変換は誤差がないので f64 に委譲する
+impl KetaFloat for f32 {
+    fn digits_of_int_part(self) -> Vec<u8> {
+        (self as f64).digits_of_int_part()
+    }
+
+    fn digits_of_int_part_radix(self, base: u32) -> Vec<u8> {
+        (self as f64).digits_of_int_part_radix(base)
+    }
+
+    fn frac_digits(self, base: u32, k: usize) -> Vec<u8> {
+        // 最短の表記は f32 としての値で求める (f64 に広げると 0.3 が 0.30000001... になる)
+        if valid_base(base) == 10 && self.is_finite() {
+            return shortest_frac_digits(alloc::format!("{}", self.abs()), k);
+        }
+        (self as f64).frac_digits(base, k)
+    }
+}
@@ -1,23 +1,81 @@
 #![doc = include_str!("../README.md")]
 #![allow(clippy::needless_doctest_main)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::VecDeque, string::String, vec, vec::Vec};
+use core::cmp::Ordering;
+
+pub mod based;
+pub mod cst;
+pub mod ops;
+
+mod nonzero;
+pub use nonzero::KetaNonZero;
+
+mod sum;
+pub use sum::DigitSum;
+
+#[cfg(feature = "alloc")]
+mod view;
+#[cfg(feature = "alloc")]
+pub use view::DigitView;
+
+#[cfg(feature = "alloc")]
+mod digits;
+#[cfg(feature = "alloc")]
+pub use digits::Digits;
+
+#[cfg(feature = "bigint")]
+mod bigint;
+#[cfg(feature = "bigint")]
+pub use bigint::KetaBig;
+
+#[cfg(feature = "std")]
+mod float;
+#[cfg(feature = "std")]
+pub use float::KetaFloat;
 
 pub trait Keta: Copy {
     // ============================================================
     // 10進数ショートカット
     // ============================================================
 
+    /// 10進数で表したときの最大の桁数 (符号を除く)
+    ///
+    /// 桁を取り出すための固定長バッファの大きさに使える。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(u8::MAX_DIGITS, 3);
+    /// assert_eq!(u64::MAX_DIGITS, 20);
+    /// let buf = [0u8; i32::MAX_DIGITS as usize];
+    /// assert_eq!(buf.len(), 10);
+    /// ```
+    const MAX_DIGITS: u32;
+
     /// 10進数で各桁の数字(u8)のベクタに分解する
     ///
+    /// 負の数は絶対値を分解し、符号は失われる。符号も必要なら `signed_digits` を使う。
+    ///
     /// # Example
     /// ```
     /// use keta::Keta;
     /// assert_eq!(12345.digits(), vec![1, 2, 3, 4, 5]);
     /// assert_eq!((-12345).digits(), vec![1, 2, 3, 4, 5]); // 負の数も絶対値で分解
     /// ```
+    #[cfg(feature = "alloc")]
     fn digits(self) -> Vec<u8>;
 
     /// 数字の列から数値を復元する (10進数)
     ///
+    /// # Panics
+    /// 10 以上の数字を含む場合、または結果が型の範囲を超える場合 (`try_from_digits` を使う)
+    ///
     /// # Example
     /// ```
     /// use keta::Keta;
@@ -37,6 +95,8 @@ pub trait Keta: Copy {
 
     /// 10進数での各桁の積を計算する
     ///
+    /// 積が `u64` を超えうる大きな数には `checked_digit_product` を使う。
+    ///
     /// # Example
     /// ```
     /// use keta::Keta;
@@ -56,6 +116,9 @@ pub trait Keta: Copy {
 
     /// 数値の並びを反転させる (10進数)
     ///
+    /// 結果が型の範囲を超える場合、デバッグビルドではパニックし、リリースビルドでは折り返す。
+    /// 常に同じ挙動が必要なら `checked_reverse` / `saturating_reverse` / `wrapping_reverse` を使う。
+    ///
     /// # Example
     /// ```
     /// use keta::Keta;
@@ -87,6 +150,11 @@ pub trait Keta: Copy {
 
     /// 数値を結合する (10進数)
     ///
+    /// 範囲外を `None` にするなら `checked_concat`、折り返すなら `wrapping_concat` を使う。
+    ///
+    /// # Panics
+    /// 結果が型の範囲を超える場合 (リリースビルドでも折り返さない)
+    ///
     /// # Example
     /// ```
     /// use keta::Keta;
@@ -106,560 +174,5986 @@ pub trait Keta: Copy {
 
     /// 桁を並び替えてできる「最大の数値」を返す (10進数)
     ///
+    /// 負の数は符号を保ったまま、絶対値が最小になる並び (最も 0 に近い値) を返す。
+    /// 結果が型の範囲を超える場合、デバッグビルドではパニックし、リリースビルドでは折り返す。
+    /// 常に同じ挙動が必要なら `checked_make_max` / `wrapping_make_max` を使う。
+    ///
     /// # Example
     /// ```
     /// use keta::Keta;
     /// assert_eq!(2026.make_max(), 6220);
+    /// assert_eq!((-2026).make_max(), -226);
     /// ```
     fn make_max(self) -> Self;
 
     /// 桁を並び替えてできる「最小の数値」を返す (10進数)
     ///
+    /// 負の数は符号を保ったまま、絶対値が最大になる並びを返す。
+    /// 先頭に来た 0 は数値にすると消えるので、並びそのものが欲しい場合は `sort_digits` を使う。
+    ///
     /// # Example
     /// ```
     /// use keta::Keta;
     /// assert_eq!(2026.make_min(), 226); // 0226 -> 226
+    /// assert_eq!((-2026).make_min(), -6220);
     /// ```
     fn make_min(self) -> Self;
 
-    // ============================================================
-    // n進数対応
-    // ============================================================
+    /// 最下位桁から順に符号パターンを繰り返し適用して各桁の和を計算する (10進数)
+    ///
+    /// `signs[0]` が一の位、`signs[1]` が十の位…に対応し、桁数が `signs` より
+    /// 長い場合はパターンを先頭から繰り返す。`signs` が空なら `0` を返す。
+    /// 負の数は絶対値で計算する。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// // [1] は通常の桁和 (3, 9 の倍数判定)
+    /// assert_eq!(123.signed_digit_sum_pattern(&[1]), 6);
+    /// // [1, -1] は交代和 (11 の倍数判定): 3 - 2 + 1
+    /// assert_eq!(123.signed_digit_sum_pattern(&[1, -1]), 2);
+    /// assert_eq!(1331.signed_digit_sum_pattern(&[1, -1]) % 11, 0);
+    /// ```
+    fn signed_digit_sum_pattern(self, signs: &[i8]) -> i64;
 
-    /// n進数で各桁の数字(u8)のベクタに分解する
-    fn digits_radix(self, base: u32) -> Vec<u8>;
+    /// 指定した数字(0-9)が何回現れるかを数える (10進数)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(1_717_171.count_digit(7), 3);
+    /// assert_eq!(0.count_digit(0), 1);
+    /// ```
+    fn count_digit(self, digit: u8) -> u32;
 
-    /// n進数の数字列から数値を復元する
-    fn from_digits_radix(digits: &[u8], base: u32) -> Self;
+    /// 各桁を電話のキーパッドの文字グループに変換する (10進数)
+    ///
+    /// `2 -> "abc"`, `3 -> "def"`, ..., `7 -> "pqrs"`, `9 -> "wxyz"` と対応し、
+    /// `0` は空白 `" "`、`1` は文字を持たないため空文字列 `""` になる。
+    /// 負の数は絶対値で変換する。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(207.to_phone_letters(), vec!["abc", " ", "pqrs"]);
+    /// assert_eq!(1.to_phone_letters(), vec![""]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_phone_letters(self) -> Vec<&'static str>;
 
-    /// n進数での各桁の和を計算する
-    fn digit_sum_radix(self, base: u32) -> u64;
+    /// 電話のキーパッドで表せる文字列の組み合わせをすべて列挙する (10進数)
+    ///
+    /// 上位桁から順に `to_phone_letters` の各グループから1文字ずつ選んだ文字列を、
+    /// 辞書順に遅延生成する。`1` は文字を持たないので読み飛ばされ、`0` は空白になる。
+    /// すべての桁が `1` の場合は空文字列を1つだけ返す。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// let v: Vec<String> = 23u32.phone_letter_combinations().collect();
+    /// assert_eq!(v, ["ad", "ae", "af", "bd", "be", "bf", "cd", "ce", "cf"]);
+    /// assert_eq!(213u32.phone_letter_combinations().count(), 9);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn phone_letter_combinations(self) -> impl Iterator<Item = String>;
 
-    /// n進数での各桁の積を計算する
-    fn digit_product_radix(self, base: u32) -> u64;
+    /// 各数字(0-9)の出現回数を数える (10進数)
+    ///
+    /// 添字が数字、値がその出現回数を表す。負の数は絶対値で数える。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(2026.digit_counts(), [1, 0, 2, 0, 0, 0, 1, 0, 0, 0]);
+    /// assert_eq!(0.digit_counts(), [1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    /// ```
+    fn digit_counts(self) -> [u32; 10];
 
-    /// n進数での桁数を返す
-    fn digits_len_radix(self, base: u32) -> u32;
+    /// 指定した数字(0-9)が現れる位置をすべて返す (10進数, 上位桁から 0-indexed)
+    ///
+    /// 位置は昇順に並ぶ。数字が含まれない場合は空のベクタを返す。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(12121.digit_positions(1), vec![0, 2, 4]);
+    /// assert_eq!(12121.digit_positions(9), vec![]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn digit_positions(self, digit: u8) -> Vec<u32>;
 
-    /// 数値の並びを反転させる (n進数)
-    fn reverse_radix(self, base: u32) -> Self;
+    /// 0-9 の各数字をちょうど1回ずつ使っているか判定する (10進数)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(1_234_567_890u64.is_pandigital());
+    /// assert!(!123_456_789.is_pandigital()); // 0 がない
+    /// ```
+    fn is_pandigital(self) -> bool;
 
-    /// 回文数かどうか判定する (n進数)
-    fn is_palindrome_radix(self, base: u32) -> bool;
+    /// 桁数を n として、1-n の各数字をちょうど1回ずつ使っているか判定する (10進数)
+    ///
+    /// `0` を含む数は常に `false` になる。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(2143.is_pandigital_1_to_n());
+    /// assert!(!2144.is_pandigital_1_to_n());
+    /// assert!(!1023.is_pandigital_1_to_n());
+    /// ```
+    fn is_pandigital_1_to_n(self) -> bool;
 
-    /// 上からi番目の桁を取得する (n進数, 0-indexed)
-    fn nth_digit_radix(self, i: u32, base: u32) -> Option<u8>;
+    /// 桁を左に巡回シフトする (10進数)
+    ///
+    /// 上位 `n` 桁を下位側へ移動する。`n` は桁数で割った余りが使われる。
+    /// 先頭に 0 が来た場合はその分だけ桁数が減った数値を返す。符号は維持。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(1234.rotate_digits_left(1), 2341);
+    /// assert_eq!(1234.rotate_digits_left(5), 2341); // 5 % 4 = 1
+    /// assert_eq!(100.rotate_digits_left(1), 1); // 001 -> 1
+    /// assert_eq!((-123).rotate_digits_left(1), -231);
+    /// ```
+    ///
+    /// # Panics
+    /// 結果が型の範囲を超える場合 (`129u8.rotate_digits_left(1)` は 291)
+    fn rotate_digits_left(self, n: u32) -> Self;
 
-    /// 数値を結合する (n進数)
-    fn concat_radix(self, other: Self, base: u32) -> Self;
+    /// 桁を右に巡回シフトする (10進数)
+    ///
+    /// 下位 `n` 桁を上位側へ移動する。それ以外の扱いは `rotate_digits_left` と同じ。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(1234.rotate_digits_right(1), 4123);
+    /// assert_eq!(120.rotate_digits_right(1), 12); // 012 -> 12
+    /// ```
+    fn rotate_digits_right(self, n: u32) -> Self;
 
-    /// 指定した数字が含まれているか判定する (n進数)
-    fn contains_digit_radix(self, digit: u8, base: u32) -> bool;
+    /// 桁を左に巡回シフトする (10進数, 型の範囲を超える場合は `None`)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(123u8.checked_rotate_digits_left(1), Some(231));
+    /// assert_eq!(129u8.checked_rotate_digits_left(1), None); // 291
+    /// ```
+    fn checked_rotate_digits_left(self, n: u32) -> Option<Self>;
 
-    /// 桁を並び替えてできる「最大の数値」を返す (n進数)
-    fn make_max_radix(self, base: u32) -> Self;
+    /// 桁を右に巡回シフトする (10進数, 型の範囲を超える場合は `None`)
+    fn checked_rotate_digits_right(self, n: u32) -> Option<Self>;
 
-    /// 桁を並び替えてできる「最小の数値」を返す (n進数)
-    fn make_min_radix(self, base: u32) -> Self;
-}
+    /// 10進数での各桁の2乗の和を計算する
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(123.digit_square_sum(), 14); // 1 + 4 + 9
+    /// ```
+    fn digit_square_sum(self) -> u64;
 
-// ----------------------------------------------------------------
-// 実装用マクロ (符号なし整数用: u32, u64...)
-// ----------------------------------------------------------------
-macro_rules! impl_keta_uint {
-    ($($t:ty),*) => {
-        $(
-            impl Keta for $t {
-                // --- 10-base Shortcuts (Optimized) ---
+    /// ハッピー数かどうか判定する (10進数)
+    ///
+    /// 各桁の2乗の和で置き換える操作を繰り返して 1 に到達するなら `true`。
+    /// 負の数は絶対値で判定し、`0` はハッピー数ではない。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(7.is_happy()); // 7 -> 49 -> 97 -> 130 -> 10 -> 1
+    /// assert!(!4.is_happy()); // 4 -> 16 -> 37 -> ... -> 4
+    /// ```
+    fn is_happy(self) -> bool;
 
-                fn digits(self) -> Vec<u8> {
-                    if self == 0 { return vec![0]; }
-                    let mut n = self;
-                    // ilog10で正確な容量を計算 (再アロケーション防止)
-                    let cap = (self.ilog10() + 1) as usize;
-                    let mut ret = Vec::with_capacity(cap);
-                    while n > 0 {
-                        ret.push((n % 10) as u8);
-                        n /= 10;
-                    }
-                    ret.reverse();
-                    ret
-                }
+    /// アームストロング数 (ナルシシスト数) かどうか判定する (10進数)
+    ///
+    /// 各桁を桁数乗した和が元の数と等しければ `true`。
+    /// 和は `u128` で計算し、元の数を超えた時点で打ち切る。負の数は絶対値で判定。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(153.is_armstrong()); // 1^3 + 5^3 + 3^3
+    /// assert!(!154.is_armstrong());
+    /// ```
+    fn is_armstrong(self) -> bool;
 
-                fn from_digits(digits: &[u8]) -> Self {
-                    let mut ret: $t = 0;
-                    for &d in digits {
-                        ret = ret * 10 + (d as $t);
-                    }
-                    ret
-                }
+    /// ハーシャッド数 (ニーヴン数) かどうか判定する (10進数)
+    ///
+    /// 各桁の和で割り切れれば `true`。桁和が 0 になる `0` はハーシャッド数ではない。
+    /// 負の数は絶対値で判定する。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(18.is_harshad()); // 18 % (1 + 8) == 0
+    /// assert!(!19.is_harshad());
+    /// assert!(!0.is_harshad());
+    /// ```
+    fn is_harshad(self) -> bool;
 
-                fn digit_sum(self) -> u64 {
-                    let mut n = self;
-                    let mut sum: u64 = 0;
-                    while n > 0 {
-                        sum += (n % 10) as u64;
-                        n /= 10;
-                    }
-                    sum
-                }
+    /// 数字の列から数値を復元する (10進数, 検査付き)
+    ///
+    /// 9 を超える数字が含まれる場合や、型の範囲を超える場合は `None` を返す。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(u8::try_from_digits(&[2, 5, 5]), Some(255));
+    /// assert_eq!(u8::try_from_digits(&[2, 5, 6]), None); // オーバーフロー
+    /// assert_eq!(u8::try_from_digits(&[1, 10]), None); // 範囲外の数字
+    /// ```
+    fn try_from_digits(digits: &[u8]) -> Option<Self>;
 
-                fn digit_product(self) -> u64 {
-                    if self == 0 { return 0; }
-                    let mut n = self;
-                    let mut prod: u64 = 1;
-                    while n > 0 {
-                        prod *= (n % 10) as u64;
-                        n /= 10;
-                    }
-                    prod
-                }
+    /// 加法的持続性 (1桁になるまで `digit_sum` を適用する回数) を返す (10進数)
+    ///
+    /// 1桁の数は `0`。負の数は絶対値で計算する。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(199.additive_persistence(), 3); // 199 -> 19 -> 10 -> 1
+    /// assert_eq!(7.additive_persistence(), 0);
+    /// ```
+    fn additive_persistence(self) -> u32;
 
-                fn digits_len(self) -> u32 {
-                    if self == 0 { return 1; }
-                    self.ilog10() + 1
-                }
+    /// 乗法的持続性 (1桁になるまで `digit_product` を適用する回数) を返す (10進数)
+    ///
+    /// 1桁の数は `0`。負の数は絶対値で計算する。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(39.multiplicative_persistence(), 3); // 39 -> 27 -> 14 -> 4
+    /// assert_eq!(7.multiplicative_persistence(), 0);
+    /// ```
+    fn multiplicative_persistence(self) -> u32;
 
-                fn reverse(self) -> Self {
-                    let mut n = self;
-                    let mut ret: $t = 0;
-                    while n > 0 {
-                        ret = ret * 10 + (n % 10);
-                        n /= 10;
-                    }
-                    ret
-                }
+    /// 上から `start` 桁目 (0-indexed) から `len` 桁を取り出した数値を返す (10進数)
+    ///
+    /// 範囲が桁数を超える部分は切り詰め、`start` が桁数以上なら `0` を返す。
+    /// 取り出した桁の先頭の 0 は数値として消える。符号は維持。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(123456.digit_slice(1, 3), 234);
+    /// assert_eq!(123456.digit_slice(4, 10), 56); // 切り詰め
+    /// assert_eq!(10023.digit_slice(1, 3), 2); // 002 -> 2
+    /// assert_eq!(123.digit_slice(5, 1), 0);
+    /// ```
+    fn digit_slice(self, start: u32, len: u32) -> Self;
 
-                fn is_palindrome(self) -> bool {
-                    self == self.reverse()
-                }
+    /// 上から `i` 桁目 (0-indexed) を `digit` に置き換えた数値を返す (10進数)
+    ///
+    /// 先頭の桁を 0 に置き換えた場合は、その分だけ桁数が減った数値になる。符号は維持。
+    ///
+    /// # Panics
+    /// `digit` が 10 以上の場合、`i` が桁数以上の場合、または結果が型の範囲を超える場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(12345.with_digit(2, 9), 12945);
+    /// assert_eq!(12345.with_digit(0, 0), 2345); // 02345 -> 2345
+    /// ```
+    fn with_digit(self, i: u32, digit: u8) -> Self;
 
-                fn nth_digit(self, i: u32) -> Option<u8> {
-                    let l = self.digits_len();
-                    if i >= l { return None; }
-                    let pow = l - 1 - i;
-                    Some(((self / (10 as $t).pow(pow)) % 10) as u8)
-                }
+    /// 上から `i` 桁目 (0-indexed) を `digit` に置き換えた数値を返す (10進数, 型の範囲を超える場合は `None`)
+    ///
+    /// # Panics
+    /// `digit` が 10 以上の場合、または `i` が桁数以上の場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(255u8.checked_with_digit(0, 1), Some(155));
+    /// assert_eq!(255u8.checked_with_digit(0, 9), None); // 955
+    /// ```
+    fn checked_with_digit(self, i: u32, digit: u8) -> Option<Self>;
 
-                fn concat(self, other: Self) -> Self {
-                    let shift = other.digits_len();
-                    self * (10 as $t).pow(shift) + other
-                }
+    /// Luhn アルゴリズムで検査数字が正しいか判定する (10進数)
+    ///
+    /// 一の位を検査数字とみなし、右から偶数番目の桁を2倍 (9 を超えたら 9 を引く)
+    /// して合計したものが 10 の倍数なら `true`。負の数は絶対値で判定する。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(79_927_398_713u64.luhn_is_valid());
+    /// assert!(!79_927_398_714u64.luhn_is_valid());
+    /// ```
+    fn luhn_is_valid(self) -> bool;
 
-                fn contains_digit(self, digit: u8) -> bool {
-                    if self == 0 { return digit == 0; }
-                    let mut n = self;
-                    while n > 0 {
-                        if (n % 10) as u8 == digit {
-                            return true;
-                        }
+    /// 末尾に付け足すと Luhn アルゴリズムで正しくなる検査数字を返す (10進数)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(7_992_739_871u64.luhn_check_digit(), 3);
+    /// ```
+    fn luhn_check_digit(self) -> u8;
+
+    /// 自己同形数 (2乗の末尾が自分自身になる数) かどうか判定する (10進数)
+    ///
+    /// 負の数は絶対値で判定する。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(76.is_automorphic()); // 76^2 = 5776
+    /// assert!(!7.is_automorphic()); // 7^2 = 49
+    /// ```
+    fn is_automorphic(self) -> bool;
+
+    /// 下からi番目の桁を取得する (10進数, 0-indexed, 一の位が 0)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(12345.nth_digit_from_right(0), Some(5));
+    /// assert_eq!(12345.nth_digit_from_right(4), Some(1));
+    /// assert_eq!(12345.nth_digit_from_right(5), None);
+    /// ```
+    fn nth_digit_from_right(self, i: u32) -> Option<u8>;
+
+    /// 最大の桁の数字を返す (10進数)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(2026.max_digit(), 6);
+    /// ```
+    fn max_digit(self) -> u8;
+
+    /// 最小の桁の数字を返す (10進数)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(2026.min_digit(), 0);
+    /// assert_eq!(987.min_digit(), 7);
+    /// ```
+    fn min_digit(self) -> u8;
+
+    /// 使われている数字の種類数を返す (10進数)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(112233.distinct_digit_count(), 3);
+    /// assert_eq!(7777.distinct_digit_count(), 1);
+    /// ```
+    fn distinct_digit_count(self) -> u32;
+
+    /// 全ての桁が同じ数字 (ゾロ目) か判定する (10進数)
+    ///
+    /// 1桁の数 (`0` を含む) は常に `true`。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(555.is_repdigit());
+    /// assert!(!12321.is_repdigit());
+    /// ```
+    fn is_repdigit(self) -> bool;
+
+    /// 全ての桁が 1 (レピュニット) か判定する (10進数)
+    ///
+    /// `0` は `false`。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(1111.is_repunit());
+    /// assert!(!2222.is_repunit());
+    /// ```
+    fn is_repunit(self) -> bool;
+
+    /// `self` より大きい最小の回文数を返す (10進数)
+    ///
+    /// 左半分を右半分に写し、足りなければ中央に 1 を足して繰り上げることで構成する。
+    /// 負の数では、絶対値が `|self|` より小さい最大の回文数に符号を付けた値になる。
+    ///
+    /// # Panics
+    /// 結果が型の範囲を超える場合 (`checked_next_palindrome` を参照)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(123.next_palindrome(), 131);
+    /// assert_eq!(999.next_palindrome(), 1001);
+    /// assert_eq!((-123).next_palindrome(), -121);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn next_palindrome(self) -> Self;
+
+    /// `self` より大きい最小の回文数を返す (10進数, 型の範囲を超える場合は `None`)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(200u8.checked_next_palindrome(), Some(202));
+    /// assert_eq!(252u8.checked_next_palindrome(), None); // 262 は u8 に収まらない
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn checked_next_palindrome(self) -> Option<Self>;
+
+    /// 10進数での各桁の `exp` 乗の和を計算する
+    ///
+    /// 和は `u128` で計算し、それでも収まらない場合は `u128::MAX` で飽和する。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(123.digit_power_sum(2), 14); // 1 + 4 + 9
+    /// assert_eq!(153.digit_power_sum(3), 153);
+    /// ```
+    fn digit_power_sum(self, exp: u32) -> u128;
+
+    /// 数値の並びを反転させる (10進数, 型の範囲を超える場合は `None`)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(123i32.checked_reverse(), Some(321));
+    /// assert_eq!(1_534_236_469i32.checked_reverse(), None); // 9646324351 > i32::MAX
+    /// ```
+    fn checked_reverse(self) -> Option<Self>;
+
+    /// 数値の並びを反転させる (10進数, 型の範囲を超える場合は `MAX` / `MIN` で飽和)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(1_534_236_469i32.saturating_reverse(), i32::MAX);
+    /// assert_eq!((-1_534_236_469i32).saturating_reverse(), i32::MIN);
+    /// ```
+    fn saturating_reverse(self) -> Self;
+
+    /// 数値の並びを反転させる (10進数, 型の範囲を超える場合は折り返す)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(199u8.wrapping_reverse(), (991 % 256) as u8);
+    /// ```
+    fn wrapping_reverse(self) -> Self;
+
+    /// 10進数での各桁の積を計算する (`u64` に収まらない場合は `None`)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(1234u32.checked_digit_product(), Some(24));
+    /// // 9^22 は u64 に収まらない
+    /// assert_eq!(9_999_999_999_999_999_999_999u128.checked_digit_product(), None);
+    /// ```
+    fn checked_digit_product(self) -> Option<u64>;
+
+    /// 10進数で `width` 桁にゼロ埋めした各桁の数字のベクタを返す (負の数は絶対値)
+    ///
+    /// # Panics
+    /// 桁数が `width` を超える場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(5.digits_padded(3), vec![0, 0, 5]);
+    /// assert_eq!(20240101.digits_padded(8), vec![2, 0, 2, 4, 0, 1, 0, 1]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn digits_padded(self, width: usize) -> Vec<u8>;
+
+    /// 2つの数値の桁を `self` の上位桁から交互に並べる (10進数, 符号は `concat` と同様に `self` に従う)
+    ///
+    /// 桁数が異なる場合は、長い方の残りの桁を末尾に続ける。
+    ///
+    /// # Panics
+    /// 結果が型の範囲を超える場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(123.interleave(456), 142536);
+    /// assert_eq!(12.interleave(3456), 132456);
+    /// ```
+    fn interleave(self, other: Self) -> Self;
+
+    /// 2つの数値の桁を交互に並べる (10進数, 型の範囲を超える場合は `None`)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(12u8.interleave(3), 132);
+    /// assert_eq!(12u8.checked_interleave(34), None); // 1324 > u8::MAX
+    /// ```
+    fn checked_interleave(self, other: Self) -> Option<Self>;
+
+    /// 上から `i` 桁とそれ以降の2つの数値に分割する (10進数, `concat` の逆, 符号は両方に残す)
+    ///
+    /// 後半の先頭に続く 0 は数値になった時点で失われる (`10045` を 2 桁で分けると `(10, 45)`)。
+    /// `i == 0` なら `(0, self)`、`i` が桁数以上なら `(self, 0)` を返す。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(12345.split_at(2), (12, 345));
+    /// assert_eq!(10045.split_at(2), (10, 45));
+    /// ```
+    fn split_at(self, i: u32) -> (Self, Self);
+
+    /// 指定した数字をすべて取り除いた数値を返す (10進数, 符号は維持)
+    ///
+    /// すべての桁が取り除かれた場合は 0 を返す。
+    ///
+    /// # Panics
+    /// `digit` が 10 以上の場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(102030.remove_digit(0), 123);
+    /// assert_eq!((-1213).remove_digit(1), -23);
+    /// ```
+    fn remove_digit(self, digit: u8) -> Self;
+
+    /// 平衡3進数 (各桁が -1, 0, 1) の桁のベクタに変換する (上位桁から)
+    ///
+    /// 負の数もそのまま表せる (最上位の桁が -1 になる)。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(8.to_balanced_ternary(), vec![1, 0, -1]); // 9 - 1
+    /// assert_eq!((-8).to_balanced_ternary(), vec![-1, 0, 1]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_balanced_ternary(self) -> Vec<i8>;
+
+    /// 平衡3進数の桁の列から数値を復元する
+    ///
+    /// # Panics
+    /// -1, 0, 1 以外の桁が含まれる場合、または値が型の範囲外の場合 (符号なし整数で負になる場合を含む)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(i32::from_balanced_ternary(&[1, 0, -1]), 8);
+    /// ```
+    fn from_balanced_ternary(digits: &[i8]) -> Self;
+
+    /// 負の基数 (-2 なら negabinary) で各桁の数字のベクタに分解する (上位桁から)
+    ///
+    /// 負の基数では符号を使わずに負の数も表せるため、符号付き整数も数字列だけで往復できる。
+    ///
+    /// # Panics
+    /// `base` が `-256..=-2` の範囲外の場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!((-2).digits_negative_base(-2), vec![1, 0]); // (-2)^1
+    /// assert_eq!(2.digits_negative_base(-2), vec![1, 1, 0]); // 4 - 2
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn digits_negative_base(self, base: i32) -> Vec<u8>;
+
+    /// 負の基数の数字列から数値を復元する
+    ///
+    /// # Panics
+    /// `base` が `-256..=-2` の範囲外の場合、`|base|` 以上の数字が含まれる場合、
+    /// または値が型の範囲外の場合 (符号なし整数で負になる場合を含む)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(i32::from_digits_negative_base(&[1, 0], -2), -2);
+    /// ```
+    fn from_digits_negative_base(digits: &[u8], base: i32) -> Self;
+
+    /// カプレカ操作を1回行う (10進数, 負の数は絶対値)
+    ///
+    /// 4 桁に満たない数は 0 で埋めてから、桁を降順に並べた数と昇順に並べた数の差を返す
+    /// (`999` なら `9990 - 0999`)。5 桁以上の数はその桁数のまま計算する。
+    ///
+    /// # Panics
+    /// 結果が型の範囲を超える場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(3524.kaprekar_step(), 3087); // 5432 - 2345
+    /// assert_eq!(999.kaprekar_step(), 8991); // 9990 - 0999
+    /// assert_eq!(6174.kaprekar_step(), 6174);
+    /// ```
+    fn kaprekar_step(self) -> Self;
+
+    /// カプレカ操作を繰り返して 6174 に到達するまでの回数を返す (10進数, 負の数は絶対値)
+    ///
+    /// 4 桁ゾロ目 (0 を含む) は 0 に潰れるため `None`。5 桁以上の数も `None` を返す。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(3524.kaprekar_iterations(), Some(3));
+    /// assert_eq!(6174.kaprekar_iterations(), Some(0));
+    /// assert_eq!(7777.kaprekar_iterations(), None);
+    /// ```
+    fn kaprekar_iterations(self) -> Option<u32>;
+
+    /// 桁で比較する (10進数, 負の数は絶対値): まず桁数、同じ桁数なら上位桁から辞書順
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// use std::cmp::Ordering;
+    /// assert_eq!(91.digit_cmp(19), Ordering::Greater);
+    /// assert_eq!(9.digit_cmp(10), Ordering::Less);
+    /// assert_eq!((-12).digit_cmp(12), Ordering::Equal);
+    /// ```
+    fn digit_cmp(self, other: Self) -> Ordering;
+
+    /// 各桁の和で比較し、同じなら数値で比較する (10進数)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// let mut v = vec![91, 19, 100, 5];
+    /// v.sort_by(|a, b| a.cmp_by_digit_sum(*b));
+    /// assert_eq!(v, vec![100, 5, 19, 91]);
+    /// ```
+    fn cmp_by_digit_sum(self, other: Self) -> Ordering;
+
+    /// 下位から `group` 桁ごとに区切り文字 `sep` を挟んだ文字列に変換する (10進数, 符号は維持)
+    ///
+    /// # Panics
+    /// `group` が 0 の場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(1234567.group_digits(',', 3), "1,234,567");
+    /// assert_eq!((-1234).group_digits('_', 2), "-12_34");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn group_digits(self, sep: char, group: usize) -> String;
+
+    /// 数値の列それぞれの各桁の和を合計する (10進数)
+    ///
+    /// 要素ごとに `Vec` を作らず、割り算のループだけで集計する。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(u32::sum_of_digit_sums(1..=10u32), 46); // 1 + 2 + ... + 9 + 1
+    /// assert_eq!(i64::sum_of_digit_sums([-12, 34]), 10);
+    /// ```
+    fn sum_of_digit_sums<I: IntoIterator<Item = Self>>(iter: I) -> u64;
+
+    /// ミュンヒハウゼン数 (各桁をその桁自身で累乗した和が元の数に等しい) か判定する (10進数)
+    ///
+    /// `0^0 = 0` とみなすので、10進数では 0, 1, 3435, 438579088 の4つだけが該当する。
+    /// 負の数は `false`。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(3435.is_munchausen()); // 3^3 + 4^4 + 3^3 + 5^5
+    /// assert!(!3436.is_munchausen());
+    /// ```
+    fn is_munchausen(self) -> bool;
+
+    /// 各桁の数字を並び替えたベクタを返す (10進数, 負の数は絶対値)
+    ///
+    /// `make_max` / `make_min` と違い、0 も並べた位置にそのまま残る。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(2001.sort_digits(true), vec![0, 0, 1, 2]);
+    /// assert_eq!(2001.sort_digits(false), vec![2, 1, 0, 0]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn sort_digits(self, ascending: bool) -> Vec<u8>;
+
+    /// 10進数での各桁の和を `m` で割った余りを返す (負の数は絶対値)
+    ///
+    /// # Panics
+    /// `m` が 0 の場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(12345.digit_sum_mod(7), 1); // 15 % 7
+    /// ```
+    fn digit_sum_mod(self, m: u64) -> u64;
+
+    /// 九去法の検算に使う値 (数字根) を返す (10進数, 負の数は絶対値)
+    ///
+    /// 0 以外の 9 の倍数は 0 ではなく 9 を返し、0 を返すのは 0 だけ。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(12345.casting_out_nines(), 6); // 1+2+3+4+5 = 15 -> 1+5 = 6
+    /// assert_eq!(18.casting_out_nines(), 9);
+    /// // 123 * 456 = 56088 の検算
+    /// assert_eq!(
+    ///     (123.casting_out_nines() * 456.casting_out_nines()).casting_out_nines(),
+    ///     56088.casting_out_nines()
+    /// );
+    /// ```
+    fn casting_out_nines(self) -> u8;
+
+    /// 負かどうかと、絶対値の各桁の数字のベクタの組を返す (10進数)
+    ///
+    /// `digits` が捨てる符号を明示的に受け取れるので、`(neg, d)` から元の値を復元できる。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!((-123).signed_digits(), (true, vec![1, 2, 3]));
+    /// assert_eq!(123.signed_digits(), (false, vec![1, 2, 3]));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn signed_digits(self) -> (bool, Vec<u8>);
+
+    /// `self` より大きく、各桁の和が `self` と等しい最小の数を返す (10進数)
+    ///
+    /// 総当たりではなく、1 増やせる桁を探して残りの和を下位桁に詰め直すことで求める。
+    /// 負の数では、絶対値が小さくなる側 (0 をまたぐ場合は正の側) の数を返す。
+    ///
+    /// # Panics
+    /// 結果が型の範囲を超える場合、または `self` が 0 の場合 (和が 0 の数は 0 しかない)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(19.next_with_digit_sum(), 28);
+    /// assert_eq!(28.next_with_digit_sum(), 37);
+    /// assert_eq!(91.next_with_digit_sum(), 109);
+    /// ```
+    fn next_with_digit_sum(self) -> Self;
+
+    /// `self` より大きく、各桁の和が `self` と等しい最小の数を返す (10進数, 存在しないか型の範囲を超える場合は `None`)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(19u8.checked_next_with_digit_sum(), Some(28));
+    /// assert_eq!(253u8.checked_next_with_digit_sum(), None); // 262 > u8::MAX
+    /// ```
+    fn checked_next_with_digit_sum(self) -> Option<Self>;
+
+    /// スミス数 (各桁の和が、素因数分解した各素因数の各桁の和の合計に等しい合成数) か判定する (10進数)
+    ///
+    /// 素因数は重複も数える。素数と 1 以下 (負の数を含む) は `false`。
+    /// 素因数分解は試し割りなので、大きな素因数を持つ数では `O(√n)` かかる。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(4.is_smith()); // 4 = 2 * 2, 4 = 2 + 2
+    /// assert!(22.is_smith()); // 22 = 2 * 11, 2 + 2 = 2 + (1 + 1)
+    /// assert!(!7.is_smith()); // 素数
+    /// ```
+    fn is_smith(self) -> bool;
+
+    /// 反転した数を足す操作を回文になるまで繰り返し、その回数を返す (10進数)
+    ///
+    /// 最初の数が回文でも少なくとも1回は足す。`max_iters` 回以内に回文にならない場合
+    /// (リクレル数の候補) や、途中で型の範囲を超えた場合は `None`。
+    /// 値はすぐに大きくなるので `u128` で使うのがよい。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(56u128.reverse_add_steps(10), Some(1)); // 56 + 65 = 121
+    /// assert_eq!(89u128.reverse_add_steps(100), Some(24));
+    /// assert_eq!(196u128.reverse_add_steps(30), None);
+    /// ```
+    fn reverse_add_steps(self, max_iters: u32) -> Option<u32>;
+
+    /// 2進数での各桁の和 (立っているビットの数) を返す (負の数は絶対値)
+    ///
+    /// `digit_sum_radix(2)` と同じ値を、ハードウェアの `count_ones` で求める。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(0b1011.popcount(), 3);
+    /// assert_eq!((-6).popcount(), 2); // -110
+    /// ```
+    fn popcount(self) -> u32;
+
+    /// 2進数での桁数を返す (負の数は絶対値, 0 は 1 桁)
+    ///
+    /// `digits_len_radix(2)` と同じ値を、`leading_zeros` で求める。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(0b1011.bit_len(), 4);
+    /// assert_eq!(0.bit_len(), 1);
+    /// ```
+    fn bit_len(self) -> u32;
+
+    /// 各桁に `f(位置, 数字)` を適用した値の和を返す (10進数, 負の数は絶対値)
+    ///
+    /// 位置は最下位の桁を 0 として上位へ数える (チェックディジットの式と合わせるため)。
+    /// Luhn や ISBN のチェックディジット、交代和による倍数判定などをこれ1つで書ける。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// // ISBN-10: 下から i 番目 (0-indexed) の桁に i + 1 を掛けた和が 11 の倍数
+    /// let isbn = 306406152u64; // 0-306-40615-2
+    /// assert_eq!(isbn.weighted_digit_sum(|i, d| (i as i64 + 1) * d as i64) % 11, 0);
+    /// // 11 の倍数判定 (交代和)
+    /// let alt = 918082.weighted_digit_sum(|i, d| if i % 2 == 0 { d as i64 } else { -(d as i64) });
+    /// assert_eq!(alt % 11, 0); // 2 - 8 + 0 - 8 + 1 - 9 = -22
+    /// ```
+    fn weighted_digit_sum<F: FnMut(u32, u8) -> i64>(self, f: F) -> i64;
+
+    /// 数値を結合する (10進数, 型の範囲を超える場合は `None`)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(12u8.checked_concat(3), Some(123));
+    /// assert_eq!(25u8.checked_concat(6), None); // 256 > u8::MAX
+    /// ```
+    fn checked_concat(self, other: Self) -> Option<Self>;
+
+    /// 上位桁から見て各桁が前の桁以上か判定する (10進数, 負の数は絶対値, 1桁なら `true`)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(134468.has_increasing_digits());
+    /// assert!(!155349.has_increasing_digits());
+    /// ```
+    fn has_increasing_digits(self) -> bool;
+
+    /// 上位桁から見て各桁が前の桁以下か判定する (10進数, 負の数は絶対値, 1桁なら `true`)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(66420.has_decreasing_digits());
+    /// assert!(!155349.has_decreasing_digits());
+    /// ```
+    fn has_decreasing_digits(self) -> bool;
+
+    /// 隣り合う各桁の差がすべて 1 か判定する (10進数, 負の数は絶対値, 1桁なら `true`)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(4565.is_stepping());
+    /// assert!(!14.is_stepping());
+    /// ```
+    fn is_stepping(self) -> bool;
+
+    /// 10進数で `width` 桁にゼロ埋めした表記が回文か判定する (負の数は絶対値)
+    ///
+    /// # Panics
+    /// 桁数が `width` を超える場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(!10.is_palindrome_padded(4)); // 0010
+    /// assert!(1001.is_palindrome_padded(4));
+    /// assert!(10.is_palindrome_padded(3)); // 010
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn is_palindrome_padded(self, width: usize) -> bool;
+
+    /// 10進数で上位桁から各桁までの和 (累積和) のベクタを返す (負の数は絶対値)
+    ///
+    /// 最後の要素は `digit_sum` と等しい。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(123.digit_prefix_sums(), vec![1, 3, 6]);
+    /// assert_eq!(102.digit_prefix_sums(), vec![1, 1, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn digit_prefix_sums(self) -> Vec<u64>;
+
+    /// 桁を並び替えてできる「最大の数値」を返す (10進数, 型の範囲を超える場合は `None`)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(1_000_000_002i32.checked_make_max(), Some(2_100_000_000));
+    /// // 8776444321 は i32 に収まらない
+    /// assert_eq!(i32::MAX.checked_make_max(), None);
+    /// ```
+    fn checked_make_max(self) -> Option<Self>;
+
+    /// 桁を並び替えてできる「最小の数値」を返す (10進数, 型の範囲を超える場合は `None`)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!((-1_000_000_002i32).checked_make_min(), Some(-2_100_000_000));
+    /// assert_eq!(i32::MIN.checked_make_min(), None);
+    /// ```
+    fn checked_make_min(self) -> Option<Self>;
+
+    /// `0..=limit` のうち10進数での各桁の和が `target` になる数の個数を返す
+    ///
+    /// 全数を調べず桁 DP で数えるので、`u64` の上限のような大きな `limit` でも速い。
+    /// `limit` が負なら 0、個数が `u64` に収まらない場合は `u64::MAX`。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// // 5, 14, 23, 32, 41, 50
+    /// assert_eq!(u32::count_with_digit_sum(100, 5), 6);
+    /// ```
+    fn count_with_digit_sum(limit: Self, target: u64) -> u64;
+
+    /// 10進数の各桁を 4 ビットずつ詰めた BCD (二進化十進数) のバイト列に変換する (負の数は絶対値)
+    ///
+    /// 上位桁から 1 バイトに 2 桁ずつ詰める。桁数が奇数なら先頭に 0 のニブルを補う。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(12345.to_bcd(), vec![0x01, 0x23, 0x45]);
+    /// assert_eq!(2024.to_bcd(), vec![0x20, 0x24]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_bcd(self) -> Vec<u8>;
+
+    /// BCD (二進化十進数) のバイト列から数値を復元する
+    ///
+    /// # Panics
+    /// 9 を超えるニブルが含まれる場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(u32::from_bcd(&[0x01, 0x23, 0x45]), 12345);
+    /// ```
+    fn from_bcd(bytes: &[u8]) -> Self;
+
+    /// 10進数の各桁を上位桁から順に畳み込む (負の数は絶対値, 0 は `[0]` として扱う)
+    ///
+    /// `Vec` を作らずに割り算のループだけで桁を取り出す。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(1234.fold_digits(0, |acc, d| acc + d as u64), 10);
+    /// // ホーナー法で復元すると元の数に戻る
+    /// assert_eq!(1234.fold_digits(0, |acc, d| acc * 10 + d as u32), 1234);
+    /// ```
+    fn fold_digits<B, F: FnMut(B, u8) -> B>(self, init: B, f: F) -> B;
+
+    /// 10進数で1桁の数ならその数字を返す (負の数は絶対値, 2桁以上なら `None`)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(7.as_single_digit(), Some(7));
+    /// assert_eq!((-7).as_single_digit(), Some(7));
+    /// assert_eq!(10.as_single_digit(), None);
+    /// ```
+    fn as_single_digit(self) -> Option<u8>;
+
+    /// `n!` の10進数での各桁の和を計算する
+    ///
+    /// 固定長の整数に収まらない階乗も、10^9 ごとに区切った筆算で求める。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(u64::factorial_digit_sum(10), 27); // 3628800
+    /// assert_eq!(u64::factorial_digit_sum(100), 648);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn factorial_digit_sum(n: u32) -> u64;
+
+    /// 桁が増加も減少もする「弾む数」(bouncy number) か判定する (10進数, 負の数は絶対値)
+    ///
+    /// `has_increasing_digits` と `has_decreasing_digits` がどちらも `false` になる数。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(155349.is_bouncy());
+    /// assert!(!123456.is_bouncy());
+    /// assert!(!987654.is_bouncy());
+    /// ```
+    fn is_bouncy(self) -> bool;
+
+    /// 桁数を変えずに桁を並び替えてできる「最小の数値」を返す (10進数)
+    ///
+    /// 最も小さい 0 以外の数字を先頭に置くので、`make_min` と違い先頭の 0 で桁数が減らない。
+    /// 負の数は絶対値が最大になる並びを返す。
+    ///
+    /// # Panics
+    /// 負の数で、結果が型の範囲を超える場合 (`checked_smallest_arrangement_fixed` を使う)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(2001.smallest_arrangement_fixed(), 1002);
+    /// assert_eq!(2001.make_min(), 12);
+    /// assert_eq!(100.smallest_arrangement_fixed(), 100);
+    /// ```
+    fn smallest_arrangement_fixed(self) -> Self;
+
+    /// 桁数を変えずに桁を並び替えてできる「最小の数値」を返す (10進数, 型の範囲を超える場合は `None`)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!((-2001i32).checked_smallest_arrangement_fixed(), Some(-2100));
+    /// // -9100000000 は i32 に収まらない
+    /// assert_eq!((-1_000_000_009i32).checked_smallest_arrangement_fixed(), None);
+    /// ```
+    fn checked_smallest_arrangement_fixed(self) -> Option<Self>;
+
+    /// 桁数を変えずに桁を並び替えてできる「最大の数値」を返す (10進数)
+    ///
+    /// 0 以上の数では `make_max` と同じ。負の数は絶対値が桁数を保ったまま最小になる並びを返す。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(2001.largest_arrangement_fixed(), 2100);
+    /// assert_eq!((-2001).largest_arrangement_fixed(), -1002);
+    /// ```
+    fn largest_arrangement_fixed(self) -> Self;
+
+    /// 各桁に重みを掛けた和を `modulus` で割った余り (0 以上) を返す (10進数, 負の数は絶対値)
+    ///
+    /// 一の位から順に `weights[0], weights[1], ...` を掛け、桁が重みより多ければ重みを繰り返す。
+    /// Luhn や ISBN、EAN などのチェックディジットの検査をまとめて表せる。
+    ///
+    /// # Panics
+    /// `weights` が空、または `modulus` が 0 以下の場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// // ISBN-13 は一の位から 1, 3, 1, 3, ... の重みで 10 の倍数になる
+    /// assert_eq!(9780306406157u64.checksum_mod(&[1, 3], 10), 0);
+    /// assert_ne!(9780306406158u64.checksum_mod(&[1, 3], 10), 0);
+    /// ```
+    fn checksum_mod(self, weights: &[i64], modulus: i64) -> i64;
+
+    /// 2種類の数字が交互に並ぶ `abab...` の形 (3桁以上, `a != b`) か判定する (10進数, 負の数は絶対値)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(8989.is_undulating());
+    /// assert!(121.is_undulating());
+    /// assert!(!111.is_undulating());
+    /// assert!(!12.is_undulating());
+    /// ```
+    fn is_undulating(self) -> bool;
+
+    /// 数値を結合する (10進数, 型の範囲を超える場合は折り返す)
+    ///
+    /// デバッグビルドでもリリースビルドでも同じ結果になる。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(12u8.wrapping_concat(34), (1234 % 256) as u8);
+    /// ```
+    fn wrapping_concat(self, other: Self) -> Self;
+
+    /// 桁を並び替えてできる「最大の数値」を返す (10進数, 型の範囲を超える場合は折り返す)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(199u8.wrapping_make_max(), (991 % 256) as u8);
+    /// ```
+    fn wrapping_make_max(self) -> Self;
+
+    /// 10進数で末尾に並ぶ 0 の個数を返す (10 で割り切れる回数, 0 は `"0"` として 1)
+    ///
+    /// 整数型の `trailing_zeros` (2進数) と区別するため `_10` を付けている。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(1200.trailing_zeros_10(), 2);
+    /// assert_eq!(0.trailing_zeros_10(), 1);
+    /// ```
+    fn trailing_zeros_10(self) -> u32;
+
+    /// 10進数の桁を逆順に並べた数字のベクタと、負の数かどうかを返す
+    ///
+    /// `reverse` と違い、数値に戻さないので末尾の 0 が失われない。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(1200.reverse_digits(), (vec![0, 0, 2, 1], false));
+    /// assert_eq!((-12).reverse_digits(), (vec![2, 1], true));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn reverse_digits(self) -> (Vec<u8>, bool);
+
+    /// 各桁の `power` 乗の和が自分自身と等しいか判定する (10進数, 負の数は絶対値)
+    ///
+    /// `power` が桁数に等しい場合は `is_armstrong` と同じ。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(4150.is_pdi(5)); // 4^5 + 1^5 + 5^5 + 0^5
+    /// assert!(!4152.is_pdi(5));
+    /// ```
+    fn is_pdi(self, power: u32) -> bool;
+
+    /// 最も多く現れる数字を返す (10進数, 負の数は絶対値)
+    ///
+    /// 出現回数が同じ数字が複数ある場合は小さい方を返す。0 は `0` を返す。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(1223334444.most_common_digit(), 4);
+    /// assert_eq!(112233.most_common_digit(), 1);
+    /// ```
+    fn most_common_digit(self) -> u8;
+
+    /// 現れる数字のうち最も出現回数が少ない数字を返す (10進数, 負の数は絶対値)
+    ///
+    /// 現れない数字は対象外。出現回数が同じ数字が複数ある場合は小さい方を返す。0 は `0` を返す。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(1223334444.least_common_digit(), 1);
+    /// assert_eq!(332211.least_common_digit(), 1);
+    /// ```
+    fn least_common_digit(self) -> u8;
+
+    /// `self` から `digit_sum` を1桁になるまで繰り返し適用した値を順に返すイテレータ (10進数, 負の数は絶対値)
+    ///
+    /// `self` と最後の1桁の値を含む。1桁の数は自分自身だけを返す。
+    /// 長さから1を引いたものが `additive_persistence` に、最後の値が数字根に等しい。
+    ///
+    /// # Panics
+    /// 絶対値が `u64` に収まらない場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(199u32.digit_sum_trajectory().collect::<Vec<_>>(), vec![199, 19, 10, 1]);
+    /// assert_eq!(7u32.digit_sum_trajectory().collect::<Vec<_>>(), vec![7]);
+    /// ```
+    fn digit_sum_trajectory(self) -> impl Iterator<Item = u64>;
+
+    /// `self` から `digit_product` を1桁になるまで繰り返し適用した値を順に返すイテレータ (10進数, 負の数は絶対値)
+    ///
+    /// `self` と最後の1桁の値を含む。長さから1を引いたものが `multiplicative_persistence` に等しい。
+    ///
+    /// # Panics
+    /// 絶対値が `u64` に収まらない場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(39u32.digit_product_trajectory().collect::<Vec<_>>(), vec![39, 27, 14, 4]);
+    /// ```
+    fn digit_product_trajectory(self) -> impl Iterator<Item = u64>;
+
+    /// 反転すると `other` になるか判定する (10進数, `self.reverse() == other` と同じ)
+    ///
+    /// `reverse` と同様に末尾の 0 は失われるので、`120` は `21` の反転とみなす。
+    /// 桁数も一致させたい場合は `is_digit_reverse_of_strict` を使う。
+    /// 反転が型の範囲を超える場合は `false`。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(123.is_digit_reverse_of(321));
+    /// assert!(120.is_digit_reverse_of(21));
+    /// assert!(!123.is_digit_reverse_of(123));
+    /// ```
+    fn is_digit_reverse_of(self, other: Self) -> bool;
+
+    /// 数字の並びを逆にすると `other` の数字の並びになるか判定する (10進数, 0 も含めて比べる)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(123.is_digit_reverse_of_strict(321));
+    /// assert!(!120.is_digit_reverse_of_strict(21));
+    /// ```
+    fn is_digit_reverse_of_strict(self, other: Self) -> bool;
+
+    /// 反転した数が自分と異なる素数になる素数 (emirp) か判定する (10進数, 負の数は `false`)
+    ///
+    /// 素数判定は試し割りなので O(√n)。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(13.is_emirp()); // 31 も素数
+    /// assert!(!11.is_emirp()); // 回文素数は除く
+    /// assert!(!23.is_emirp()); // 32 は合成数
+    /// ```
+    fn is_emirp(self) -> bool;
+
+    /// 吸血鬼数か判定する (10進数, 負の数は `false`)
+    ///
+    /// 桁数が偶数で、桁数が半分ずつの2つの因数 (牙) の積で表せ、2つの牙の数字を合わせると
+    /// 元の数の数字をちょうど使い切るもの。ただし牙が両方とも 0 で終わってはいけない。
+    /// 牙は試し割りで探すので、候補を mod 9 で絞り込んでも最悪 `O(√n)` かかる。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(1260.is_vampire()); // 21 * 60
+    /// assert!(!1234.is_vampire());
+    /// ```
+    fn is_vampire(self) -> bool;
+
+    /// 吸血鬼数の牙 `(x, y)` (`x <= y`) を返す (10進数, 複数ある場合は `x` が最小のもの)
+    ///
+    /// 吸血鬼数でなければ `None`。計算量は `is_vampire` と同じく最悪 `O(√n)`。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(1395.vampire_fangs(), Some((15, 93)));
+    /// assert_eq!(1234.vampire_fangs(), None);
+    /// ```
+    fn vampire_fangs(self) -> Option<(Self, Self)>;
+
+    /// 数値の列を左から順に結合する (10進数, `concat` を繰り返すのと同じ結果)
+    ///
+    /// 符号は `concat` と同じく先頭の数に従い、2つ目以降の数は絶対値を結合する。空の列は `0`。
+    /// 結果が型の範囲を超える場合の挙動は `concat` と同じ。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(u32::concat_many_10(&[12, 34, 5]), 12345);
+    /// assert_eq!(i32::concat_many_10(&[-1, 20, 3]), -1203);
+    /// ```
+    fn concat_many_10(parts: &[Self]) -> Self;
+
+    /// 数値の列を左から順に結合する (10進数, 型の範囲を超える場合は `None`)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(u16::checked_concat_many_10(&[6, 55, 3]), Some(6553));
+    /// assert_eq!(u16::checked_concat_many_10(&[6, 55, 36]), None);
+    /// ```
+    fn checked_concat_many_10(parts: &[Self]) -> Option<Self>;
+
+    /// 各桁の数字を `f` で変換した数値を返す (10進数, 負の数は絶対値を変換して符号を保つ)
+    ///
+    /// 変換後に先頭が 0 になった場合は数値として返すので、その 0 は失われる (`900` の9の補数は `99`)。
+    ///
+    /// # Panics
+    /// `f` が 10 以上の値を返した場合、または結果が型の範囲を超える場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(123.map_digits(|d| 9 - d), 876);
+    /// assert_eq!(1962.map_digits(|d| d.min(5)), 1552);
+    /// ```
+    fn map_digits<F: Fn(u8) -> u8>(self, f: F) -> Self;
+
+    /// 各桁の数字を `f` で変換した数値を返す (10進数, 型の範囲を超える場合は `None`)
+    ///
+    /// # Panics
+    /// `f` が 10 以上の値を返した場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(12u8.checked_map_digits(|d| 9 - d), Some(87));
+    /// assert_eq!(255u8.checked_map_digits(|d| 9 - d), None); // 744
+    /// ```
+    fn checked_map_digits<F: Fn(u8) -> u8>(self, f: F) -> Option<Self>;
+
+    /// 桁を巡回シフトしてできる数のうち最小のものを返す (10進数, 巡回数の正規形)
+    ///
+    /// `make_min` と違い、並び替えは巡回シフトに限る。先頭に 0 が来る回転も候補に含め、
+    /// その場合は `rotate_digits_left` と同じく桁数が減った数値として比べる。
+    /// 負の数は値として最小のもの (絶対値が最大の回転) を返す。
+    /// 型の範囲を超える回転は候補に含めない。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(312.min_rotation(), 123);
+    /// assert_eq!(231.min_rotation(), 123);
+    /// assert_eq!(120.min_rotation(), 12); // 012
+    /// ```
+    fn min_rotation(self) -> Self;
+
+    /// 桁を巡回シフトしてできる数のうち最大のものを返す (10進数)
+    ///
+    /// 型の範囲を超える回転は候補に含めない (`125u8` は 512 を飛ばして 251)。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(123.max_rotation(), 312);
+    /// assert_eq!(1020.max_rotation(), 2010);
+    /// assert_eq!(125u8.max_rotation(), 251); // 512 は範囲外
+    /// ```
+    fn max_rotation(self) -> Self;
+
+    /// 数字の多重集合を表すシグネチャを返す (10進数, 負の数は絶対値)
+    ///
+    /// 各数字(0-9)の出現回数を 6 ビットずつ詰めた値なので、桁を並び替えた数どうしは
+    /// 同じ値になり、それ以外は異なる値になる。6 ビットで数えられるのは各数字 63 個までだが、
+    /// `u128` でも 39 桁なので、どの整数型でも衝突しない。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(123.digit_signature(), 321.digit_signature());
+    /// assert_ne!(123.digit_signature(), 122.digit_signature());
+    /// ```
+    fn digit_signature(self) -> u64;
+
+    /// 10進数の各桁の数字を上位桁から順に返すイテレータ (負の数は絶対値)
+    ///
+    /// `digits` と同じ並びを、ベクタを確保せずに1桁ずつ返す。
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert!(12345u32.digits_iter().eq([1, 2, 3, 4, 5]));
+    /// assert_eq!(9081u32.digits_iter().max(), Some(9));
+    /// assert_eq!((-120i32).digits_iter().map(u64::from).sum::<u64>(), 3);
+    /// ```
+    fn digits_iter(self) -> impl Iterator<Item = u8>;
+
+    // ============================================================
+    // n進数対応
+    // base が 2 未満の場合はパニックする
+    // ============================================================
+
+    /// n進数で各桁の数字(u8)のベクタに分解する
+    #[cfg(feature = "alloc")]
+    fn digits_radix(self, base: u32) -> Vec<u8>;
+
+    /// n進数の数字列から数値を復元する
+    ///
+    /// # Panics
+    /// `base` 以上の数字を含む場合、または結果が型の範囲を超える場合
+    fn from_digits_radix(digits: &[u8], base: u32) -> Self;
+
+    /// n進数での各桁の和を計算する
+    fn digit_sum_radix(self, base: u32) -> u64;
+
+    /// n進数での各桁の積を計算する
+    fn digit_product_radix(self, base: u32) -> u64;
+
+    /// n進数での桁数を返す
+    fn digits_len_radix(self, base: u32) -> u32;
+
+    /// 数値の並びを反転させる (n進数)
+    fn reverse_radix(self, base: u32) -> Self;
+
+    /// 回文数かどうか判定する (n進数)
+    fn is_palindrome_radix(self, base: u32) -> bool;
+
+    /// 上からi番目の桁を取得する (n進数, 0-indexed)
+    fn nth_digit_radix(self, i: u32, base: u32) -> Option<u8>;
+
+    /// 数値を結合する (n進数)
+    ///
+    /// # Panics
+    /// 結果が型の範囲を超える場合 (リリースビルドでも折り返さない)
+    fn concat_radix(self, other: Self, base: u32) -> Self;
+
+    /// 指定した数字が含まれているか判定する (n進数)
+    fn contains_digit_radix(self, digit: u8, base: u32) -> bool;
+
+    /// 桁を並び替えてできる「最大の数値」を返す (n進数, 負の数は `make_max` と同様)
+    fn make_max_radix(self, base: u32) -> Self;
+
+    /// 桁を並び替えてできる「最小の数値」を返す (n進数, 負の数は `make_min` と同様)
+    fn make_min_radix(self, base: u32) -> Self;
+
+    /// 指定した数字が何回現れるかを数える (n進数)
+    fn count_digit_radix(self, digit: u8, base: u32) -> u32;
+
+    /// 各数字の出現回数を数える (n進数, 長さ `base` のベクタ)
+    #[cfg(feature = "alloc")]
+    fn digit_counts_radix(self, base: u32) -> Vec<u32>;
+
+    /// 指定した数字が現れる位置をすべて返す (n進数, 上位桁から 0-indexed)
+    #[cfg(feature = "alloc")]
+    fn digit_positions_radix(self, digit: u8, base: u32) -> Vec<u32>;
+
+    /// 0..base の各数字をちょうど1回ずつ使っているか判定する (n進数)
+    fn is_pandigital_radix(self, base: u32) -> bool;
+
+    /// 桁を左に巡回シフトする (n進数)
+    ///
+    /// # Panics
+    /// 結果が型の範囲を超える場合
+    fn rotate_digits_left_radix(self, n: u32, base: u32) -> Self;
+
+    /// 桁を右に巡回シフトする (n進数)
+    fn rotate_digits_right_radix(self, n: u32, base: u32) -> Self;
+
+    /// 桁を左に巡回シフトする (n進数, 型の範囲を超える場合は `None`)
+    fn checked_rotate_digits_left_radix(self, n: u32, base: u32) -> Option<Self>;
+
+    /// 桁を右に巡回シフトする (n進数, 型の範囲を超える場合は `None`)
+    fn checked_rotate_digits_right_radix(self, n: u32, base: u32) -> Option<Self>;
+
+    /// n進数での各桁の2乗の和を計算する
+    fn digit_square_sum_radix(self, base: u32) -> u64;
+
+    /// ハッピー数かどうか判定する (n進数)
+    fn is_happy_radix(self, base: u32) -> bool;
+
+    /// アームストロング数 (ナルシシスト数) かどうか判定する (n進数)
+    fn is_armstrong_radix(self, base: u32) -> bool;
+
+    /// ハーシャッド数 (ニーヴン数) かどうか判定する (n進数)
+    fn is_harshad_radix(self, base: u32) -> bool;
+
+    /// n進数の文字列に変換する (`0-9`, `a-z` を使用, 負の数は先頭に `-`)
+    ///
+    /// # Panics
+    /// `base` が `2..=36` の範囲外の場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(255.to_radix_string(16), "ff");
+    /// assert_eq!((-6).to_radix_string(2), "-110");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_radix_string(self, base: u32) -> String;
+
+    /// n進数の文字列に変換する (`0-9`, `A-Z` を使用, 負の数は先頭に `-`)
+    ///
+    /// # Panics
+    /// `base` が `2..=36` の範囲外の場合
+    #[cfg(feature = "alloc")]
+    fn to_radix_string_upper(self, base: u32) -> String;
+
+    /// n進数の文字列から数値を復元する (`0-9`, `a-z`, `A-Z` を受け付ける)
+    ///
+    /// 先頭に `+` / `-` の符号を1つだけ付けられる (符号なし整数では `-` はエラー)。
+    ///
+    /// # Panics
+    /// `base` が `2..=36` の範囲外の場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::{Keta, ParseKetaError};
+    /// assert_eq!(u32::from_radix_string("ff", 16), Ok(255));
+    /// assert_eq!(i32::from_radix_string("-Z", 36), Ok(-35));
+    /// assert_eq!(u8::from_radix_string("256", 10), Err(ParseKetaError::Overflow));
+    /// ```
+    fn from_radix_string(s: &str, base: u32) -> Result<Self, ParseKetaError>;
+
+    /// n進数の数字列から数値を復元する (検査付き, 範囲外の数字やオーバーフローで `None`)
+    fn try_from_digits_radix(digits: &[u8], base: u32) -> Option<Self>;
+
+    /// 上から `start` 桁目 (0-indexed) から `len` 桁を取り出した数値を返す (n進数)
+    fn digit_slice_radix(self, start: u32, len: u32, base: u32) -> Self;
+
+    /// 上から `i` 桁目 (0-indexed) を `digit` に置き換えた数値を返す (n進数)
+    ///
+    /// # Panics
+    /// `digit` が `base` 以上の場合、`i` が桁数以上の場合、または結果が型の範囲を超える場合
+    fn with_digit_radix(self, i: u32, digit: u8, base: u32) -> Self;
+
+    /// 上から `i` 桁目 (0-indexed) を `digit` に置き換えた数値を返す (n進数, 型の範囲を超える場合は `None`)
+    fn checked_with_digit_radix(self, i: u32, digit: u8, base: u32) -> Option<Self>;
+
+    /// 下からi番目の桁を取得する (n進数, 0-indexed)
+    fn nth_digit_from_right_radix(self, i: u32, base: u32) -> Option<u8>;
+
+    /// 最大の桁の数字を返す (n進数)
+    fn max_digit_radix(self, base: u32) -> u8;
+
+    /// 最小の桁の数字を返す (n進数)
+    fn min_digit_radix(self, base: u32) -> u8;
+
+    /// 使われている数字の種類数を返す (n進数)
+    fn distinct_digit_count_radix(self, base: u32) -> u32;
+
+    /// 全ての桁が同じ数字か判定する (n進数)
+    fn is_repdigit_radix(self, base: u32) -> bool;
+
+    /// 全ての桁が 1 か判定する (n進数)
+    fn is_repunit_radix(self, base: u32) -> bool;
+
+    /// `self` より大きい最小の回文数を返す (n進数)
+    #[cfg(feature = "alloc")]
+    fn next_palindrome_radix(self, base: u32) -> Self;
+
+    /// `self` より大きい最小の回文数を返す (n進数, 型の範囲を超える場合は `None`)
+    #[cfg(feature = "alloc")]
+    fn checked_next_palindrome_radix(self, base: u32) -> Option<Self>;
+
+    /// n進数での各桁の `exp` 乗の和を計算する (`u128` で飽和)
+    fn digit_power_sum_radix(self, exp: u32, base: u32) -> u128;
+
+    /// 数値の並びを反転させる (n進数, 型の範囲を超える場合は `None`)
+    fn checked_reverse_radix(self, base: u32) -> Option<Self>;
+
+    /// 数値の並びを反転させる (n進数, 型の範囲を超える場合は飽和)
+    fn saturating_reverse_radix(self, base: u32) -> Self;
+
+    /// 数値の並びを反転させる (n進数, 型の範囲を超える場合は折り返す)
+    fn wrapping_reverse_radix(self, base: u32) -> Self;
+
+    /// n進数での各桁の積を計算する (`u64` に収まらない場合は `None`)
+    fn checked_digit_product_radix(self, base: u32) -> Option<u64>;
+
+    /// n進数で `width` 桁にゼロ埋めした各桁の数字のベクタを返す
+    ///
+    /// # Panics
+    /// 桁数が `width` を超える場合
+    #[cfg(feature = "alloc")]
+    fn digits_padded_radix(self, width: usize, base: u32) -> Vec<u8>;
+
+    /// n進数で `width` 桁にゼロ埋めした文字列に変換する (符号は `width` に含めない)
+    ///
+    /// # Panics
+    /// `base` が `2..=36` の範囲外の場合、または桁数が `width` を超える場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(10.to_radix_string_padded(8, 2), "00001010");
+    /// assert_eq!((-255).to_radix_string_padded(4, 16), "-00ff");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_radix_string_padded(self, width: usize, base: u32) -> String;
+
+    /// 2つの数値の桁を `self` の上位桁から交互に並べる (n進数)
+    fn interleave_radix(self, other: Self, base: u32) -> Self;
+
+    /// 2つの数値の桁を交互に並べる (n進数, 型の範囲を超える場合は `None`)
+    fn checked_interleave_radix(self, other: Self, base: u32) -> Option<Self>;
+
+    /// 上から `i` 桁とそれ以降の2つの数値に分割する (n進数)
+    fn split_at_radix(self, i: u32, base: u32) -> (Self, Self);
+
+    /// 指定した数字をすべて取り除いた数値を返す (n進数)
+    ///
+    /// # Panics
+    /// `digit` が `base` 以上の場合
+    fn remove_digit_radix(self, digit: u8, base: u32) -> Self;
+
+    /// 桁で比較する (n進数): まず桁数、同じ桁数なら上位桁から辞書順
+    fn digit_cmp_radix(self, other: Self, base: u32) -> Ordering;
+
+    /// 各桁の和で比較し、同じなら数値で比較する (n進数)
+    fn cmp_by_digit_sum_radix(self, other: Self, base: u32) -> Ordering;
+
+    /// 下位から `group` 桁ごとに区切り文字 `sep` を挟んだ文字列に変換する (n進数, 小文字)
+    ///
+    /// # Panics
+    /// `group` が 0 の場合、または `base` が `2..=36` の範囲外の場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// assert_eq!(0xdeadbeef_u32.group_digits_radix(' ', 2, 16), "de ad be ef");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn group_digits_radix(self, sep: char, group: usize, base: u32) -> String;
+
+    /// ミュンヒハウゼン数か判定する (n進数, `0^0 = 0`)
+    fn is_munchausen_radix(self, base: u32) -> bool;
+
+    /// 各桁の数字を並び替えたベクタを返す (n進数)
+    #[cfg(feature = "alloc")]
+    fn sort_digits_radix(self, ascending: bool, base: u32) -> Vec<u8>;
+
+    /// n進数での各桁の和を `m` で割った余りを返す
+    fn digit_sum_mod_radix(self, m: u64, base: u32) -> u64;
+
+    /// 負かどうかと、絶対値の各桁の数字のベクタの組を返す (n進数)
+    #[cfg(feature = "alloc")]
+    fn signed_digits_radix(self, base: u32) -> (bool, Vec<u8>);
+
+    /// `self` より大きく、各桁の和が `self` と等しい最小の数を返す (n進数)
+    fn next_with_digit_sum_radix(self, base: u32) -> Self;
+
+    /// `self` より大きく、各桁の和が `self` と等しい最小の数を返す (n進数, 存在しないか型の範囲を超える場合は `None`)
+    fn checked_next_with_digit_sum_radix(self, base: u32) -> Option<Self>;
+
+    /// 各桁に `f(位置, 数字)` を適用した値の和を返す (n進数, 位置は最下位の桁が 0)
+    fn weighted_digit_sum_radix<F: FnMut(u32, u8) -> i64>(self, f: F, base: u32) -> i64;
+
+    /// 数値を結合する (n進数, 基数が不正か型の範囲を超える場合はその理由を返す)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::{DigitError, Keta};
+    /// assert_eq!(0xfu8.try_concat_radix(0xf, 16), Ok(0xff));
+    /// assert_eq!(0xffu8.try_concat_radix(0xf, 16), Err(DigitError::Overflow));
+    /// assert_eq!(1u8.try_concat_radix(1, 300), Err(DigitError::InvalidRadix(300)));
+    /// ```
+    fn try_concat_radix(self, other: Self, base: u32) -> Result<Self, DigitError>;
+
+    /// 数値を結合する (n進数, 基数が不正か型の範囲を超える場合は `None`)
+    fn checked_concat_radix(self, other: Self, base: u32) -> Option<Self>;
+
+    /// 上位桁から見て各桁が前の桁以上か判定する (n進数)
+    fn has_increasing_digits_radix(self, base: u32) -> bool;
+
+    /// 上位桁から見て各桁が前の桁以下か判定する (n進数)
+    fn has_decreasing_digits_radix(self, base: u32) -> bool;
+
+    /// 隣り合う各桁の差がすべて 1 か判定する (n進数)
+    fn is_stepping_radix(self, base: u32) -> bool;
+
+    /// n進数で `width` 桁にゼロ埋めした表記が回文か判定する
+    ///
+    /// # Panics
+    /// 桁数が `width` を超える場合
+    #[cfg(feature = "alloc")]
+    fn is_palindrome_padded_radix(self, width: usize, base: u32) -> bool;
+
+    /// n進数で上位桁から各桁までの和 (累積和) のベクタを返す
+    #[cfg(feature = "alloc")]
+    fn digit_prefix_sums_radix(self, base: u32) -> Vec<u64>;
+
+    /// 桁を並び替えてできる「最大の数値」を返す (n進数, 型の範囲を超える場合は `None`)
+    fn checked_make_max_radix(self, base: u32) -> Option<Self>;
+
+    /// 桁を並び替えてできる「最小の数値」を返す (n進数, 型の範囲を超える場合は `None`)
+    fn checked_make_min_radix(self, base: u32) -> Option<Self>;
+
+    /// n進数の各桁を上位桁から順に畳み込む
+    fn fold_digits_radix<B, F: FnMut(B, u8) -> B>(self, init: B, f: F, base: u32) -> B;
+
+    /// n進数で1桁の数ならその数字を返す (2桁以上なら `None`)
+    fn as_single_digit_radix(self, base: u32) -> Option<u8>;
+
+    /// 桁が増加も減少もする「弾む数」か判定する (n進数)
+    fn is_bouncy_radix(self, base: u32) -> bool;
+
+    /// n進数で一度だけ桁を分解し、位置を指定した参照を O(1) で行える `DigitView` を返す
+    #[cfg(feature = "alloc")]
+    fn digit_view(self, base: u32) -> DigitView;
+
+    /// n進数の文字列に変換する (`alphabet` の先頭 `base` 文字を 0, 1, ... の数字として使う)
+    ///
+    /// 負の数は先頭に `'-'` を付ける。
+    ///
+    /// # Panics
+    /// `base` が `2..=256` の範囲外、`alphabet` が `base` 文字未満、
+    /// または先頭 `base` 文字に重複がある場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// let b58: Vec<char> = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz".chars().collect();
+    /// assert_eq!(1000u32.to_radix_string_with(58, &b58), "JF");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_radix_string_with(self, base: u32, alphabet: &[char]) -> String;
+
+    /// `to_radix_string_with` と同じ文字集合で書かれた n進数の文字列を数値に変換する
+    ///
+    /// 先頭の `'-'` / `'+'` は、その文字が `alphabet` に含まれない場合だけ符号として扱う。
+    ///
+    /// # Panics
+    /// `to_radix_string_with` と同じ
+    ///
+    /// # Example
+    /// ```
+    /// use keta::Keta;
+    /// let b58: Vec<char> = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz".chars().collect();
+    /// assert_eq!(u32::from_radix_string_with("JF", 58, &b58), Ok(1000));
+    /// ```
+    fn from_radix_string_with(
+        s: &str,
+        base: u32,
+        alphabet: &[char],
+    ) -> Result<Self, ParseKetaError>;
+
+    /// 桁数を変えずに桁を並び替えてできる「最小の数値」を返す (n進数)
+    ///
+    /// # Panics
+    /// 負の数で、結果が型の範囲を超える場合
+    fn smallest_arrangement_fixed_radix(self, base: u32) -> Self;
+
+    /// 桁数を変えずに桁を並び替えてできる「最小の数値」を返す (n進数, 型の範囲を超える場合は `None`)
+    fn checked_smallest_arrangement_fixed_radix(self, base: u32) -> Option<Self>;
+
+    /// 桁数を変えずに桁を並び替えてできる「最大の数値」を返す (n進数)
+    fn largest_arrangement_fixed_radix(self, base: u32) -> Self;
+
+    /// 各桁に重みを掛けた和を `modulus` で割った余り (0 以上) を返す (n進数)
+    fn checksum_mod_radix(self, weights: &[i64], modulus: i64, base: u32) -> i64;
+
+    /// 2種類の数字が交互に並ぶ `abab...` の形 (3桁以上, `a != b`) か判定する (n進数)
+    fn is_undulating_radix(self, base: u32) -> bool;
+
+    /// 数値を結合する (n進数, 型の範囲を超える場合は折り返す)
+    fn wrapping_concat_radix(self, other: Self, base: u32) -> Self;
+
+    /// 桁を並び替えてできる「最大の数値」を返す (n進数, 型の範囲を超える場合は折り返す)
+    fn wrapping_make_max_radix(self, base: u32) -> Self;
+
+    /// n進数で末尾に並ぶ 0 の個数を返す (`base` で割り切れる回数, 0 は 1)
+    fn trailing_zeros_radix(self, base: u32) -> u32;
+
+    /// n進数で `width` 桁にゼロ埋めするときに先頭に補う 0 の個数を返す (桁数が `width` 以上なら 0)
+    fn leading_zeros_radix(self, width: u32, base: u32) -> u32;
+
+    /// n進数の桁を逆順に並べた数字のベクタと、負の数かどうかを返す
+    #[cfg(feature = "alloc")]
+    fn reverse_digits_radix(self, base: u32) -> (Vec<u8>, bool);
+
+    /// n進数で表したときの最大の桁数を返す (符号を除く)
+    fn max_digits_radix(base: u32) -> u32;
+
+    /// 各桁の `power` 乗の和が自分自身と等しいか判定する (n進数)
+    fn is_pdi_radix(self, power: u32, base: u32) -> bool;
+
+    /// n進数の `width` 桁固定幅で桁ごとに足し算し、`width` 桁に切り詰めた和と最上位からの繰り上がりの有無を返す
+    /// (負の数や `width` 桁に収まらない数が含まれる場合、和が型に収まらない場合は `None`)
+    fn add_with_carry(self, other: Self, width: u32, base: u32) -> Option<(Self, bool)>;
+
+    /// n進数の `width` 桁固定幅で桁ごとに引き算し、`width` 桁に切り詰めた差と最上位からの借りの有無を返す
+    /// (負の数や `width` 桁に収まらない数が含まれる場合、差が型に収まらない場合は `None`)
+    fn sub_with_borrow(self, other: Self, width: u32, base: u32) -> Option<(Self, bool)>;
+
+    /// 最も多く現れる数字を返す (n進数, 同数なら小さい方, 0 は `0`)
+    fn most_common_digit_radix(self, base: u32) -> u8;
+
+    /// 現れる数字のうち最も出現回数が少ない数字を返す (n進数, 同数なら小さい方, 0 は `0`)
+    fn least_common_digit_radix(self, base: u32) -> u8;
+
+    /// 桁区切り (`,` / `_` / 空白) を含むn進数の文字列から数値を復元する
+    ///
+    /// 区切り文字は数字と数字の間にだけ置ける。先頭・末尾・連続した区切り文字は
+    /// `ParseKetaError::InvalidDigit` になる。それ以外は `from_radix_string` と同じ。
+    ///
+    /// # Panics
+    /// `base` が `2..=36` の範囲外の場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::{Keta, ParseKetaError};
+    /// assert_eq!(u32::from_grouped_str("1,234,567", 10), Ok(1234567));
+    /// assert_eq!(i64::from_grouped_str("-1_000", 10), Ok(-1000));
+    /// assert_eq!(u32::from_grouped_str(",100", 10), Err(ParseKetaError::InvalidDigit(',')));
+    /// ```
+    fn from_grouped_str(s: &str, base: u32) -> Result<Self, ParseKetaError>;
+
+    /// 反転すると `other` になるか判定する (n進数, 末尾の 0 は失われる)
+    fn is_digit_reverse_of_radix(self, other: Self, base: u32) -> bool;
+
+    /// 数字の並びを逆にすると `other` の数字の並びになるか判定する (n進数, 0 も含めて比べる)
+    fn is_digit_reverse_of_strict_radix(self, other: Self, base: u32) -> bool;
+
+    /// 数値の列を左から順に結合する (n進数, 空の列は `0`)
+    fn concat_many(parts: &[Self], base: u32) -> Self;
+
+    /// 数値の列を左から順に結合する (n進数, 型の範囲を超える場合は `None`)
+    fn checked_concat_many(parts: &[Self], base: u32) -> Option<Self>;
+
+    /// 各桁の数字を `f` で変換した数値を返す (n進数, `f` が `base` 以上の値を返すとパニック)
+    ///
+    /// # Panics
+    /// `f` が `base` 以上の値を返した場合、または結果が型の範囲を超える場合
+    fn map_digits_radix<F: Fn(u8) -> u8>(self, f: F, base: u32) -> Self;
+
+    /// 各桁の数字を `f` で変換した数値を返す (n進数, 型の範囲を超える場合は `None`)
+    fn checked_map_digits_radix<F: Fn(u8) -> u8>(self, f: F, base: u32) -> Option<Self>;
+
+    /// 桁を巡回シフトしてできる数のうち最小のものを返す (n進数, 先頭に 0 が来る回転も含む)
+    ///
+    /// 型の範囲を超える回転は候補に含めない (`max_rotation_radix` も同様)。
+    fn min_rotation_radix(self, base: u32) -> Self;
+
+    /// 桁を巡回シフトしてできる数のうち最大のものを返す (n進数)
+    fn max_rotation_radix(self, base: u32) -> Self;
+
+    /// n進数の各桁の数字を上位桁から順に返すイテレータ (ベクタを確保しない)
+    fn digits_iter_radix(self, base: u32) -> impl Iterator<Item = u8>;
+}
+
+// ----------------------------------------------------------------
+// 符号なし整数専用の操作
+// ----------------------------------------------------------------
+
+/// 符号なし整数だけに意味のある桁操作 (負の数では2進数の表現が定まらないもの)
+pub trait KetaUnsigned: Keta {
+    /// グレイコードに変換する (隣り合う数で2進数の桁が1つだけ変わる並び)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::KetaUnsigned;
+    /// let g: Vec<u8> = (0..4u8).map(|n| n.to_gray()).collect();
+    /// assert_eq!(g, vec![0, 1, 3, 2]);
+    /// ```
+    fn to_gray(self) -> Self;
+
+    /// グレイコードから元の数に戻す (`to_gray` の逆)
+    ///
+    /// # Example
+    /// ```
+    /// use keta::KetaUnsigned;
+    /// assert_eq!(2u8.from_gray(), 3);
+    /// ```
+    // `to_gray` と対になる名前にするため、self を取る from_ を許す
+    #[allow(clippy::wrong_self_convention)]
+    fn from_gray(self) -> Self;
+
+    /// 下位 `width` ビットを固定幅のまま反転する (FFT のビット反転順など, それより上のビットは無視)
+    ///
+    /// `reverse_radix(2)` は先頭の 0 を落として反転するが、こちらは `width` 桁として扱う。
+    ///
+    /// # Panics
+    /// `width` が型のビット数を超える場合
+    ///
+    /// # Example
+    /// ```
+    /// use keta::{Keta, KetaUnsigned};
+    /// assert_eq!(0b001u8.reverse_bits_width(3), 0b100);
+    /// assert_eq!(0b001u8.reverse_radix(2), 0b1);
+    /// ```
+    fn reverse_bits_width(self, width: u32) -> Self;
+}
+
+// ----------------------------------------------------------------
+// 文字列からの変換エラー
+// ----------------------------------------------------------------
+
+/// `from_radix_string` などで文字列を数値に変換できなかった理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseKetaError {
+    /// 入力が空 (符号のみの場合も含む)
+    Empty,
+    /// 数字として解釈できない文字が含まれている
+    InvalidDigit(char),
+    /// 基数以上の値を表す数字が含まれている (例: 16進数での `'g'`)
+    DigitOutOfRange(char),
+    /// 型の範囲に収まらない
+    Overflow,
+}
+
+impl core::fmt::Display for ParseKetaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseKetaError::Empty => write!(f, "cannot parse number from empty string"),
+            ParseKetaError::InvalidDigit(c) => write!(f, "invalid digit {:?} found in string", c),
+            ParseKetaError::DigitOutOfRange(c) => {
+                write!(f, "digit {:?} is out of range for the base", c)
+            }
+            ParseKetaError::Overflow => write!(f, "number too large to fit in target type"),
+        }
+    }
+}
+
+impl core::error::Error for ParseKetaError {}
+
+// ----------------------------------------------------------------
+// 桁操作のエラー
+// ----------------------------------------------------------------
+
+/// `try_concat_radix` などで桁操作の結果が得られなかった理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigitError {
+    /// 基数が 2 未満か、型で表せない
+    InvalidRadix(u32),
+    /// 結果 (または途中で必要な基数の累乗) が型の範囲に収まらない
+    Overflow,
+}
+
+impl core::fmt::Display for DigitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DigitError::InvalidRadix(b) => write!(f, "invalid radix {} for the type", b),
+            DigitError::Overflow => write!(f, "result too large to fit in target type"),
+        }
+    }
+}
+
+impl core::error::Error for DigitError {}
+
+// base == 1 では割り算のループが終わらず、base == 0 では 0 除算になるため先に弾く
+#[inline]
+#[track_caller]
+fn valid_radix(base: u32) -> u32 {
+    assert!(base >= 2, "base must be at least 2 (got {})", base);
+    base
+}
+
+// base^exp をその型の値として求める (基数が型に収まらない場合や溢れる場合はエラー)
+fn checked_radix_pow<T: TryFrom<u128>>(base: u32, exp: u32) -> Result<T, DigitError> {
+    if base < 2 || T::try_from(base as u128).is_err() {
+        return Err(DigitError::InvalidRadix(base));
+    }
+    (base as u128)
+        .checked_pow(exp)
+        .and_then(|p| T::try_from(p).ok())
+        .ok_or(DigitError::Overflow)
+}
+
+// 基数が型に収まらないときの数字列の復元 (どの値も1桁なので、最後以外の桁はすべて 0 でなければならない)
+fn single_digit_from<T: TryFrom<u8>>(digits: &[u8]) -> Option<T> {
+    match digits.split_last() {
+        Some((&last, rest)) if rest.iter().all(|&d| d == 0) => T::try_from(last).ok(),
+        Some(_) => None,
+        None => T::try_from(0).ok(),
+    }
+}
+
+// 1文字を n進数の数字として解釈する
+fn radix_char_value(c: char, base: u32) -> Result<u8, ParseKetaError> {
+    match c.to_digit(36) {
+        Some(d) if d < base => Ok(d as u8),
+        Some(_) => Err(ParseKetaError::DigitOutOfRange(c)),
+        None => Err(ParseKetaError::InvalidDigit(c)),
+    }
+}
+
+// ----------------------------------------------------------------
+// 電話のキーパッド配列 (to_phone_letters / phone_letter_combinations 用)
+// ----------------------------------------------------------------
+#[cfg(feature = "alloc")]
+const PHONE_LETTERS: [&str; 10] = [
+    " ", "", "abc", "def", "ghi", "jkl", "mno", "pqrs", "tuv", "wxyz",
+];
+
+#[cfg(feature = "alloc")]
+// 各桁の文字グループを「オドメーター」のように回して組み合わせを列挙する
+struct PhoneLetterCombinations {
+    groups: Vec<&'static [u8]>,
+    idx: Vec<usize>,
+    done: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl PhoneLetterCombinations {
+    fn new(digits: &[u8]) -> Self {
+        // 文字を持たない桁 (1) は組み合わせに寄与しないので除外する
+        let groups: Vec<&'static [u8]> = digits
+            .iter()
+            .map(|&d| PHONE_LETTERS[d as usize].as_bytes())
+            .filter(|g| !g.is_empty())
+            .collect();
+        let idx = vec![0; groups.len()];
+        PhoneLetterCombinations {
+            groups,
+            idx,
+            done: false,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Iterator for PhoneLetterCombinations {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.done {
+            return None;
+        }
+        let ret = self
+            .groups
+            .iter()
+            .zip(&self.idx)
+            .map(|(g, &i)| g[i] as char)
+            .collect();
+        // 末尾の桁から繰り上げる
+        self.done = true;
+        for k in (0..self.groups.len()).rev() {
+            self.idx[k] += 1;
+            if self.idx[k] < self.groups[k].len() {
+                self.done = false;
+                break;
+            }
+            self.idx[k] = 0;
+        }
+        Some(ret)
+    }
+}
+
+// ----------------------------------------------------------------
+// 回文の構成 (next_palindrome 用, 数字列は上位桁から)
+// ----------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+// 左半分を右半分に写して回文にする
+fn mirror_digits(d: &mut [u8]) {
+    let l = d.len();
+    for i in 0..l / 2 {
+        d[l - 1 - i] = d[i];
+    }
+}
+
+#[cfg(feature = "alloc")]
+// d より大きい最小の回文
+fn next_palindrome_digits(d: &[u8], base: u32) -> Vec<u8> {
+    let l = d.len();
+    let top = (base - 1) as u8;
+    // 999 -> 1001 のように桁数が増える
+    if d.iter().all(|&x| x == top) {
+        let mut ret = vec![0; l + 1];
+        ret[0] = 1;
+        ret[l] = 1;
+        return ret;
+    }
+    let mut ret = d.to_vec();
+    mirror_digits(&mut ret);
+    if ret.as_slice() > d {
+        return ret;
+    }
+    // 左半分 (中央を含む) に 1 を足す。全桁 top でないので先頭から溢れることはない
+    let mut i = (l - 1) / 2;
+    while ret[i] == top {
+        ret[i] = 0;
+        i -= 1;
+    }
+    ret[i] += 1;
+    mirror_digits(&mut ret);
+    ret
+}
+
+#[cfg(feature = "alloc")]
+// d (> 0) より小さい最大の回文
+fn prev_palindrome_digits(d: &[u8], base: u32) -> Vec<u8> {
+    let l = d.len();
+    let top = (base - 1) as u8;
+    let mut ret = d.to_vec();
+    mirror_digits(&mut ret);
+    if ret.as_slice() < d {
+        return ret;
+    }
+    // 左半分 (中央を含む) から 1 を引く。先頭桁は 1 以上なので借りが溢れることはない
+    let mut i = (l - 1) / 2;
+    while ret[i] == 0 {
+        ret[i] = top;
+        i -= 1;
+    }
+    ret[i] -= 1;
+    // 1001 -> 999 のように桁数が減る
+    if ret[0] == 0 {
+        return if l == 1 { vec![0] } else { vec![top; l - 1] };
+    }
+    mirror_digits(&mut ret);
+    ret
+}
+
+// ----------------------------------------------------------------
+// 負の基数 (digits_negative_base / from_digits_negative_base 用)
+// ----------------------------------------------------------------
+
+// 数字は 0..|base| に収まるので、|base| は u8 で表せる 256 まで
+fn valid_negative_base(base: i32) -> i128 {
+    assert!(
+        (-256..=-2).contains(&base),
+        "negative base must be in -256..=-2 (got {})",
+        base
+    );
+    base as i128
+}
+
+// n を負の基数で割り、余りを非負に補正しながら下位桁から積む
+#[cfg(feature = "alloc")]
+fn push_negative_base_digits(mut n: i128, base: i128, ret: &mut Vec<u8>) {
+    while n != 0 {
+        let mut r = n % base;
+        let mut q = n / base;
+        if r < 0 {
+            r -= base;
+            q += 1;
+        }
+        ret.push(r as u8);
+        n = q;
+    }
+}
+
+// 最後の桁を除いた上位桁の値と最後の桁を返す (空なら None)
+// 上位桁の値は最終結果の 1/|base| 程度なので、u128 / i128 の範囲の結果でも i128 に収まる
+fn negative_base_split(digits: &[u8], base: i128) -> Option<(i128, u8)> {
+    let (&last, head) = digits.split_last()?;
+    let mut prefix: i128 = 0;
+    for &d in digits {
+        assert!(
+            (d as i128) < -base,
+            "digit must be less than |base| {} (got {})",
+            -base,
+            d
+        );
+    }
+    for &d in head {
+        prefix = prefix
+            .checked_mul(base)
+            .and_then(|p| p.checked_add(d as i128))
+            .expect("value out of range for the type");
+    }
+    Some((prefix, last))
+}
+
+// ----------------------------------------------------------------
+// カプレカ操作 (kaprekar_step / kaprekar_iterations 用)
+// ----------------------------------------------------------------
+
+// 4 桁 (5 桁以上ならその桁数) にゼロ埋めして「降順の数 - 昇順の数」を求める
+// 降順に並べた数が u128 を超える場合は None
+fn kaprekar_step_u128(n: u128) -> Option<u128> {
+    let mut counts = [0u32; 10];
+    let mut len = 0;
+    let mut m = n;
+    while m > 0 {
+        counts[(m % 10) as usize] += 1;
+        m /= 10;
+        len += 1;
+    }
+    counts[0] += 4u32.saturating_sub(len);
+    let (mut max, mut min) = (0u128, 0u128);
+    for d in (0..10).rev() {
+        for _ in 0..counts[d] {
+            max = max.checked_mul(10)?.checked_add(d as u128)?;
+        }
+    }
+    // 昇順の数は降順の数以下なので溢れない
+    for (d, &c) in counts.iter().enumerate() {
+        for _ in 0..c {
+            min = min * 10 + d as u128;
+        }
+    }
+    Some(max - min)
+}
+
+// ----------------------------------------------------------------
+// 各桁の和を保った前後の数 (next_with_digit_sum 用, u128 で計算する)
+// ----------------------------------------------------------------
+
+// 各桁の和が s になる最小の数 (下位桁から base - 1 を詰める)
+fn min_with_digit_sum_u128(s: u64, base: u128) -> Option<u128> {
+    let m = (base - 1) as u64;
+    let p = base.checked_pow((s / m) as u32)?;
+    // 下位 s / m 桁が base - 1、その上の桁が s % m
+    ((s % m) as u128 + 1).checked_mul(p).map(|x| x - 1)
+}
+
+// n より大きく、各桁の和が n と等しい最小の数
+fn next_with_digit_sum_u128(n: u128, base: u128) -> Option<u128> {
+    if n == 0 {
+        return None;
+    }
+    let mut high = n;
+    let mut low_sum = 0u64;
+    let mut j = 0;
+    // 下位に和が残っていて、かつ 1 増やせる最初の桁 j を探す
+    while low_sum == 0 || high % base == base - 1 {
+        low_sum += (high % base) as u64;
+        high /= base;
+        j += 1;
+    }
+    // 桁 j を 1 増やし、下位 j 桁には残りの和を最小になるよう詰める
+    let low = min_with_digit_sum_u128(low_sum - 1, base)?;
+    (high + 1)
+        .checked_mul(base.checked_pow(j)?)?
+        .checked_add(low)
+}
+
+// n より小さく、各桁の和が n と等しい最大の数
+fn prev_with_digit_sum_u128(n: u128, base: u128) -> Option<u128> {
+    let m = (base - 1) as u64;
+    let mut high = n;
+    let mut low_sum = 0u64;
+    let mut j = 0;
+    // 1 減らせて、その分を下位 j 桁に足す余地がある最初の桁 j を探す
+    loop {
+        if high == 0 {
+            return None;
+        }
+        let d = high % base;
+        if j > 0 && d > 0 && low_sum < j as u64 * m {
+            break;
+        }
+        low_sum += d as u64;
+        high /= base;
+        j += 1;
+    }
+    // 桁 j は n に含まれるので base^j は n 以下に収まる
+    let p = base.pow(j);
+    // 下位 j 桁は和が low_sum + 1 の最大の並び (最小の並びの各桁を base - 1 から引いたもの)
+    let low = (p - 1) - min_with_digit_sum_u128(j as u64 * m - low_sum - 1, base)?;
+    Some((high - 1) * p + low)
+}
+
+// ----------------------------------------------------------------
+// 素因数分解 (is_smith 用)
+// ----------------------------------------------------------------
+
+// 素因数 (重複を含む) それぞれの各桁の和の合計と、素因数の個数を返す (試し割り)
+fn prime_factor_digit_sum(mut n: u128) -> (u64, u32) {
+    let mut sum = 0;
+    let mut cnt = 0;
+    let mut p: u128 = 2;
+    while p <= n / p {
+        while n.is_multiple_of(p) {
+            sum += p.digit_sum();
+            cnt += 1;
+            n /= p;
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        sum += n.digit_sum();
+        cnt += 1;
+    }
+    (sum, cnt)
+}
+
+// ----------------------------------------------------------------
+// 桁 DP (count_with_digit_sum 用)
+// ----------------------------------------------------------------
+
+// u128 の10進数での最大の各桁の和 (39 桁すべて 9)
+const MAX_DIGIT_SUM_U128: usize = 9 * 39;
+
+// 0..=limit のうち各桁の和が target の数を数える (u64 に収まらなければ飽和)
+fn count_with_digit_sum_u128(limit: u128, target: u64) -> u64 {
+    if target > MAX_DIGIT_SUM_U128 as u64 {
+        return 0;
+    }
+    let t = target as usize;
+    // 下位 k 桁について、free[s]: 0..10^k で和が s の数, tight[s]: 0..=(limit の下位 k 桁) で和が s の数
+    let mut free = [0u128; MAX_DIGIT_SUM_U128 + 1];
+    let mut tight = [0u128; MAX_DIGIT_SUM_U128 + 1];
+    free[0] = 1;
+    tight[0] = 1;
+    let mut n = limit;
+    while n > 0 {
+        let d = (n % 10) as usize;
+        for s in (0..=t).rev() {
+            // 今の桁が d 未満なら下位は自由、d ならば下位も limit 以下に制限される
+            let mut lt = 0;
+            let mut all = 0;
+            for x in 0..=s.min(9) {
+                if x < d {
+                    lt += free[s - x];
+                }
+                all += free[s - x];
+            }
+            tight[s] = lt + if s >= d { tight[s - d] } else { 0 };
+            free[s] = all;
+        }
+        n /= 10;
+    }
+    u64::try_from(tight[t]).unwrap_or(u64::MAX)
+}
+
+// ----------------------------------------------------------------
+// 階乗の各桁の和 (factorial_digit_sum 用)
+// ----------------------------------------------------------------
+
+// n! を 10^9 ごとの limb (下位から) で筆算し、各桁の和を求める
+#[cfg(feature = "alloc")]
+fn factorial_digit_sum_limbs(n: u32) -> u64 {
+    const LIMB: u64 = 1_000_000_000;
+    let mut limbs: Vec<u64> = vec![1];
+    for k in 2..=n as u64 {
+        // limb < 10^9, k < 2^32 なので積と繰り上がりの和は u64 に収まる
+        let mut carry = 0;
+        for l in limbs.iter_mut() {
+            let v = *l * k + carry;
+            *l = v % LIMB;
+            carry = v / LIMB;
+        }
+        while carry > 0 {
+            limbs.push(carry % LIMB);
+            carry /= LIMB;
+        }
+    }
+    limbs.iter().map(|l| l.digit_sum()).sum()
+}
+
+// ----------------------------------------------------------------
+// 任意の数字の文字集合 (to_radix_string_with / from_radix_string_with 用)
+// ----------------------------------------------------------------
+
+// アルファベットの先頭 base 文字を数字として使えるか検査する
+#[track_caller]
+fn valid_alphabet(alphabet: &[char], base: u32) {
+    assert!(
+        (2..=256).contains(&base),
+        "base must be in 2..=256 (got {})",
+        base
+    );
+    assert!(
+        alphabet.len() >= base as usize,
+        "alphabet must have at least {} characters (got {})",
+        base,
+        alphabet.len()
+    );
+    let used = &alphabet[..base as usize];
+    for (i, c) in used.iter().enumerate() {
+        assert!(
+            !used[..i].contains(c),
+            "duplicate character {:?} in alphabet",
+            c
+        );
+    }
+}
+
+// 文字から数字への逆引き (ASCII は表で引き、それ以外はアルファベットを線形探索する)
+struct AlphabetIndex<'a> {
+    alphabet: &'a [char],
+    base: u32,
+    ascii: [Option<u16>; 128],
+}
+
+impl<'a> AlphabetIndex<'a> {
+    #[track_caller]
+    fn new(alphabet: &'a [char], base: u32) -> Self {
+        valid_alphabet(alphabet, base);
+        let mut ascii = [None; 128];
+        // 重複があれば先に現れた位置を優先する
+        for (i, &c) in alphabet.iter().enumerate().rev() {
+            if c.is_ascii() {
+                ascii[c as usize] = Some(i as u16);
+            }
+        }
+        AlphabetIndex {
+            alphabet,
+            base,
+            ascii,
+        }
+    }
+
+    fn position(&self, c: char) -> Option<usize> {
+        if c.is_ascii() {
+            self.ascii[c as usize].map(|i| i as usize)
+        } else {
+            self.alphabet.iter().position(|&a| a == c)
+        }
+    }
+
+    fn value(&self, c: char) -> Result<u8, ParseKetaError> {
+        match self.position(c) {
+            Some(i) if i < self.base as usize => Ok(i as u8),
+            Some(_) => Err(ParseKetaError::DigitOutOfRange(c)),
+            None => Err(ParseKetaError::InvalidDigit(c)),
+        }
+    }
+
+    // 符号の文字がアルファベットに含まれる場合 (base64 の '+' など) は数字として扱う
+    fn strip_sign<'s>(&self, s: &'s str, sign: char) -> Option<&'s str> {
+        if self.position(sign).is_some() {
+            None
+        } else {
+            s.strip_prefix(sign)
+        }
+    }
+}
+
+// ----------------------------------------------------------------
+// 各桁の和の表 (digit_sum / digit_sum_batch 用)
+// ----------------------------------------------------------------
+
+// 0..1000 の各桁の和 (3 桁ずつ区切って引くための表, 1 KB でキャッシュに収まる)
+static DIGIT_SUM_1K: [u8; 1000] = {
+    let mut t = [0; 1000];
+    let mut i = 0;
+    while i < 1000 {
+        t[i] = cst::digit_sum_u64(i as u64) as u8;
+        i += 1;
+    }
+    t
+};
+
+// 3 桁ずつ表を引いて各桁の和を求める
+fn digit_sum_u64_table(mut n: u64) -> u64 {
+    let mut sum = 0;
+    while n >= 1000 {
+        sum += DIGIT_SUM_1K[(n % 1000) as usize] as u64;
+        n /= 1000;
+    }
+    sum + DIGIT_SUM_1K[n as usize] as u64
+}
+
+// u64 に収まるまでは u128 で割り、残りは u64 の割り算で済ませる
+fn digit_sum_u128_table(mut n: u128) -> u64 {
+    let mut sum = 0;
+    while n > u64::MAX as u128 {
+        sum += DIGIT_SUM_1K[(n % 1000) as usize] as u64;
+        n /= 1000;
+    }
+    sum + digit_sum_u64_table(n as u64)
+}
+
+// ----------------------------------------------------------------
+// 回文の生成 (palindromes_in_range 用)
+// ----------------------------------------------------------------
+
+// 上半分 prefix を鏡映して回文を作る (odd なら中央の桁を重ねない)
+fn mirror_palindrome(prefix: u128, odd: bool, b: u128) -> u128 {
+    let mut ret = prefix;
+    let mut q = if odd { prefix / b } else { prefix };
+    while q > 0 {
+        ret = ret * b + q % b;
+        q /= b;
+    }
+    ret
+}
+
+// ----------------------------------------------------------------
+// 最頻・最少の数字 (most_common_digit / least_common_digit 用)
+// ----------------------------------------------------------------
+
+// 出現回数が最も多い数字 (同数なら小さい方)
+fn most_common_in(counts: &[u32]) -> u8 {
+    let mut best = 0;
+    for (d, &c) in counts.iter().enumerate() {
+        if c > counts[best] {
+            best = d;
+        }
+    }
+    best as u8
+}
+
+// 出現する数字のうち出現回数が最も少ない数字 (同数なら小さい方)
+fn least_common_in(counts: &[u32]) -> u8 {
+    let mut best: Option<usize> = None;
+    for (d, &c) in counts.iter().enumerate() {
+        if c > 0 && best.is_none_or(|b| c < counts[b]) {
+            best = Some(d);
+        }
+    }
+    best.unwrap_or(0) as u8
+}
+
+// ----------------------------------------------------------------
+// 区切り文字付きの文字列 (from_grouped_str 用)
+// ----------------------------------------------------------------
+
+// 桁区切りとして読み飛ばす文字
+const GROUP_SEPARATORS: [char; 3] = [',', '_', ' '];
+
+// 区切り文字が数字と数字の間にだけあることを確かめ、区切り文字を除いた文字列を返す
+fn strip_group_separators(body: &str) -> Result<impl Iterator<Item = char> + '_, ParseKetaError> {
+    let mut prev_sep = true;
+    for c in body.chars() {
+        let sep = GROUP_SEPARATORS.contains(&c);
+        if sep && prev_sep {
+            return Err(ParseKetaError::InvalidDigit(c));
+        }
+        prev_sep = sep;
+    }
+    if let Some(c) = body.chars().last().filter(|_| prev_sep) {
+        return Err(ParseKetaError::InvalidDigit(c));
+    }
+    Ok(body.chars().filter(|c| !GROUP_SEPARATORS.contains(c)))
+}
+
+// ----------------------------------------------------------------
+// 素数判定 (is_emirp 用)
+// ----------------------------------------------------------------
+
+// 6k ± 1 の形の数で試し割りする
+fn is_prime_u128(n: u128) -> bool {
+    if n < 4 {
+        return n >= 2;
+    }
+    if n.is_multiple_of(2) || n.is_multiple_of(3) {
+        return false;
+    }
+    let mut i = 5;
+    while i <= n / i {
+        if n.is_multiple_of(i) || n.is_multiple_of(i + 2) {
+            return false;
+        }
+        i += 6;
+    }
+    true
+}
+
+// 10進数で反転した数が自分と異なる素数になる素数か (反転が u128 に収まらない場合は false)
+fn is_emirp_u128(n: u128) -> bool {
+    let mut m = n;
+    let mut r: u128 = 0;
+    while m > 0 {
+        r = match r.checked_mul(10).and_then(|r| r.checked_add(m % 10)) {
+            Some(r) => r,
+            None => return false,
+        };
+        m /= 10;
+    }
+    r != n && is_prime_u128(n) && is_prime_u128(r)
+}
+
+// ----------------------------------------------------------------
+// 吸血鬼数 (vampire_fangs 用)
+// ----------------------------------------------------------------
+
+// 桁数が半分ずつの2つの因数 (牙) で、数字の多重集合が n と一致するものを小さい順に探す
+fn vampire_fangs_u128(n: u128) -> Option<(u128, u128)> {
+    let len = n.digits_len();
+    if n == 0 || !len.is_multiple_of(2) {
+        return None;
+    }
+    let half = len / 2;
+    let counts = n.digit_counts();
+    // y <= 10^half - 1 なので x >= ceil(n / (10^half - 1)) から探せばよい。
+    // x <= y となる範囲だけを見るので、y も half 桁に収まる
+    let mut x = 10u128.pow(half - 1).max(n.div_ceil(10u128.pow(half) - 1));
+    // 数字の多重集合が一致するなら x + y ≡ n (mod 9) で、x * y = n と合わせると
+    // x * (n - x) ≡ n (mod 9) が必要になる。割り算の前に x だけで候補を落とせる
+    let r = n % 9;
+    while x <= n / x {
+        let rx = x % 9;
+        if rx * ((r + 9 - rx) % 9) % 9 == r && n.is_multiple_of(x) {
+            let y = n / x;
+            if !(x.is_multiple_of(10) && y.is_multiple_of(10)) {
+                let (cx, cy) = (x.digit_counts(), y.digit_counts());
+                if (0..10).all(|d| cx[d] + cy[d] == counts[d]) {
+                    return Some((x, y));
+                }
+            }
+        }
+        x += 1;
+    }
+    None
+}
+
+// ----------------------------------------------------------------
+// 実装用マクロ (符号なし整数用: u32, u64...)
+// ----------------------------------------------------------------
+macro_rules! impl_keta_uint {
+    ($($t:ty),*) => {
+        $(
+            impl KetaUnsigned for $t {
+                fn to_gray(self) -> Self {
+                    self ^ (self >> 1)
+                }
+
+                fn from_gray(self) -> Self {
+                    // 上位ビットからの累積 XOR を、シフト幅を倍々にして log(BITS) 回で求める
+                    let mut n = self;
+                    let mut shift = 1;
+                    while shift < <$t>::BITS {
+                        n ^= n >> shift;
+                        shift <<= 1;
+                    }
+                    n
+                }
+
+                fn reverse_bits_width(self, width: u32) -> Self {
+                    assert!(
+                        width <= <$t>::BITS,
+                        "width {} exceeds bit size {}",
+                        width,
+                        <$t>::BITS
+                    );
+                    if width == 0 { return 0; }
+                    // 全体を反転すると下位 width ビットが上端に来るので、下へずらす
+                    self.reverse_bits() >> (<$t>::BITS - width)
+                }
+            }
+
+            impl Keta for $t {
+                // --- 10-base Shortcuts (Optimized) ---
+
+                const MAX_DIGITS: u32 = <$t>::MAX.ilog10() + 1;
+
+                #[cfg(feature = "alloc")]
+                fn digits(self) -> Vec<u8> {
+                    if self == 0 { return vec![0]; }
+                    let mut n = self;
+                    // ilog10で正確な容量を計算 (再アロケーション防止)
+                    let cap = (self.ilog10() + 1) as usize;
+                    let mut ret = Vec::with_capacity(cap);
+                    while n > 0 {
+                        ret.push((n % 10) as u8);
+                        n /= 10;
+                    }
+                    ret.reverse();
+                    ret
+                }
+
+                fn from_digits(digits: &[u8]) -> Self {
+                    Self::from_digits_radix(digits, 10)
+                }
+
+                fn digit_sum(self) -> u64 {
+                    // 1桁ずつ割る代わりに 3 桁ずつ表を引く (分岐はコンパイル時に決まる)
+                    if <$t>::BITS <= 64 {
+                        digit_sum_u64_table(self as u64)
+                    } else {
+                        digit_sum_u128_table(self as u128)
+                    }
+                }
+
+                fn digit_product(self) -> u64 {
+                    if self == 0 { return 0; }
+                    let mut n = self;
+                    let mut prod: u64 = 1;
+                    while n > 0 {
+                        prod *= (n % 10) as u64;
+                        n /= 10;
+                    }
+                    prod
+                }
+
+                fn digits_len(self) -> u32 {
+                    if self == 0 { return 1; }
+                    self.ilog10() + 1
+                }
+
+                fn reverse(self) -> Self {
+                    let mut n = self;
+                    let mut ret: $t = 0;
+                    while n > 0 {
+                        ret = ret * 10 + (n % 10);
+                        n /= 10;
+                    }
+                    ret
+                }
+
+                fn is_palindrome(self) -> bool {
+                    self == self.reverse()
+                }
+
+                fn nth_digit(self, i: u32) -> Option<u8> {
+                    let l = self.digits_len();
+                    if i >= l { return None; }
+                    let pow = l - 1 - i;
+                    Some(((self / (10 as $t).pow(pow)) % 10) as u8)
+                }
+
+                fn concat(self, other: Self) -> Self {
+                    self.concat_radix(other, 10)
+                }
+
+                fn contains_digit(self, digit: u8) -> bool {
+                    if self == 0 { return digit == 0; }
+                    let mut n = self;
+                    while n > 0 {
+                        if (n % 10) as u8 == digit {
+                            return true;
+                        }
+                        n /= 10;
+                    }
+                    false
+                }
+
+                // O(N) Algorithm: Frequency Distribution
+                fn make_max(self) -> Self {
+                    let counts = self.digit_counts();
+                    let mut ret: $t = 0;
+                    for d in (0..=9).rev() {
+                        for _ in 0..counts[d] {
+                            ret *= 10;
+                            ret += d as $t;
+                        }
+                    }
+                    ret
+                }
+
+                // O(N) Algorithm: Frequency Distribution
+                fn make_min(self) -> Self {
+                    let counts = self.digit_counts();
+                    let mut ret: $t = 0;
+                    for d in 0..=9 {
+                        for _ in 0..counts[d] {
+                            ret *= 10;
+                            ret += d as $t;
+                        }
+                    }
+                    ret
+                }
+
+                fn signed_digit_sum_pattern(self, signs: &[i8]) -> i64 {
+                    if signs.is_empty() { return 0; }
+                    let mut n = self;
+                    let mut sum: i64 = 0;
+                    let mut i = 0;
+                    while n > 0 {
+                        sum += (n % 10) as i64 * signs[i % signs.len()] as i64;
+                        n /= 10;
+                        i += 1;
+                    }
+                    sum
+                }
+
+                fn count_digit(self, digit: u8) -> u32 {
+                    if self == 0 { return (digit == 0) as u32; }
+                    let mut n = self;
+                    let mut cnt = 0;
+                    while n > 0 {
+                        if (n % 10) as u8 == digit {
+                            cnt += 1;
+                        }
+                        n /= 10;
+                    }
+                    cnt
+                }
+
+                #[cfg(feature = "alloc")]
+                fn to_phone_letters(self) -> Vec<&'static str> {
+                    self.digits().into_iter().map(|d| PHONE_LETTERS[d as usize]).collect()
+                }
+
+                #[cfg(feature = "alloc")]
+                fn phone_letter_combinations(self) -> impl Iterator<Item = String> {
+                    PhoneLetterCombinations::new(&self.digits())
+                }
+
+                fn digit_counts(self) -> [u32; 10] {
+                    let mut counts = [0u32; 10];
+                    if self == 0 {
+                        counts[0] = 1;
+                        return counts;
+                    }
+                    let mut n = self;
+                    while n > 0 {
+                        counts[(n % 10) as usize] += 1;
+                        n /= 10;
+                    }
+                    counts
+                }
+
+                #[cfg(feature = "alloc")]
+                fn digit_positions(self, digit: u8) -> Vec<u32> {
+                    if self == 0 {
+                        return if digit == 0 { vec![0] } else { Vec::new() };
+                    }
+                    let mut n = self;
+                    let mut i = self.digits_len();
+                    let mut ret = Vec::new();
+                    // 下位桁から走査するので、最後に反転して昇順にする
+                    while n > 0 {
+                        i -= 1;
+                        if (n % 10) as u8 == digit {
+                            ret.push(i);
+                        }
+                        n /= 10;
+                    }
+                    ret.reverse();
+                    ret
+                }
+
+                fn is_pandigital(self) -> bool {
+                    self.digit_counts() == [1; 10]
+                }
+
+                fn is_pandigital_1_to_n(self) -> bool {
+                    let l = self.digits_len() as usize;
+                    if l > 9 { return false; }
+                    let counts = self.digit_counts();
+                    counts[0] == 0 && counts[1..=l].iter().all(|&c| c == 1)
+                }
+
+                fn rotate_digits_left(self, n: u32) -> Self {
+                    self.checked_rotate_digits_left(n).expect("rotated value overflows the type")
+                }
+
+                fn rotate_digits_right(self, n: u32) -> Self {
+                    self.checked_rotate_digits_right(n).expect("rotated value overflows the type")
+                }
+
+                fn checked_rotate_digits_left(self, n: u32) -> Option<Self> {
+                    let l = self.digits_len();
+                    let k = n % l;
+                    if k == 0 { return Some(self); }
+                    // 上位 k 桁 (head) と下位 l-k 桁 (tail) を入れ替える
+                    let p = (10 as $t).pow(l - k);
+                    let head = self / p;
+                    let tail = self % p;
+                    tail.checked_mul((10 as $t).pow(k))?.checked_add(head)
+                }
+
+                fn checked_rotate_digits_right(self, n: u32) -> Option<Self> {
+                    let l = self.digits_len();
+                    self.checked_rotate_digits_left(l - n % l)
+                }
+
+                fn digit_square_sum(self) -> u64 {
+                    let mut n = self;
+                    let mut sum: u64 = 0;
+                    while n > 0 {
+                        let d = (n % 10) as u64;
+                        sum += d * d;
+                        n /= 10;
+                    }
+                    sum
+                }
+
+                // Floyd の循環検出: 1 は不動点なので、合流点が 1 ならハッピー数
+                fn is_happy(self) -> bool {
+                    let mut slow = self.digit_square_sum();
+                    let mut fast = slow.digit_square_sum();
+                    while slow != fast {
+                        slow = slow.digit_square_sum();
+                        fast = fast.digit_square_sum().digit_square_sum();
+                    }
+                    slow == 1
+                }
+
+                fn is_armstrong(self) -> bool {
+                    let l = self.digits_len();
+                    let target = self as u128;
+                    let mut n = self;
+                    let mut sum: u128 = 0;
+                    while n > 0 {
+                        let d = (n % 10) as u128;
+                        sum = match d.checked_pow(l).and_then(|p| sum.checked_add(p)) {
+                            Some(s) if s <= target => s,
+                            _ => return false,
+                        };
+                        n /= 10;
+                    }
+                    sum == target
+                }
+
+                fn is_harshad(self) -> bool {
+                    // 桁和は元の数以下なので $t に収まる
+                    let s = self.digit_sum();
+                    s != 0 && self % (s as $t) == 0
+                }
+
+                fn try_from_digits(digits: &[u8]) -> Option<Self> {
+                    Self::try_from_digits_radix(digits, 10)
+                }
+
+                fn additive_persistence(self) -> u32 {
+                    if self.digits_len() == 1 { return 0; }
+                    let mut n = self.digit_sum();
+                    let mut cnt = 1;
+                    while n >= 10 {
+                        n = n.digit_sum();
+                        cnt += 1;
+                    }
+                    cnt
+                }
+
+                fn multiplicative_persistence(self) -> u32 {
+                    if self.digits_len() == 1 { return 0; }
+                    let mut n = self.digit_product();
+                    let mut cnt = 1;
+                    while n >= 10 {
+                        n = n.digit_product();
+                        cnt += 1;
+                    }
+                    cnt
+                }
+
+                fn digit_slice(self, start: u32, len: u32) -> Self {
+                    let l = self.digits_len();
+                    // len == 0 のまま進むと 10^l を求めてしまい、最大桁数の値で溢れる
+                    if start >= l || len == 0 { return 0; }
+                    let end = l.min(start.saturating_add(len));
+                    // 下位 l-end 桁を落とした後、start > 0 なら上位 start 桁を落とす
+                    let n = self / (10 as $t).pow(l - end);
+                    if start == 0 { n } else { n % (10 as $t).pow(end - start) }
+                }
+
+                fn with_digit(self, i: u32, digit: u8) -> Self {
+                    self.checked_with_digit(i, digit).expect("with_digit result overflows the type")
+                }
+
+                fn checked_with_digit(self, i: u32, digit: u8) -> Option<Self> {
+                    assert!(digit < 10, "digit must be less than 10 (got {})", digit);
+                    let l = self.digits_len();
+                    assert!(i < l, "digit index out of range: {} >= {}", i, l);
+                    let p = (10 as $t).pow(l - 1 - i);
+                    let old = (self / p) % 10;
+                    // 元の桁を引いた値は溢れないので、足す側だけ検査する
+                    (digit as $t).checked_mul(p).and_then(|d| (self - old * p).checked_add(d))
+                }
+
+                fn luhn_is_valid(self) -> bool {
+                    let mut n = self;
+                    let mut sum: u64 = 0;
+                    let mut double = false;
+                    while n > 0 {
+                        let mut d = (n % 10) as u64;
+                        if double {
+                            d *= 2;
+                            if d > 9 { d -= 9; }
+                        }
+                        sum += d;
+                        double = !double;
+                        n /= 10;
+                    }
+                    sum % 10 == 0
+                }
+
+                fn luhn_check_digit(self) -> u8 {
+                    // 検査数字が付くと一の位から1つずれるので、一の位から2倍する
+                    let mut n = self;
+                    let mut sum: u64 = 0;
+                    let mut double = true;
+                    while n > 0 {
+                        let mut d = (n % 10) as u64;
+                        if double {
+                            d *= 2;
+                            if d > 9 { d -= 9; }
+                        }
+                        sum += d;
+                        double = !double;
+                        n /= 10;
+                    }
+                    ((10 - sum % 10) % 10) as u8
+                }
+
+                fn is_automorphic(self) -> bool {
+                    // n^2 ≡ n (mod 10^L) ⇔ n(n-1) が 2^L と 5^L の両方で割り切れる。
+                    // n と n-1 は互いに素なので、それぞれどちらか一方が割り切れればよい。
+                    // 2乗を計算しないので u128 でもオーバーフローしない
+                    let l = self.digits_len();
+                    let n = self as u128;
+                    let (p2, p5) = (2u128.pow(l), 5u128.pow(l));
+                    (n % p2 == 0 || (n - 1) % p2 == 0) && (n % p5 == 0 || (n - 1) % p5 == 0)
+                }
+
+                fn nth_digit_from_right(self, i: u32) -> Option<u8> {
+                    if self == 0 { return if i == 0 { Some(0) } else { None }; }
+                    let mut n = self;
+                    for _ in 0..i {
+                        n /= 10;
+                        if n == 0 { return None; }
+                    }
+                    Some((n % 10) as u8)
+                }
+
+                fn max_digit(self) -> u8 {
+                    let mut n = self;
+                    let mut ret = 0;
+                    while n > 0 {
+                        ret = ret.max((n % 10) as u8);
+                        if ret == 9 { break; }
+                        n /= 10;
+                    }
+                    ret
+                }
+
+                fn min_digit(self) -> u8 {
+                    if self == 0 { return 0; }
+                    let mut n = self;
+                    let mut ret = 9;
+                    while n > 0 {
+                        ret = ret.min((n % 10) as u8);
+                        if ret == 0 { break; }
+                        n /= 10;
+                    }
+                    ret
+                }
+
+                fn distinct_digit_count(self) -> u32 {
+                    if self == 0 { return 1; }
+                    let mut n = self;
+                    let mut seen: u16 = 0;
+                    while n > 0 {
+                        seen |= 1 << (n % 10);
+                        n /= 10;
+                    }
+                    seen.count_ones()
+                }
+
+                fn is_repdigit(self) -> bool {
+                    self.distinct_digit_count() == 1
+                }
+
+                fn is_repunit(self) -> bool {
+                    if self == 0 { return false; }
+                    let mut n = self;
+                    while n > 0 {
+                        if n % 10 != 1 { return false; }
+                        n /= 10;
+                    }
+                    true
+                }
+
+                #[cfg(feature = "alloc")]
+                fn next_palindrome(self) -> Self {
+                    self.next_palindrome_radix(10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn checked_next_palindrome(self) -> Option<Self> {
+                    self.checked_next_palindrome_radix(10)
+                }
+
+                fn digit_power_sum(self, exp: u32) -> u128 {
+                    let mut n = self;
+                    let mut sum: u128 = 0;
+                    while n > 0 {
+                        let d = (n % 10) as u128;
+                        sum = sum.saturating_add(d.saturating_pow(exp));
+                        n /= 10;
+                    }
+                    sum
+                }
+
+                fn checked_reverse(self) -> Option<Self> {
+                    self.checked_reverse_radix(10)
+                }
+
+                fn saturating_reverse(self) -> Self {
+                    self.saturating_reverse_radix(10)
+                }
+
+                fn wrapping_reverse(self) -> Self {
+                    self.wrapping_reverse_radix(10)
+                }
+
+                fn checked_digit_product(self) -> Option<u64> {
+                    self.checked_digit_product_radix(10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn digits_padded(self, width: usize) -> Vec<u8> {
+                    self.digits_padded_radix(width, 10)
+                }
+
+                fn interleave(self, other: Self) -> Self {
+                    self.interleave_radix(other, 10)
+                }
+
+                fn checked_interleave(self, other: Self) -> Option<Self> {
+                    self.checked_interleave_radix(other, 10)
+                }
+
+                fn split_at(self, i: u32) -> (Self, Self) {
+                    self.split_at_radix(i, 10)
+                }
+
+                fn remove_digit(self, digit: u8) -> Self {
+                    self.remove_digit_radix(digit, 10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn to_balanced_ternary(self) -> Vec<i8> {
+                    if self == 0 { return vec![0]; }
+                    let mut n = self;
+                    let mut ret = Vec::new();
+                    while n != 0 {
+                        // 余りが 2 なら -1 とし、上の桁に 1 繰り上げる
+                        // (div_euclid と rem_euclid を使うので負の数も MIN まで扱える)
+                        let r = n.rem_euclid(3);
+                        if r == 2 {
+                            ret.push(-1);
+                            n = n.div_euclid(3) + 1;
+                        } else {
+                            ret.push(r as i8);
+                            n = n.div_euclid(3);
+                        }
+                    }
+                    ret.reverse();
+                    ret
+                }
+
+                fn from_balanced_ternary(digits: &[i8]) -> Self {
+                    let mut ret: $t = 0;
+                    for &d in digits {
+                        ret = match d {
+                            // 3 * ret - 1 を MAX 付近でも溢れないよう (ret - 1) * 3 + 2 で計算する
+                            -1 => ret.checked_sub(1).expect("balanced ternary value is negative") * 3 + 2,
+                            0 => ret * 3,
+                            1 => ret * 3 + 1,
+                            _ => panic!("balanced ternary digit must be -1, 0 or 1 (got {})", d),
+                        };
+                    }
+                    ret
+                }
+
+                #[cfg(feature = "alloc")]
+                fn digits_negative_base(self, base: i32) -> Vec<u8> {
+                    let b = valid_negative_base(base);
+                    if self == 0 { return vec![0]; }
+                    // 最初の1桁だけ u128 で処理すれば、以降の値は i128 に収まる
+                    let n = self as u128;
+                    let m = b.unsigned_abs();
+                    let mut ret = vec![(n % m) as u8];
+                    push_negative_base_digits(-((n / m) as i128), b, &mut ret);
+                    ret.reverse();
+                    ret
+                }
+
+                fn from_digits_negative_base(digits: &[u8], base: i32) -> Self {
+                    let b = valid_negative_base(base);
+                    let Some((prefix, last)) = negative_base_split(digits, b) else { return 0; };
+                    // 値 = prefix * base + last が非負になるのは prefix <= 0 のときだけ
+                    prefix
+                        .checked_neg()
+                        .and_then(|p| u128::try_from(p).ok())
+                        .and_then(|p| p.checked_mul(b.unsigned_abs()))
+                        .and_then(|v| v.checked_add(last as u128))
+                        .and_then(|v| <$t>::try_from(v).ok())
+                        .expect("value out of range for the type")
+                }
+
+                fn kaprekar_step(self) -> Self {
+                    kaprekar_step_u128(self as u128)
+                        .and_then(|n| <$t>::try_from(n).ok())
+                        .expect("kaprekar step overflows the type")
+                }
+
+                fn kaprekar_iterations(self) -> Option<u32> {
+                    let mut n = self as u128;
+                    if n > 9999 { return None; }
+                    let mut cnt = 0;
+                    while n != 6174 {
+                        if n == 0 { return None; }
+                        n = kaprekar_step_u128(n)?;
+                        cnt += 1;
+                    }
+                    Some(cnt)
+                }
+
+                fn digit_cmp(self, other: Self) -> Ordering {
+                    self.digit_cmp_radix(other, 10)
+                }
+
+                fn cmp_by_digit_sum(self, other: Self) -> Ordering {
+                    self.cmp_by_digit_sum_radix(other, 10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn group_digits(self, sep: char, group: usize) -> String {
+                    self.group_digits_radix(sep, group, 10)
+                }
+
+                fn sum_of_digit_sums<I: IntoIterator<Item = Self>>(iter: I) -> u64 {
+                    iter.into_iter().map(|n| n.digit_sum()).sum()
+                }
+
+                fn is_munchausen(self) -> bool {
+                    self.is_munchausen_radix(10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn sort_digits(self, ascending: bool) -> Vec<u8> {
+                    self.sort_digits_radix(ascending, 10)
+                }
+
+                fn digit_sum_mod(self, m: u64) -> u64 {
+                    self.digit_sum_mod_radix(m, 10)
+                }
+
+                fn casting_out_nines(self) -> u8 {
+                    // 各桁の和と元の数は 9 で割った余りが等しい
+                    if self == 0 { return 0; }
+                    match self % 9 {
+                        0 => 9,
+                        r => r as u8,
+                    }
+                }
+
+                #[cfg(feature = "alloc")]
+                fn signed_digits(self) -> (bool, Vec<u8>) {
+                    self.signed_digits_radix(10)
+                }
+
+                fn next_with_digit_sum(self) -> Self {
+                    self.next_with_digit_sum_radix(10)
+                }
+
+                fn checked_next_with_digit_sum(self) -> Option<Self> {
+                    self.checked_next_with_digit_sum_radix(10)
+                }
+
+                fn is_smith(self) -> bool {
+                    if self < 4 { return false; }
+                    let (sum, cnt) = prime_factor_digit_sum(self as u128);
+                    cnt >= 2 && sum == self.digit_sum()
+                }
+
+                fn reverse_add_steps(self, max_iters: u32) -> Option<u32> {
+                    let mut n = self;
+                    for step in 1..=max_iters {
+                        n = n.checked_add(n.checked_reverse()?)?;
+                        // 回文は反転しても同じ値なので、反転が溢れるなら回文ではない
+                        if n.checked_reverse() == Some(n) { return Some(step); }
+                    }
+                    None
+                }
+
+                fn popcount(self) -> u32 {
+                    self.count_ones()
+                }
+
+                fn bit_len(self) -> u32 {
+                    (<$t>::BITS - self.leading_zeros()).max(1)
+                }
+
+                fn weighted_digit_sum<F: FnMut(u32, u8) -> i64>(self, f: F) -> i64 {
+                    self.weighted_digit_sum_radix(f, 10)
+                }
+
+                fn checked_concat(self, other: Self) -> Option<Self> {
+                    self.checked_concat_radix(other, 10)
+                }
+
+                fn has_increasing_digits(self) -> bool {
+                    self.has_increasing_digits_radix(10)
+                }
+
+                fn has_decreasing_digits(self) -> bool {
+                    self.has_decreasing_digits_radix(10)
+                }
+
+                fn is_stepping(self) -> bool {
+                    self.is_stepping_radix(10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn is_palindrome_padded(self, width: usize) -> bool {
+                    self.is_palindrome_padded_radix(width, 10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn digit_prefix_sums(self) -> Vec<u64> {
+                    self.digit_prefix_sums_radix(10)
+                }
+
+                fn checked_make_max(self) -> Option<Self> {
+                    self.checked_make_max_radix(10)
+                }
+
+                fn checked_make_min(self) -> Option<Self> {
+                    self.checked_make_min_radix(10)
+                }
+
+                fn count_with_digit_sum(limit: Self, target: u64) -> u64 {
+                    count_with_digit_sum_u128(limit as u128, target)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn to_bcd(self) -> Vec<u8> {
+                    let mut d = self.digits();
+                    if d.len() % 2 == 1 { d.insert(0, 0); }
+                    d.chunks(2).map(|c| (c[0] << 4) | c[1]).collect()
+                }
+
+                fn from_bcd(bytes: &[u8]) -> Self {
+                    let mut ret: $t = 0;
+                    for &byte in bytes {
+                        for nib in [byte >> 4, byte & 0x0f] {
+                            assert!(nib <= 9, "invalid BCD nibble {:#x}", nib);
+                            ret = ret * 10 + nib as $t;
+                        }
+                    }
+                    ret
+                }
+
+                fn fold_digits<B, F: FnMut(B, u8) -> B>(self, init: B, f: F) -> B {
+                    self.fold_digits_radix(init, f, 10)
+                }
+
+                fn as_single_digit(self) -> Option<u8> {
+                    self.as_single_digit_radix(10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn factorial_digit_sum(n: u32) -> u64 {
+                    factorial_digit_sum_limbs(n)
+                }
+
+                fn is_bouncy(self) -> bool {
+                    self.is_bouncy_radix(10)
+                }
+
+                fn smallest_arrangement_fixed(self) -> Self {
+                    self.smallest_arrangement_fixed_radix(10)
+                }
+
+                fn checked_smallest_arrangement_fixed(self) -> Option<Self> {
+                    self.checked_smallest_arrangement_fixed_radix(10)
+                }
+
+                fn largest_arrangement_fixed(self) -> Self {
+                    self.largest_arrangement_fixed_radix(10)
+                }
+
+                fn checksum_mod(self, weights: &[i64], modulus: i64) -> i64 {
+                    self.checksum_mod_radix(weights, modulus, 10)
+                }
+
+                fn is_undulating(self) -> bool {
+                    self.is_undulating_radix(10)
+                }
+
+                fn wrapping_concat(self, other: Self) -> Self {
+                    self.wrapping_concat_radix(other, 10)
+                }
+
+                fn wrapping_make_max(self) -> Self {
+                    self.wrapping_make_max_radix(10)
+                }
+
+                fn trailing_zeros_10(self) -> u32 {
+                    self.trailing_zeros_radix(10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn reverse_digits(self) -> (Vec<u8>, bool) {
+                    self.reverse_digits_radix(10)
+                }
+
+                fn is_pdi(self, power: u32) -> bool {
+                    self.is_pdi_radix(power, 10)
+                }
+
+                fn most_common_digit(self) -> u8 {
+                    most_common_in(&self.digit_counts())
+                }
+
+                fn least_common_digit(self) -> u8 {
+                    least_common_in(&self.digit_counts())
+                }
+
+                fn digit_sum_trajectory(self) -> impl Iterator<Item = u64> {
+                    let n = self as u128;
+                    assert!(n <= u64::MAX as u128, "value does not fit in u64");
+                    core::iter::successors(Some(n as u64), |&n| (n >= 10).then(|| n.digit_sum()))
+                }
+
+                fn digit_product_trajectory(self) -> impl Iterator<Item = u64> {
+                    let n = self as u128;
+                    assert!(n <= u64::MAX as u128, "value does not fit in u64");
+                    core::iter::successors(Some(n as u64), |&n| (n >= 10).then(|| n.digit_product()))
+                }
+
+                fn is_digit_reverse_of(self, other: Self) -> bool {
+                    self.is_digit_reverse_of_radix(other, 10)
+                }
+
+                fn is_digit_reverse_of_strict(self, other: Self) -> bool {
+                    self.is_digit_reverse_of_strict_radix(other, 10)
+                }
+
+                fn is_emirp(self) -> bool {
+                    is_emirp_u128(self as u128)
+                }
+
+                fn is_vampire(self) -> bool {
+                    self.vampire_fangs().is_some()
+                }
+
+                fn vampire_fangs(self) -> Option<(Self, Self)> {
+                    vampire_fangs_u128(self as u128).map(|(x, y)| (x as $t, y as $t))
+                }
+
+                fn concat_many_10(parts: &[Self]) -> Self {
+                    Self::concat_many(parts, 10)
+                }
+
+                fn checked_concat_many_10(parts: &[Self]) -> Option<Self> {
+                    Self::checked_concat_many(parts, 10)
+                }
+
+                fn map_digits<F: Fn(u8) -> u8>(self, f: F) -> Self {
+                    self.map_digits_radix(f, 10)
+                }
+
+                fn checked_map_digits<F: Fn(u8) -> u8>(self, f: F) -> Option<Self> {
+                    self.checked_map_digits_radix(f, 10)
+                }
+
+                fn min_rotation(self) -> Self {
+                    self.min_rotation_radix(10)
+                }
+
+                fn max_rotation(self) -> Self {
+                    self.max_rotation_radix(10)
+                }
+
+                fn digit_signature(self) -> u64 {
+                    self.digit_counts().iter().fold(0, |acc, &c| (acc << 6) | c as u64)
+                }
+
+                fn digits_iter(self) -> impl Iterator<Item = u8> {
+                    self.digits_iter_radix(10)
+                }
+
+                // --- Radix Implementations ---
+                #[cfg(feature = "alloc")]
+                fn digits_radix(self, base: u32) -> Vec<u8> {
+                    if self == 0 { return vec![0]; }
+                    // 基数が型に収まらなければ必ず1桁 (u8 の 256 進数など)
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return vec![self as u8];
+                    };
+                    let mut n = self;
+                    // 汎用ベースのためヒューリスティックな容量確保
+                    let mut ret = Vec::with_capacity(32);
+                    while n > 0 {
+                        ret.push((n % b) as u8);
+                        n /= b;
+                    }
+                    ret.reverse();
+                    ret
+                }
+
+                fn from_digits_radix(digits: &[u8], base: u32) -> Self {
+                    // 桁の走査は検査付きの try_from_digits_radix と共有する
+                    Self::try_from_digits_radix(digits, valid_radix(base))
+                        .expect("digit out of range for the base or value out of range for the type")
+                }
+
+                fn digit_sum_radix(self, base: u32) -> u64 {
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        // 基数が型に収まらなければ必ず1桁 (u8 の 256 進数など)
+                        return self as u64;
+                    };
+                    let mut sum: u64 = 0;
+                    while n > 0 {
+                        sum += (n % b) as u64;
+                        n /= b;
+                    }
+                    sum
+                }
+
+                fn digit_product_radix(self, base: u32) -> u64 {
+                    if self == 0 { return 0; }
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return self as u64;
+                    };
+                    let mut prod: u64 = 1;
+                    while n > 0 {
+                        prod *= (n % b) as u64;
+                        n /= b;
+                    }
+                    prod
+                }
+
+                fn digits_len_radix(self, base: u32) -> u32 {
+                    if self == 0 { return 1; }
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else { return 1; };
+                    let mut cnt = 0;
+                    while n > 0 {
+                        n /= b;
+                        cnt += 1;
+                    }
+                    cnt
+                }
+
+                fn reverse_radix(self, base: u32) -> Self {
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else { return self; };
+                    let mut ret: $t = 0;
+                    while n > 0 {
+                        ret = ret * b + (n % b);
+                        n /= b;
+                    }
+                    ret
+                }
+
+                fn is_palindrome_radix(self, base: u32) -> bool {
+                    self == self.reverse_radix(base)
+                }
+
+                fn nth_digit_radix(self, i: u32, base: u32) -> Option<u8> {
+                    let b: $t = match checked_radix_pow(base, 1) {
+                        Ok(b) => b,
+                        // 基数が型に収まらなければ1桁
+                        Err(_) if base >= 2 => return if i == 0 { Some(self as u8) } else { None },
+                        Err(_) => return None,
+                    };
+                    let l = self.digits_len_radix(base);
+                    if i >= l { return None; }
+                    let p: $t = checked_radix_pow(base, l - 1 - i).ok()?;
+                    Some(((self / p) % b) as u8)
+                }
+
+                fn concat_radix(self, other: Self, base: u32) -> Self {
+                    // 不正な基数は checked 版の None ではなく基数のメッセージでパニックさせる
+                    valid_radix(base);
+                    self.checked_concat_radix(other, base).expect("concatenated value overflows the type")
+                }
+
+                fn contains_digit_radix(self, digit: u8, base: u32) -> bool {
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return self as u128 == digit as u128;
+                    };
+                    if n == 0 { return digit == 0; }
+                    while n > 0 {
+                        if (n % b) as u8 == digit {
+                            return true;
+                        }
+                        n /= b;
+                    }
+                    false
+                }
+
+                // O(N) Algorithm: Frequency Distribution
+                fn make_max_radix(self, base: u32) -> Self {
+                    if self == 0 { return 0; }
+                    let mut counts = [0u32; 256];
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else { return self; };
+                    while n > 0 {
+                        counts[(n % b) as usize] += 1;
+                        n /= b;
+                    }
+                    let mut ret: $t = 0;
+                    for d in (0..base as usize).rev() {
+                        for _ in 0..counts[d] {
+                            ret = ret * b + d as $t;
+                        }
+                    }
+                    ret
+                }
+
+                // O(N) Algorithm: Frequency Distribution
+                fn make_min_radix(self, base: u32) -> Self {
+                    if self == 0 { return 0; }
+                    let mut counts = [0u32; 256];
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else { return self; };
+                    while n > 0 {
+                        counts[(n % b) as usize] += 1;
+                        n /= b;
+                    }
+                    let mut ret: $t = 0;
+                    for d in 0..base as usize {
+                        for _ in 0..counts[d] {
+                            ret = ret * b + d as $t;
+                        }
+                    }
+                    ret
+                }
+
+                fn count_digit_radix(self, digit: u8, base: u32) -> u32 {
+                    if self == 0 { return (digit == 0) as u32; }
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return (self as u128 == digit as u128) as u32;
+                    };
+                    let mut cnt = 0;
+                    while n > 0 {
+                        if (n % b) as u8 == digit {
+                            cnt += 1;
+                        }
+                        n /= b;
+                    }
+                    cnt
+                }
+
+                #[cfg(feature = "alloc")]
+                fn digit_counts_radix(self, base: u32) -> Vec<u32> {
+                    // 基数 0 や 1 で添字が溢れる前に弾く
+                    valid_radix(base);
+                    let mut counts = vec![0u32; base as usize];
+                    if self == 0 {
+                        counts[0] = 1;
+                        return counts;
+                    }
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(base, 1) else {
+                        counts[self as usize] = 1;
+                        return counts;
+                    };
+                    while n > 0 {
+                        counts[(n % b) as usize] += 1;
+                        n /= b;
+                    }
+                    counts
+                }
+
+                #[cfg(feature = "alloc")]
+                fn digit_positions_radix(self, digit: u8, base: u32) -> Vec<u32> {
+                    if self == 0 {
+                        return if digit == 0 { vec![0] } else { Vec::new() };
+                    }
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return if self as u128 == digit as u128 { vec![0] } else { Vec::new() };
+                    };
+                    let mut i = self.digits_len_radix(base);
+                    let mut ret = Vec::new();
+                    while n > 0 {
+                        i -= 1;
+                        if (n % b) as u8 == digit {
+                            ret.push(i);
+                        }
+                        n /= b;
+                    }
+                    ret.reverse();
+                    ret
+                }
+
+                fn is_pandigital_radix(self, base: u32) -> bool {
+                    self.digits_len_radix(base) == base
+                        && self.distinct_digit_count_radix(base) == base
+                }
+
+                fn rotate_digits_left_radix(self, n: u32, base: u32) -> Self {
+                    self.checked_rotate_digits_left_radix(n, base).expect("rotated value overflows the type")
+                }
+
+                fn rotate_digits_right_radix(self, n: u32, base: u32) -> Self {
+                    self.checked_rotate_digits_right_radix(n, base).expect("rotated value overflows the type")
+                }
+
+                fn checked_rotate_digits_left_radix(self, n: u32, base: u32) -> Option<Self> {
+                    let l = self.digits_len_radix(base);
+                    let k = n % l;
+                    if k == 0 { return Some(self); }
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return Some(self);
+                    };
+                    let p = b.pow(l - k);
+                    let head = self / p;
+                    let tail = self % p;
+                    tail.checked_mul(b.pow(k))?.checked_add(head)
+                }
+
+                fn checked_rotate_digits_right_radix(self, n: u32, base: u32) -> Option<Self> {
+                    let l = self.digits_len_radix(base);
+                    self.checked_rotate_digits_left_radix(l - n % l, base)
+                }
+
+                fn digit_square_sum_radix(self, base: u32) -> u64 {
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return (self as u64).pow(2);
+                    };
+                    let mut sum: u64 = 0;
+                    while n > 0 {
+                        let d = (n % b) as u64;
+                        sum += d * d;
+                        n /= b;
+                    }
+                    sum
+                }
+
+                fn is_happy_radix(self, base: u32) -> bool {
+                    let mut slow = self.digit_square_sum_radix(base);
+                    let mut fast = slow.digit_square_sum_radix(base);
+                    while slow != fast {
+                        slow = slow.digit_square_sum_radix(base);
+                        fast = fast.digit_square_sum_radix(base).digit_square_sum_radix(base);
+                    }
+                    slow == 1
+                }
+
+                fn is_armstrong_radix(self, base: u32) -> bool {
+                    let l = self.digits_len_radix(base);
+                    let target = self as u128;
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        // 1桁の数 d は d^1 = d
+                        return true;
+                    };
+                    let mut sum: u128 = 0;
+                    while n > 0 {
+                        let d = (n % b) as u128;
+                        sum = match d.checked_pow(l).and_then(|p| sum.checked_add(p)) {
+                            Some(s) if s <= target => s,
+                            _ => return false,
+                        };
+                        n /= b;
+                    }
+                    sum == target
+                }
+
+                fn is_harshad_radix(self, base: u32) -> bool {
+                    let s = self.digit_sum_radix(base);
+                    s != 0 && self % (s as $t) == 0
+                }
+
+                #[cfg(feature = "alloc")]
+                fn to_radix_string(self, base: u32) -> String {
+                    assert!((2..=36).contains(&base), "base must be in 2..=36 (got {})", base);
+                    self.digits_radix(base)
+                        .into_iter()
+                        .map(|d| char::from_digit(d as u32, base).unwrap())
+                        .collect()
+                }
+
+                #[cfg(feature = "alloc")]
+                fn to_radix_string_upper(self, base: u32) -> String {
+                    let mut s = self.to_radix_string(base);
+                    s.make_ascii_uppercase();
+                    s
+                }
+
+                fn from_radix_string(s: &str, base: u32) -> Result<Self, ParseKetaError> {
+                    assert!((2..=36).contains(&base), "base must be in 2..=36 (got {})", base);
+                    let body = s.strip_prefix('+').unwrap_or(s);
+                    if body.is_empty() { return Err(ParseKetaError::Empty); }
+                    let b = valid_radix(base) as $t;
+                    let mut ret: $t = 0;
+                    for c in body.chars() {
+                        let d = radix_char_value(c, base)? as $t;
+                        ret = ret
+                            .checked_mul(b)
+                            .and_then(|r| r.checked_add(d))
+                            .ok_or(ParseKetaError::Overflow)?;
+                    }
+                    Ok(ret)
+                }
+
+                fn try_from_digits_radix(digits: &[u8], base: u32) -> Option<Self> {
+                    if digits.iter().any(|&d| d as u32 >= base) { return None; }
+                    // 基数が型に収まらなければ、0 でない数字は最後の桁にしか置けない
+                    let Ok(b) = checked_radix_pow::<$t>(base, 1) else { return single_digit_from(digits); };
+                    let mut ret: $t = 0;
+                    for &d in digits {
+                        ret = ret.checked_mul(b)?.checked_add(d as $t)?;
+                    }
+                    Some(ret)
+                }
+
+                fn digit_slice_radix(self, start: u32, len: u32, base: u32) -> Self {
+                    let l = self.digits_len_radix(base);
+                    if start >= l || len == 0 { return 0; }
+                    let end = l.min(start.saturating_add(len));
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else { return self; };
+                    let n = self / b.pow(l - end);
+                    if start == 0 { n } else { n % b.pow(end - start) }
+                }
+
+                fn with_digit_radix(self, i: u32, digit: u8, base: u32) -> Self {
+                    self.checked_with_digit_radix(i, digit, base)
+                        .expect("with_digit result overflows the type")
+                }
+
+                fn checked_with_digit_radix(self, i: u32, digit: u8, base: u32) -> Option<Self> {
+                    assert!((digit as u32) < base, "digit must be less than base {} (got {})", base, digit);
+                    let l = self.digits_len_radix(base);
+                    assert!(i < l, "digit index out of range: {} >= {}", i, l);
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return Some(digit as $t);
+                    };
+                    let p = b.pow(l - 1 - i);
+                    let old = (self / p) % b;
+                    (digit as $t).checked_mul(p).and_then(|d| (self - old * p).checked_add(d))
+                }
+
+                fn nth_digit_from_right_radix(self, i: u32, base: u32) -> Option<u8> {
+                    if self == 0 { return if i == 0 { Some(0) } else { None }; }
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return if i == 0 { Some(self as u8) } else { None };
+                    };
+                    for _ in 0..i {
+                        n /= b;
+                        if n == 0 { return None; }
+                    }
+                    Some((n % b) as u8)
+                }
+
+                fn max_digit_radix(self, base: u32) -> u8 {
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return self as u8;
+                    };
+                    let mut ret = 0;
+                    while n > 0 {
+                        ret = ret.max((n % b) as u8);
+                        n /= b;
+                    }
+                    ret
+                }
+
+                fn min_digit_radix(self, base: u32) -> u8 {
+                    if self == 0 { return 0; }
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return self as u8;
+                    };
+                    let mut ret = u8::MAX;
+                    while n > 0 {
+                        ret = ret.min((n % b) as u8);
+                        if ret == 0 { break; }
+                        n /= b;
+                    }
+                    ret
+                }
+
+                fn distinct_digit_count_radix(self, base: u32) -> u32 {
+                    if self == 0 { return 1; }
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else { return 1; };
+                    // u8 の数字 256 種類分のビットマスク
+                    let mut seen = [0u128; 2];
+                    while n > 0 {
+                        let d = (n % b) as u8;
+                        seen[(d >> 7) as usize] |= 1 << (d & 127);
+                        n /= b;
+                    }
+                    seen[0].count_ones() + seen[1].count_ones()
+                }
+
+                fn is_repdigit_radix(self, base: u32) -> bool {
+                    self.distinct_digit_count_radix(base) == 1
+                }
+
+                fn is_repunit_radix(self, base: u32) -> bool {
+                    if self == 0 { return false; }
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return self == 1;
+                    };
+                    while n > 0 {
+                        if n % b != 1 { return false; }
+                        n /= b;
+                    }
+                    true
+                }
+
+                #[cfg(feature = "alloc")]
+                fn next_palindrome_radix(self, base: u32) -> Self {
+                    self.checked_next_palindrome_radix(base)
+                        .expect("next palindrome overflows the type")
+                }
+
+                #[cfg(feature = "alloc")]
+                fn checked_next_palindrome_radix(self, base: u32) -> Option<Self> {
+                    let d = next_palindrome_digits(&self.digits_radix(base), base);
+                    Self::try_from_digits_radix(&d, base)
+                }
+
+                fn digit_power_sum_radix(self, exp: u32, base: u32) -> u128 {
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return (self as u128).saturating_pow(exp);
+                    };
+                    let mut sum: u128 = 0;
+                    while n > 0 {
+                        let d = (n % b) as u128;
+                        sum = sum.saturating_add(d.saturating_pow(exp));
+                        n /= b;
+                    }
+                    sum
+                }
+
+                fn checked_reverse_radix(self, base: u32) -> Option<Self> {
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return Some(self);
+                    };
+                    let mut ret: $t = 0;
+                    while n > 0 {
+                        ret = ret.checked_mul(b)?.checked_add(n % b)?;
+                        n /= b;
+                    }
+                    Some(ret)
+                }
+
+                fn saturating_reverse_radix(self, base: u32) -> Self {
+                    self.checked_reverse_radix(base).unwrap_or(<$t>::MAX)
+                }
+
+                fn wrapping_reverse_radix(self, base: u32) -> Self {
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else { return self; };
+                    let mut ret: $t = 0;
+                    while n > 0 {
+                        ret = ret.wrapping_mul(b).wrapping_add(n % b);
+                        n /= b;
+                    }
+                    ret
+                }
+
+                fn checked_digit_product_radix(self, base: u32) -> Option<u64> {
+                    if self == 0 { return Some(0); }
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return Some(self as u64);
+                    };
+                    let mut prod: Option<u64> = Some(1);
+                    while n > 0 {
+                        let d = (n % b) as u64;
+                        // 途中で溢れても、後に 0 の桁があれば積は 0 になる
+                        if d == 0 { return Some(0); }
+                        prod = prod.and_then(|p| p.checked_mul(d));
+                        n /= b;
+                    }
+                    prod
+                }
+
+                #[cfg(feature = "alloc")]
+                fn digits_padded_radix(self, width: usize, base: u32) -> Vec<u8> {
+                    let d = self.digits_radix(base);
+                    assert!(d.len() <= width, "{} digits do not fit in width {}", d.len(), width);
+                    let mut ret = vec![0; width - d.len()];
+                    ret.extend_from_slice(&d);
+                    ret
+                }
+
+                #[cfg(feature = "alloc")]
+                fn to_radix_string_padded(self, width: usize, base: u32) -> String {
+                    assert!((2..=36).contains(&base), "base must be in 2..=36 (got {})", base);
+                    self.digits_padded_radix(width, base)
+                        .into_iter()
+                        .map(|d| char::from_digit(d as u32, base).unwrap())
+                        .collect()
+                }
+
+                fn interleave_radix(self, other: Self, base: u32) -> Self {
+                    self.checked_interleave_radix(other, base)
+                        .expect("interleaved value overflows the type")
+                }
+
+                fn checked_interleave_radix(self, other: Self, base: u32) -> Option<Self> {
+                    let (x, y) = (self, other);
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        // どちらも1桁なので、x が 0 でなければ x * base + y は溢れる
+                        return if x == 0 { Some(y) } else { None };
+                    };
+                    let (lx, ly) = (x.digits_len_radix(base), y.digits_len_radix(base));
+                    let mut ret: $t = 0;
+                    for i in 0..lx.max(ly) {
+                        if i < lx {
+                            ret = ret.checked_mul(b)?.checked_add((x / b.pow(lx - 1 - i)) % b)?;
+                        }
+                        if i < ly {
+                            ret = ret.checked_mul(b)?.checked_add((y / b.pow(ly - 1 - i)) % b)?;
+                        }
+                    }
+                    Some(ret)
+                }
+
+                fn split_at_radix(self, i: u32, base: u32) -> (Self, Self) {
+                    let l = self.digits_len_radix(base);
+                    if i == 0 { return (0, self); }
+                    if i >= l { return (self, 0); }
+                    // i >= 1 なので b^(l - i) は self 以下に収まる (2桁以上あるので基数も型に収まる)
+                    let p = (valid_radix(base) as $t).pow(l - i);
+                    (self / p, self % p)
+                }
+
+                fn remove_digit_radix(self, digit: u8, base: u32) -> Self {
+                    assert!((digit as u32) < base, "digit must be less than base {} (got {})", base, digit);
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return if self as u128 == digit as u128 { 0 } else { self };
+                    };
+                    let mut ret: $t = 0;
+                    let mut p: $t = 1;
+                    while n > 0 {
+                        let d = n % b;
+                        if d as u8 != digit {
+                            ret += d * p;
+                            // 次に残る桁の位は元の位以下なので、使われる p は溢れない
+                            p = p.wrapping_mul(b);
+                        }
+                        n /= b;
+                    }
+                    ret
+                }
+
+                fn digit_cmp_radix(self, other: Self, base: u32) -> Ordering {
+                    // 桁数が同じなら、上位桁からの辞書順は数値の大小と一致する
+                    self.digits_len_radix(base)
+                        .cmp(&other.digits_len_radix(base))
+                        .then(self.cmp(&other))
+                }
+
+                fn cmp_by_digit_sum_radix(self, other: Self, base: u32) -> Ordering {
+                    self.digit_sum_radix(base)
+                        .cmp(&other.digit_sum_radix(base))
+                        .then(self.cmp(&other))
+                }
+
+                #[cfg(feature = "alloc")]
+                fn group_digits_radix(self, sep: char, group: usize, base: u32) -> String {
+                    assert!(group > 0, "group size must be positive");
+                    let s = self.to_radix_string(base);
+                    let (neg, body) = match s.strip_prefix('-') {
+                        Some(rest) => (true, rest),
+                        None => (false, s.as_str()),
+                    };
+                    let mut ret = String::with_capacity(s.len() + s.len() / group);
+                    if neg { ret.push('-'); }
+                    // 数字部分は ASCII なので、バイト長を桁数として扱える
+                    for (i, c) in body.chars().enumerate() {
+                        if i > 0 && (body.len() - i) % group == 0 {
+                            ret.push(sep);
+                        }
+                        ret.push(c);
+                    }
+                    ret
+                }
+
+                fn is_munchausen_radix(self, base: u32) -> bool {
+                    let target = self as u128;
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        // 1桁の数 d は d^d = d となる 1 (と 0) だけ
+                        return self <= 1;
+                    };
+                    let mut sum: u128 = 0;
+                    while n > 0 {
+                        let d = (n % b) as u32;
+                        // 0^0 = 0 とするので 0 の桁は足さない
+                        if d > 0 {
+                            sum = match (d as u128).checked_pow(d).and_then(|p| sum.checked_add(p)) {
+                                Some(s) if s <= target => s,
+                                _ => return false,
+                            };
+                        }
+                        n /= b;
+                    }
+                    sum == target
+                }
+
+                #[cfg(feature = "alloc")]
+                fn sort_digits_radix(self, ascending: bool, base: u32) -> Vec<u8> {
+                    let mut d = self.digits_radix(base);
+                    if ascending {
+                        d.sort_unstable();
+                    } else {
+                        d.sort_unstable_by(|a, b| b.cmp(a));
+                    }
+                    d
+                }
+
+                fn digit_sum_mod_radix(self, m: u64, base: u32) -> u64 {
+                    assert!(m > 0, "modulus must be positive");
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return self as u64 % m;
+                    };
+                    let mut sum: u64 = 0;
+                    while n > 0 {
+                        sum = (sum + (n % b) as u64) % m;
+                        n /= b;
+                    }
+                    sum
+                }
+
+                #[cfg(feature = "alloc")]
+                fn signed_digits_radix(self, base: u32) -> (bool, Vec<u8>) {
+                    (false, self.digits_radix(base))
+                }
+
+                fn next_with_digit_sum_radix(self, base: u32) -> Self {
+                    self.checked_next_with_digit_sum_radix(base)
+                        .expect("no larger number with the same digit sum fits the type")
+                }
+
+                fn checked_next_with_digit_sum_radix(self, base: u32) -> Option<Self> {
+                    let b = valid_radix(base) as u128;
+                    next_with_digit_sum_u128(self as u128, b).and_then(|n| <$t>::try_from(n).ok())
+                }
+
+                fn weighted_digit_sum_radix<F: FnMut(u32, u8) -> i64>(self, mut f: F, base: u32) -> i64 {
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return f(0, self as u8);
+                    };
+                    if n == 0 { return f(0, 0); }
+                    let mut i = 0;
+                    let mut sum = 0;
+                    while n > 0 {
+                        sum += f(i, (n % b) as u8);
+                        n /= b;
+                        i += 1;
+                    }
+                    sum
+                }
+
+                fn try_concat_radix(self, other: Self, base: u32) -> Result<Self, DigitError> {
+                    // digits_len_radix は基数を型にキャストするので、先に収まるか確かめる
+                    checked_radix_pow::<$t>(base, 1)?;
+                    let p: $t = checked_radix_pow(base, other.digits_len_radix(base))?;
+                    self.checked_mul(p)
+                        .and_then(|s| s.checked_add(other))
+                        .ok_or(DigitError::Overflow)
+                }
+
+                fn checked_concat_radix(self, other: Self, base: u32) -> Option<Self> {
+                    self.try_concat_radix(other, base).ok()
+                }
+
+                fn has_increasing_digits_radix(self, base: u32) -> bool {
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else { return true; };
+                    // 下位桁から取り出すので、1つ上の桁が右隣 (prev) 以下であればよい
+                    let mut prev = n % b;
+                    n /= b;
+                    while n > 0 {
+                        let d = n % b;
+                        if d > prev { return false; }
+                        prev = d;
+                        n /= b;
+                    }
+                    true
+                }
+
+                fn has_decreasing_digits_radix(self, base: u32) -> bool {
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else { return true; };
+                    let mut prev = n % b;
+                    n /= b;
+                    while n > 0 {
+                        let d = n % b;
+                        if d < prev { return false; }
+                        prev = d;
+                        n /= b;
+                    }
+                    true
+                }
+
+                fn is_stepping_radix(self, base: u32) -> bool {
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else { return true; };
+                    let mut prev = n % b;
+                    n /= b;
+                    while n > 0 {
+                        let d = n % b;
+                        if d.abs_diff(prev) != 1 { return false; }
+                        prev = d;
+                        n /= b;
+                    }
+                    true
+                }
+
+                #[cfg(feature = "alloc")]
+                fn is_palindrome_padded_radix(self, width: usize, base: u32) -> bool {
+                    let d = self.digits_padded_radix(width, base);
+                    d.iter().eq(d.iter().rev())
+                }
+
+                #[cfg(feature = "alloc")]
+                fn digit_prefix_sums_radix(self, base: u32) -> Vec<u64> {
+                    let mut sum = 0u64;
+                    self.digits_radix(base)
+                        .into_iter()
+                        .map(|d| {
+                            sum += d as u64;
+                            sum
+                        })
+                        .collect()
+                }
+
+                fn checked_make_max_radix(self, base: u32) -> Option<Self> {
+                    valid_radix(base);
+                    // 基数が型に収まらなければ1桁なので、並び替えても変わらない
+                    let Ok(b) = checked_radix_pow::<$t>(base, 1) else { return Some(self); };
+                    let mut counts = [0u32; 256];
+                    let mut n = self;
+                    while n > 0 {
+                        counts[(n % b) as usize] += 1;
+                        n /= b;
+                    }
+                    let mut ret: $t = 0;
+                    for d in (0..base as usize).rev() {
+                        for _ in 0..counts[d] {
+                            ret = ret.checked_mul(b)?.checked_add(d as $t)?;
+                        }
+                    }
+                    Some(ret)
+                }
+
+                fn checked_make_min_radix(self, base: u32) -> Option<Self> {
+                    valid_radix(base);
+                    let Ok(b) = checked_radix_pow::<$t>(base, 1) else { return Some(self); };
+                    let mut counts = [0u32; 256];
+                    let mut n = self;
+                    while n > 0 {
+                        counts[(n % b) as usize] += 1;
+                        n /= b;
+                    }
+                    let mut ret: $t = 0;
+                    for d in 0..base as usize {
+                        for _ in 0..counts[d] {
+                            ret = ret.checked_mul(b)?.checked_add(d as $t)?;
+                        }
+                    }
+                    Some(ret)
+                }
+
+                fn fold_digits_radix<B, F: FnMut(B, u8) -> B>(self, init: B, mut f: F, base: u32) -> B {
+                    let n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return f(init, self as u8);
+                    };
+                    // 最上位桁の重みから順に割っていく
+                    let mut p = b.pow(n.digits_len_radix(base) - 1);
+                    let mut acc = init;
+                    while p > 0 {
+                        acc = f(acc, (n / p % b) as u8);
+                        p /= b;
+                    }
+                    acc
+                }
+
+                fn as_single_digit_radix(self, base: u32) -> Option<u8> {
+                    let n = self;
+                    // base は 256 以下なので、base 未満なら u8 に収まる
+                    if (n as u128) < valid_radix(base) as u128 { Some(n as u8) } else { None }
+                }
+
+                fn is_bouncy_radix(self, base: u32) -> bool {
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return false;
+                    };
+                    // 2桁以下は必ず単調
+                    if n / b < b { return false; }
+                    // 下位桁から見るので、1つ上の桁が小さければ上位から見て増加している
+                    let (mut up, mut down) = (false, false);
+                    let mut prev = n % b;
+                    n /= b;
+                    while n > 0 {
+                        let d = n % b;
+                        up |= d < prev;
+                        down |= d > prev;
+                        if up && down { return true; }
+                        prev = d;
+                        n /= b;
+                    }
+                    false
+                }
+
+                #[cfg(feature = "alloc")]
+                fn digit_view(self, base: u32) -> DigitView {
+                    DigitView::new(self.digits_radix(base), base)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn to_radix_string_with(self, base: u32, alphabet: &[char]) -> String {
+                    valid_alphabet(alphabet, base);
+                    self.digits_radix(base)
+                        .into_iter()
+                        .map(|d| alphabet[d as usize])
+                        .collect()
+                }
+
+                fn from_radix_string_with(s: &str, base: u32, alphabet: &[char]) -> Result<Self, ParseKetaError> {
+                    let index = AlphabetIndex::new(alphabet, base);
+                    let body = index.strip_sign(s, '+').unwrap_or(s);
+                    if body.is_empty() { return Err(ParseKetaError::Empty); }
+                    // 基数が型に収まらない場合 (u8 / i8 の 200 進数など) は i128 で掛ける
+                    let b: Option<$t> = checked_radix_pow(base, 1).ok();
+                    let mut ret: $t = 0;
+                    for c in body.chars() {
+                        let d = <$t>::try_from(index.value(c)?).ok();
+                        let shifted = match b {
+                            Some(b) => ret.checked_mul(b),
+                            None => <$t>::try_from(ret as i128 * base as i128).ok(),
+                        };
+                        ret = shifted
+                            .zip(d)
+                            .and_then(|(r, d)| r.checked_add(d))
+                            .ok_or(ParseKetaError::Overflow)?;
+                    }
+                    Ok(ret)
+                }
+
+                fn smallest_arrangement_fixed_radix(self, base: u32) -> Self {
+                    let mut counts = [0u32; 256];
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else { return self; };
+                    while n > 0 {
+                        counts[(n % b) as usize] += 1;
+                        n /= b;
+                    }
+                    // 先頭には最も小さい 0 以外の数字を置く (すべて 0 なら 0)
+                    let Some(lead) = (1..base as usize).find(|&d| counts[d] > 0) else { return 0; };
+                    counts[lead] -= 1;
+                    let mut ret = lead as $t;
+                    for d in 0..base as usize {
+                        for _ in 0..counts[d] {
+                            ret = ret * b + d as $t;
+                        }
+                    }
+                    ret
+                }
+
+                fn checked_smallest_arrangement_fixed_radix(self, base: u32) -> Option<Self> {
+                    Some(self.smallest_arrangement_fixed_radix(base))
+                }
+
+                fn largest_arrangement_fixed_radix(self, base: u32) -> Self {
+                    // 降順に並べれば先頭の 0 は現れないので make_max と同じ
+                    self.make_max_radix(base)
+                }
+
+                fn checksum_mod_radix(self, weights: &[i64], modulus: i64, base: u32) -> i64 {
+                    assert!(!weights.is_empty(), "weights must not be empty");
+                    assert!(modulus > 0, "modulus must be positive (got {})", modulus);
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return (self as i128 * weights[0] as i128).rem_euclid(modulus as i128) as i64;
+                    };
+                    let m = modulus as i128;
+                    // 1桁ごとに余りを取るので、大きな重みでも溢れない
+                    let mut acc: i128 = 0;
+                    for &w in weights.iter().cycle() {
+                        acc = (acc + (n % b) as i128 * w as i128).rem_euclid(m);
+                        n /= b;
+                        if n == 0 { break; }
+                    }
+                    acc as i64
+                }
+
+                fn is_undulating_radix(self, base: u32) -> bool {
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return false;
+                    };
+                    // 2桁以下は対象外
+                    if n / b < b { return false; }
+                    // 偶数番目と奇数番目の桁がそれぞれ揃い、互いに異なること
+                    let even = n % b;
+                    n /= b;
+                    let odd = n % b;
+                    if even == odd { return false; }
+                    let mut expect = even;
+                    n /= b;
+                    while n > 0 {
+                        if n % b != expect { return false; }
+                        expect = if expect == even { odd } else { even };
+                        n /= b;
+                    }
+                    true
+                }
+
+                fn wrapping_concat_radix(self, other: Self, base: u32) -> Self {
+                    let shift = other.digits_len_radix(base);
+                    // 型に収まらない基数を切り詰めても、2^bits を法とした値は変わらない
+                    let b = valid_radix(base) as $t;
+                    self.wrapping_mul(b.wrapping_pow(shift)).wrapping_add(other)
+                }
+
+                fn wrapping_make_max_radix(self, base: u32) -> Self {
+                    let mut counts = [0u32; 256];
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else { return self; };
+                    while n > 0 {
+                        counts[(n % b) as usize] += 1;
+                        n /= b;
+                    }
+                    let mut ret: $t = 0;
+                    for d in (0..base as usize).rev() {
+                        for _ in 0..counts[d] {
+                            ret = ret.wrapping_mul(b).wrapping_add(d as $t);
+                        }
+                    }
+                    ret
+                }
+
+                fn trailing_zeros_radix(self, base: u32) -> u32 {
+                    if self == 0 { return 1; }
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else { return 0; };
+                    let mut cnt = 0;
+                    while n % b == 0 {
+                        cnt += 1;
+                        n /= b;
+                    }
+                    cnt
+                }
+
+                fn leading_zeros_radix(self, width: u32, base: u32) -> u32 {
+                    width.saturating_sub(self.digits_len_radix(base))
+                }
+
+                #[cfg(feature = "alloc")]
+                fn reverse_digits_radix(self, base: u32) -> (Vec<u8>, bool) {
+                    let (neg, mut d) = self.signed_digits_radix(base);
+                    d.reverse();
+                    (d, neg)
+                }
+
+                fn max_digits_radix(base: u32) -> u32 {
+                    (<$t>::MAX as u128).ilog(valid_radix(base) as u128) + 1
+                }
+
+                fn is_pdi_radix(self, power: u32, base: u32) -> bool {
+                    let target = self as u128;
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return self == 0 || (self as u128).checked_pow(power) == Some(self as u128);
+                    };
+                    let mut sum: u128 = 0;
+                    // 途中で自分自身を超えたら打ち切る
+                    while n > 0 {
+                        let d = (n % b) as u128;
+                        sum = match d.checked_pow(power).and_then(|p| sum.checked_add(p)) {
+                            Some(s) if s <= target => s,
+                            _ => return false,
+                        };
+                        n /= b;
+                    }
+                    sum == target
+                }
+
+                fn add_with_carry(self, other: Self, width: u32, base: u32) -> Option<(Self, bool)> {
+                    // 途中の値が型に収まらなくてもよいように u128 で組み立てる
+                    let b = valid_radix(base) as u128;
+                    let (mut x, mut y) = (self as u128, other as u128);
+                    let mut ret: u128 = 0;
+                    let mut place = Some(1u128);
+                    let mut carry = false;
+                    for _ in 0..width {
+                        if x == 0 && y == 0 && !carry { break; }
+                        let s = x % b + y % b + carry as u128;
+                        carry = s >= b;
+                        let d = if carry { s - b } else { s };
+                        if d != 0 { ret = ret.checked_add(d.checked_mul(place?)?)?; }
+                        x /= b;
+                        y /= b;
+                        place = place.and_then(|p| p.checked_mul(b));
+                    }
+                    // width 桁を超える桁が残っている
+                    if x > 0 || y > 0 { return None; }
+                    Some((<$t>::try_from(ret).ok()?, carry))
+                }
+
+                fn sub_with_borrow(self, other: Self, width: u32, base: u32) -> Option<(Self, bool)> {
+                    let b = valid_radix(base) as u128;
+                    let (mut x, mut y) = (self as u128, other as u128);
+                    let mut ret: u128 = 0;
+                    let mut place = Some(1u128);
+                    let mut borrow = false;
+                    for _ in 0..width {
+                        if x == 0 && y == 0 && !borrow { break; }
+                        let (dx, dy) = (x % b, y % b + borrow as u128);
+                        borrow = dx < dy;
+                        let d = if borrow { dx + b - dy } else { dx - dy };
+                        if d != 0 { ret = ret.checked_add(d.checked_mul(place?)?)?; }
+                        x /= b;
+                        y /= b;
+                        place = place.and_then(|p| p.checked_mul(b));
+                    }
+                    if x > 0 || y > 0 { return None; }
+                    Some((<$t>::try_from(ret).ok()?, borrow))
+                }
+
+                fn most_common_digit_radix(self, base: u32) -> u8 {
+                    let mut counts = [0u32; 256];
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return self as u8;
+                    };
+                    loop {
+                        counts[(n % b) as usize] += 1;
+                        n /= b;
+                        if n == 0 { break; }
+                    }
+                    most_common_in(&counts)
+                }
+
+                fn least_common_digit_radix(self, base: u32) -> u8 {
+                    let mut counts = [0u32; 256];
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        return self as u8;
+                    };
+                    loop {
+                        counts[(n % b) as usize] += 1;
+                        n /= b;
+                        if n == 0 { break; }
+                    }
+                    least_common_in(&counts)
+                }
+
+                fn from_grouped_str(s: &str, base: u32) -> Result<Self, ParseKetaError> {
+                    assert!((2..=36).contains(&base), "base must be in 2..=36 (got {})", base);
+                    let body = s.strip_prefix('+').unwrap_or(s);
+                    if body.is_empty() { return Err(ParseKetaError::Empty); }
+                    let b = valid_radix(base) as $t;
+                    let mut ret: $t = 0;
+                    for c in strip_group_separators(body)? {
+                        let d = radix_char_value(c, base)? as $t;
+                        ret = ret
+                            .checked_mul(b)
+                            .and_then(|r| r.checked_add(d))
+                            .ok_or(ParseKetaError::Overflow)?;
+                    }
+                    Ok(ret)
+                }
+
+                fn is_digit_reverse_of_radix(self, other: Self, base: u32) -> bool {
+                    self.checked_reverse_radix(base) == Some(other)
+                }
+
+                fn is_digit_reverse_of_strict_radix(self, other: Self, base: u32) -> bool {
+                    // 反転で末尾の 0 が落ちていれば桁数が変わる
+                    self.is_digit_reverse_of_radix(other, base)
+                        && self.digits_len_radix(base) == other.digits_len_radix(base)
+                }
+
+                fn concat_many(parts: &[Self], base: u32) -> Self {
+                    valid_radix(base);
+                    Self::checked_concat_many(parts, base).expect("concatenated value overflows the type")
+                }
+
+                fn checked_concat_many(parts: &[Self], base: u32) -> Option<Self> {
+                    let b = <$t>::try_from(valid_radix(base)).ok();
+                    let Some((&first, rest)) = parts.split_first() else { return Some(0); };
+                    let mut ret = first;
+                    for &p in rest {
+                        // 基数が型に収まらない場合 (u8 の 256 進数など) は u128 で掛ける
+                        let Some(b) = b else {
+                            let shift = (base as u128).checked_pow(p.digits_len_radix(base))?;
+                            let s = (ret as u128).checked_mul(shift)?.checked_add(p as u128)?;
+                            ret = <$t>::try_from(s).ok()?;
+                            continue;
+                        };
+                        let mut shift: $t = 1;
+                        let mut m = p;
+                        loop {
+                            shift = shift.checked_mul(b)?;
+                            m /= b;
+                            if m == 0 { break; }
+                        }
+                        ret = ret.checked_mul(shift)?.checked_add(p)?;
+                    }
+                    Some(ret)
+                }
+
+                fn map_digits_radix<F: Fn(u8) -> u8>(self, f: F, base: u32) -> Self {
+                    self.checked_map_digits_radix(f, base).expect("mapped value overflows the type")
+                }
+
+                fn checked_map_digits_radix<F: Fn(u8) -> u8>(self, f: F, base: u32) -> Option<Self> {
+                    let mut n = self;
+                    let Ok(b) = checked_radix_pow::<$t>(valid_radix(base), 1) else {
+                        let d = f(self as u8);
+                        assert!((d as u32) < base, "mapped digit {} out of range for base {}", d, base);
+                        return Some(d as $t);
+                    };
+                    let mut ret: $t = 0;
+                    let mut place: $t = 1;
+                    loop {
+                        let d = f((n % b) as u8);
+                        assert!((d as u32) < base, "mapped digit {} out of range for base {}", d, base);
+                        // place は元の数を超えないので溢れず、溢れうるのは変換後の値だけ
+                        ret = (d as $t).checked_mul(place).and_then(|v| ret.checked_add(v))?;
+                        n /= b;
+                        if n == 0 { break; }
+                        place *= b;
+                    }
+                    Some(ret)
+                }
+
+                fn min_rotation_radix(self, base: u32) -> Self {
+                    // 型の範囲を超える回転は候補から外す (元の数自身は常に候補)
+                    let l = self.digits_len_radix(base);
+                    (1..l)
+                        .filter_map(|k| self.checked_rotate_digits_left_radix(k, base))
+                        .fold(self, <$t>::min)
+                }
+
+                fn max_rotation_radix(self, base: u32) -> Self {
+                    let l = self.digits_len_radix(base);
+                    (1..l)
+                        .filter_map(|k| self.checked_rotate_digits_left_radix(k, base))
+                        .fold(self, <$t>::max)
+                }
+
+                fn digits_iter_radix(self, base: u32) -> impl Iterator<Item = u8> {
+                    // 基数が型に収まらなければ必ず1桁 (u8 の 256 進数など)
+                    let b = checked_radix_pow::<$t>(valid_radix(base), 1).ok();
+                    // 最上位桁の重みから順に割っていく
+                    let mut p: $t = 1;
+                    if let Some(b) = b {
+                        while p <= self / b {
+                            p *= b;
+                        }
+                    }
+                    let mut rem = self;
+                    core::iter::from_fn(move || {
+                        if p == 0 { return None; }
+                        let d = rem / p;
+                        rem -= d * p;
+                        p = b.map_or(0, |b| p / b);
+                        Some(d as u8)
+                    })
+                }
+            }
+        )*
+    };
+}
+
+// ----------------------------------------------------------------
+// 実装用マクロ (符号付き整数用: i32, i64...)
+// ----------------------------------------------------------------
+macro_rules! impl_keta_int {
+    ($($t:ty),*) => {
+        $(
+            impl Keta for $t {
+                // --- 10-base Shortcuts (Optimized) ---
+
+                // |MIN| = MAX + 1 は 2 の累乗なので、桁数は MAX と同じ
+                const MAX_DIGITS: u32 = <$t>::MAX.ilog10() + 1;
+
+                #[cfg(feature = "alloc")]
+                fn digits(self) -> Vec<u8> {
+                    if self == 0 { return vec![0]; }
+                    let mut n = self;
+                    // ilog10で正確な容量を計算 (再アロケーション防止)
+                    let cap = (self.unsigned_abs().ilog10() + 1) as usize;
+                    let mut ret = Vec::with_capacity(cap);
+                    // 負のまま割って余りの絶対値を取るので MIN でも溢れない
+                    while n != 0 {
+                        ret.push((n % 10).unsigned_abs() as u8);
+                        n /= 10;
+                    }
+                    ret.reverse();
+                    ret
+                }
+
+                fn from_digits(digits: &[u8]) -> Self {
+                    Self::from_digits_radix(digits, 10)
+                }
+
+                fn digit_sum(self) -> u64 {
+                    let n = self.unsigned_abs();
+                    if <$t>::BITS <= 64 {
+                        digit_sum_u64_table(n as u64)
+                    } else {
+                        digit_sum_u128_table(n as u128)
+                    }
+                }
+
+                fn digit_product(self) -> u64 {
+                    let mut n = self.abs();
+                    if n == 0 { return 0; }
+                    let mut prod: u64 = 1;
+                    while n > 0 {
+                        prod *= (n % 10) as u64;
+                        n /= 10;
+                    }
+                    prod
+                }
+
+                fn digits_len(self) -> u32 {
+                    if self == 0 { return 1; }
+                    self.abs().ilog10() + 1
+                }
+
+                fn reverse(self) -> Self {
+                    let mut n = self.abs();
+                    let mut ret: $t = 0;
+                    while n > 0 {
+                        ret = ret * 10 + (n % 10);
+                        n /= 10;
+                    }
+                    if self < 0 { -ret } else { ret }
+                }
+
+                fn is_palindrome(self) -> bool {
+                    self == self.reverse()
+                }
+
+                fn nth_digit(self, i: u32) -> Option<u8> {
+                    let l = self.digits_len();
+                    if i >= l { return None; }
+                    let pow = l - 1 - i;
+                    Some(((self.abs() / (10 as $t).pow(pow)) % 10) as u8)
+                }
+
+                fn concat(self, other: Self) -> Self {
+                    self.concat_radix(other, 10)
+                }
+
+                fn contains_digit(self, digit: u8) -> bool {
+                    let mut n = self.abs();
+                    if n == 0 { return digit == 0; }
+                    while n > 0 {
+                        if (n % 10) as u8 == digit {
+                            return true;
+                        }
                         n /= 10;
                     }
                     false
                 }
 
-                // O(N) Algorithm: Frequency Distribution
-                fn make_max(self) -> Self {
-                    if self == 0 { return 0; }
-                    let mut counts = [0u32; 10];
-                    let mut n = self;
-                    while n > 0 {
-                        counts[(n % 10) as usize] += 1;
-                        n /= 10;
-                    }
-                    let mut ret: $t = 0;
-                    for d in (0..=9).rev() {
-                        for _ in 0..counts[d] {
-                            ret *= 10;
-                            ret += d as $t;
-                        }
-                    }
-                    ret
+                // O(N) Algorithm
+                // 負の数は符号を保ち、絶対値が最小になる並び (最も 0 に近い値) を返す
+                fn make_max(self) -> Self {
+                    if self < 0 { return -self.abs().make_min(); }
+                    let counts = self.digit_counts();
+                    let mut ret: $t = 0;
+                    for d in (0..=9).rev() {
+                        for _ in 0..counts[d] {
+                            ret *= 10;
+                            ret += d as $t;
+                        }
+                    }
+                    ret
+                }
+
+                // O(N) Algorithm
+                // 負の数は符号を保ち、絶対値が最大になる並びを返す
+                fn make_min(self) -> Self {
+                    if self < 0 { return -self.abs().make_max(); }
+                    let counts = self.digit_counts();
+                    let mut ret: $t = 0;
+                    for d in 0..=9 {
+                        for _ in 0..counts[d] {
+                            ret *= 10;
+                            ret += d as $t;
+                        }
+                    }
+                    ret
+                }
+
+                fn signed_digit_sum_pattern(self, signs: &[i8]) -> i64 {
+                    self.unsigned_abs().signed_digit_sum_pattern(signs)
+                }
+
+                fn count_digit(self, digit: u8) -> u32 {
+                    self.unsigned_abs().count_digit(digit)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn to_phone_letters(self) -> Vec<&'static str> {
+                    self.digits().into_iter().map(|d| PHONE_LETTERS[d as usize]).collect()
+                }
+
+                #[cfg(feature = "alloc")]
+                fn phone_letter_combinations(self) -> impl Iterator<Item = String> {
+                    PhoneLetterCombinations::new(&self.digits())
+                }
+
+                fn digit_counts(self) -> [u32; 10] {
+                    // MIN でも溢れないように絶対値を符号なしで数える
+                    self.unsigned_abs().digit_counts()
+                }
+
+                #[cfg(feature = "alloc")]
+                fn digit_positions(self, digit: u8) -> Vec<u32> {
+                    self.unsigned_abs().digit_positions(digit)
+                }
+
+                fn is_pandigital(self) -> bool {
+                    self.digit_counts() == [1; 10]
+                }
+
+                fn is_pandigital_1_to_n(self) -> bool {
+                    self.unsigned_abs().is_pandigital_1_to_n()
+                }
+
+                fn rotate_digits_left(self, n: u32) -> Self {
+                    self.checked_rotate_digits_left(n).expect("rotated value overflows the type")
+                }
+
+                fn rotate_digits_right(self, n: u32) -> Self {
+                    self.checked_rotate_digits_right(n).expect("rotated value overflows the type")
+                }
+
+                fn checked_rotate_digits_left(self, n: u32) -> Option<Self> {
+                    // 絶対値で回してから符号を戻す (MIN でも abs を取らない)
+                    let ret = self.unsigned_abs().checked_rotate_digits_left(n)?;
+                    if self < 0 { <$t>::checked_sub_unsigned(0, ret) } else { <$t>::checked_add_unsigned(0, ret) }
+                }
+
+                fn checked_rotate_digits_right(self, n: u32) -> Option<Self> {
+                    let ret = self.unsigned_abs().checked_rotate_digits_right(n)?;
+                    if self < 0 { <$t>::checked_sub_unsigned(0, ret) } else { <$t>::checked_add_unsigned(0, ret) }
+                }
+
+                fn digit_square_sum(self) -> u64 {
+                    self.unsigned_abs().digit_square_sum()
+                }
+
+                // Floyd の循環検出: 1 は不動点なので、合流点が 1 ならハッピー数
+                fn is_happy(self) -> bool {
+                    let mut slow = self.digit_square_sum();
+                    let mut fast = slow.digit_square_sum();
+                    while slow != fast {
+                        slow = slow.digit_square_sum();
+                        fast = fast.digit_square_sum().digit_square_sum();
+                    }
+                    slow == 1
+                }
+
+                fn is_armstrong(self) -> bool {
+                    self.unsigned_abs().is_armstrong()
+                }
+
+                fn is_harshad(self) -> bool {
+                    self.unsigned_abs().is_harshad()
+                }
+
+                fn try_from_digits(digits: &[u8]) -> Option<Self> {
+                    Self::try_from_digits_radix(digits, 10)
+                }
+
+                fn additive_persistence(self) -> u32 {
+                    if self.digits_len() == 1 { return 0; }
+                    let mut n = self.digit_sum();
+                    let mut cnt = 1;
+                    while n >= 10 {
+                        n = n.digit_sum();
+                        cnt += 1;
+                    }
+                    cnt
+                }
+
+                fn multiplicative_persistence(self) -> u32 {
+                    if self.digits_len() == 1 { return 0; }
+                    let mut n = self.digit_product();
+                    let mut cnt = 1;
+                    while n >= 10 {
+                        n = n.digit_product();
+                        cnt += 1;
+                    }
+                    cnt
+                }
+
+                fn digit_slice(self, start: u32, len: u32) -> Self {
+                    // 絶対値で切り出す (|MIN| は wrapping_neg で MIN に戻る)
+                    let ret = self.unsigned_abs().digit_slice(start, len) as $t;
+                    if self < 0 { ret.wrapping_neg() } else { ret }
+                }
+
+                fn with_digit(self, i: u32, digit: u8) -> Self {
+                    self.checked_with_digit(i, digit).expect("with_digit result overflows the type")
+                }
+
+                fn checked_with_digit(self, i: u32, digit: u8) -> Option<Self> {
+                    // 絶対値で置き換えてから符号を戻す (MIN でも abs を取らない)
+                    let ret = self.unsigned_abs().checked_with_digit(i, digit)?;
+                    if self < 0 { <$t>::checked_sub_unsigned(0, ret) } else { <$t>::checked_add_unsigned(0, ret) }
+                }
+
+                fn luhn_is_valid(self) -> bool {
+                    self.unsigned_abs().luhn_is_valid()
+                }
+
+                fn luhn_check_digit(self) -> u8 {
+                    self.unsigned_abs().luhn_check_digit()
+                }
+
+                fn is_automorphic(self) -> bool {
+                    // n^2 ≡ n (mod 10^L) ⇔ n(n-1) が 2^L と 5^L の両方で割り切れる。
+                    // n と n-1 は互いに素なので、それぞれどちらか一方が割り切れればよい。
+                    // 2乗を計算しないので u128 でもオーバーフローしない
+                    let l = self.digits_len();
+                    let n = self.unsigned_abs() as u128;
+                    let (p2, p5) = (2u128.pow(l), 5u128.pow(l));
+                    (n % p2 == 0 || (n - 1) % p2 == 0) && (n % p5 == 0 || (n - 1) % p5 == 0)
+                }
+
+                fn nth_digit_from_right(self, i: u32) -> Option<u8> {
+                    self.unsigned_abs().nth_digit_from_right(i)
+                }
+
+                fn max_digit(self) -> u8 {
+                    self.unsigned_abs().max_digit()
+                }
+
+                fn min_digit(self) -> u8 {
+                    self.unsigned_abs().min_digit()
+                }
+
+                fn distinct_digit_count(self) -> u32 {
+                    self.unsigned_abs().distinct_digit_count()
+                }
+
+                fn is_repdigit(self) -> bool {
+                    self.distinct_digit_count() == 1
+                }
+
+                fn is_repunit(self) -> bool {
+                    self.unsigned_abs().is_repunit()
+                }
+
+                #[cfg(feature = "alloc")]
+                fn next_palindrome(self) -> Self {
+                    self.next_palindrome_radix(10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn checked_next_palindrome(self) -> Option<Self> {
+                    self.checked_next_palindrome_radix(10)
+                }
+
+                fn digit_power_sum(self, exp: u32) -> u128 {
+                    self.unsigned_abs().digit_power_sum(exp)
+                }
+
+                fn checked_reverse(self) -> Option<Self> {
+                    self.checked_reverse_radix(10)
+                }
+
+                fn saturating_reverse(self) -> Self {
+                    self.saturating_reverse_radix(10)
+                }
+
+                fn wrapping_reverse(self) -> Self {
+                    self.wrapping_reverse_radix(10)
+                }
+
+                fn checked_digit_product(self) -> Option<u64> {
+                    self.checked_digit_product_radix(10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn digits_padded(self, width: usize) -> Vec<u8> {
+                    self.digits_padded_radix(width, 10)
+                }
+
+                fn interleave(self, other: Self) -> Self {
+                    self.interleave_radix(other, 10)
+                }
+
+                fn checked_interleave(self, other: Self) -> Option<Self> {
+                    self.checked_interleave_radix(other, 10)
+                }
+
+                fn split_at(self, i: u32) -> (Self, Self) {
+                    self.split_at_radix(i, 10)
+                }
+
+                fn remove_digit(self, digit: u8) -> Self {
+                    self.remove_digit_radix(digit, 10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn to_balanced_ternary(self) -> Vec<i8> {
+                    if self == 0 { return vec![0]; }
+                    let mut n = self;
+                    let mut ret = Vec::new();
+                    while n != 0 {
+                        // 余りが 2 なら -1 とし、上の桁に 1 繰り上げる
+                        // (div_euclid と rem_euclid を使うので負の数も MIN まで扱える)
+                        let r = n.rem_euclid(3);
+                        if r == 2 {
+                            ret.push(-1);
+                            n = n.div_euclid(3) + 1;
+                        } else {
+                            ret.push(r as i8);
+                            n = n.div_euclid(3);
+                        }
+                    }
+                    ret.reverse();
+                    ret
+                }
+
+                fn from_balanced_ternary(digits: &[i8]) -> Self {
+                    let mut ret: $t = 0;
+                    for &d in digits {
+                        // MAX / MIN 付近でも途中で溢れないよう、0 に近づく向きに ±1 してから 3 倍する
+                        ret = match d {
+                            -1 if ret > 0 => (ret - 1) * 3 + 2,
+                            -1 => ret * 3 - 1,
+                            0 => ret * 3,
+                            1 if ret < 0 => (ret + 1) * 3 - 2,
+                            1 => ret * 3 + 1,
+                            _ => panic!("balanced ternary digit must be -1, 0 or 1 (got {})", d),
+                        };
+                    }
+                    ret
+                }
+
+                #[cfg(feature = "alloc")]
+                fn digits_negative_base(self, base: i32) -> Vec<u8> {
+                    let b = valid_negative_base(base);
+                    if self == 0 { return vec![0]; }
+                    let mut ret = Vec::new();
+                    push_negative_base_digits(self as i128, b, &mut ret);
+                    ret.reverse();
+                    ret
+                }
+
+                fn from_digits_negative_base(digits: &[u8], base: i32) -> Self {
+                    let b = valid_negative_base(base);
+                    let Some((prefix, last)) = negative_base_split(digits, b) else { return 0; };
+                    prefix
+                        .checked_mul(b)
+                        .and_then(|v| v.checked_add(last as i128))
+                        .and_then(|v| <$t>::try_from(v).ok())
+                        .expect("value out of range for the type")
+                }
+
+                fn kaprekar_step(self) -> Self {
+                    kaprekar_step_u128(self.unsigned_abs() as u128)
+                        .and_then(|n| <$t>::try_from(n).ok())
+                        .expect("kaprekar step overflows the type")
+                }
+
+                fn kaprekar_iterations(self) -> Option<u32> {
+                    let mut n = self.unsigned_abs() as u128;
+                    if n > 9999 { return None; }
+                    let mut cnt = 0;
+                    while n != 6174 {
+                        if n == 0 { return None; }
+                        n = kaprekar_step_u128(n)?;
+                        cnt += 1;
+                    }
+                    Some(cnt)
+                }
+
+                fn digit_cmp(self, other: Self) -> Ordering {
+                    self.digit_cmp_radix(other, 10)
+                }
+
+                fn cmp_by_digit_sum(self, other: Self) -> Ordering {
+                    self.cmp_by_digit_sum_radix(other, 10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn group_digits(self, sep: char, group: usize) -> String {
+                    self.group_digits_radix(sep, group, 10)
+                }
+
+                fn sum_of_digit_sums<I: IntoIterator<Item = Self>>(iter: I) -> u64 {
+                    iter.into_iter().map(|n| n.digit_sum()).sum()
+                }
+
+                fn is_munchausen(self) -> bool {
+                    self.is_munchausen_radix(10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn sort_digits(self, ascending: bool) -> Vec<u8> {
+                    self.sort_digits_radix(ascending, 10)
+                }
+
+                fn digit_sum_mod(self, m: u64) -> u64 {
+                    self.digit_sum_mod_radix(m, 10)
+                }
+
+                fn casting_out_nines(self) -> u8 {
+                    // 各桁の和と元の数は 9 で割った余りが等しい
+                    if self == 0 { return 0; }
+                    match self.unsigned_abs() % 9 {
+                        0 => 9,
+                        r => r as u8,
+                    }
+                }
+
+                #[cfg(feature = "alloc")]
+                fn signed_digits(self) -> (bool, Vec<u8>) {
+                    self.signed_digits_radix(10)
+                }
+
+                fn next_with_digit_sum(self) -> Self {
+                    self.next_with_digit_sum_radix(10)
+                }
+
+                fn checked_next_with_digit_sum(self) -> Option<Self> {
+                    self.checked_next_with_digit_sum_radix(10)
+                }
+
+                fn is_smith(self) -> bool {
+                    if self < 4 { return false; }
+                    let (sum, cnt) = prime_factor_digit_sum(self as u128);
+                    cnt >= 2 && sum == self.digit_sum()
+                }
+
+                fn reverse_add_steps(self, max_iters: u32) -> Option<u32> {
+                    let mut n = self;
+                    for step in 1..=max_iters {
+                        n = n.checked_add(n.checked_reverse()?)?;
+                        // 回文は反転しても同じ値なので、反転が溢れるなら回文ではない
+                        if n.checked_reverse() == Some(n) { return Some(step); }
+                    }
+                    None
+                }
+
+                fn popcount(self) -> u32 {
+                    self.unsigned_abs().count_ones()
+                }
+
+                fn bit_len(self) -> u32 {
+                    (<$t>::BITS - self.unsigned_abs().leading_zeros()).max(1)
+                }
+
+                fn weighted_digit_sum<F: FnMut(u32, u8) -> i64>(self, f: F) -> i64 {
+                    self.weighted_digit_sum_radix(f, 10)
+                }
+
+                fn checked_concat(self, other: Self) -> Option<Self> {
+                    self.checked_concat_radix(other, 10)
+                }
+
+                fn has_increasing_digits(self) -> bool {
+                    self.has_increasing_digits_radix(10)
+                }
+
+                fn has_decreasing_digits(self) -> bool {
+                    self.has_decreasing_digits_radix(10)
+                }
+
+                fn is_stepping(self) -> bool {
+                    self.is_stepping_radix(10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn is_palindrome_padded(self, width: usize) -> bool {
+                    self.is_palindrome_padded_radix(width, 10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn digit_prefix_sums(self) -> Vec<u64> {
+                    self.digit_prefix_sums_radix(10)
+                }
+
+                fn checked_make_max(self) -> Option<Self> {
+                    self.checked_make_max_radix(10)
+                }
+
+                fn checked_make_min(self) -> Option<Self> {
+                    self.checked_make_min_radix(10)
+                }
+
+                fn count_with_digit_sum(limit: Self, target: u64) -> u64 {
+                    if limit < 0 { return 0; }
+                    count_with_digit_sum_u128(limit as u128, target)
                 }
 
-                // O(N) Algorithm: Frequency Distribution
-                fn make_min(self) -> Self {
-                    if self == 0 { return 0; }
-                    let mut counts = [0u32; 10];
-                    let mut n = self;
-                    while n > 0 {
-                        counts[(n % 10) as usize] += 1;
-                        n /= 10;
-                    }
+                #[cfg(feature = "alloc")]
+                fn to_bcd(self) -> Vec<u8> {
+                    let mut d = self.digits();
+                    if d.len() % 2 == 1 { d.insert(0, 0); }
+                    d.chunks(2).map(|c| (c[0] << 4) | c[1]).collect()
+                }
+
+                fn from_bcd(bytes: &[u8]) -> Self {
                     let mut ret: $t = 0;
-                    for d in 0..=9 {
-                        for _ in 0..counts[d] {
-                            ret *= 10;
-                            ret += d as $t;
+                    for &byte in bytes {
+                        for nib in [byte >> 4, byte & 0x0f] {
+                            assert!(nib <= 9, "invalid BCD nibble {:#x}", nib);
+                            ret = ret * 10 + nib as $t;
                         }
                     }
                     ret
                 }
 
+                fn fold_digits<B, F: FnMut(B, u8) -> B>(self, init: B, f: F) -> B {
+                    self.fold_digits_radix(init, f, 10)
+                }
+
+                fn as_single_digit(self) -> Option<u8> {
+                    self.as_single_digit_radix(10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn factorial_digit_sum(n: u32) -> u64 {
+                    factorial_digit_sum_limbs(n)
+                }
+
+                fn is_bouncy(self) -> bool {
+                    self.is_bouncy_radix(10)
+                }
+
+                fn smallest_arrangement_fixed(self) -> Self {
+                    self.smallest_arrangement_fixed_radix(10)
+                }
+
+                fn checked_smallest_arrangement_fixed(self) -> Option<Self> {
+                    self.checked_smallest_arrangement_fixed_radix(10)
+                }
+
+                fn largest_arrangement_fixed(self) -> Self {
+                    self.largest_arrangement_fixed_radix(10)
+                }
+
+                fn checksum_mod(self, weights: &[i64], modulus: i64) -> i64 {
+                    self.checksum_mod_radix(weights, modulus, 10)
+                }
+
+                fn is_undulating(self) -> bool {
+                    self.is_undulating_radix(10)
+                }
+
+                fn wrapping_concat(self, other: Self) -> Self {
+                    self.wrapping_concat_radix(other, 10)
+                }
+
+                fn wrapping_make_max(self) -> Self {
+                    self.wrapping_make_max_radix(10)
+                }
+
+                fn trailing_zeros_10(self) -> u32 {
+                    self.trailing_zeros_radix(10)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn reverse_digits(self) -> (Vec<u8>, bool) {
+                    self.reverse_digits_radix(10)
+                }
+
+                fn is_pdi(self, power: u32) -> bool {
+                    self.is_pdi_radix(power, 10)
+                }
+
+                fn most_common_digit(self) -> u8 {
+                    most_common_in(&self.digit_counts())
+                }
+
+                fn least_common_digit(self) -> u8 {
+                    least_common_in(&self.digit_counts())
+                }
+
+                fn digit_sum_trajectory(self) -> impl Iterator<Item = u64> {
+                    let n = self.unsigned_abs() as u128;
+                    assert!(n <= u64::MAX as u128, "value does not fit in u64");
+                    core::iter::successors(Some(n as u64), |&n| (n >= 10).then(|| n.digit_sum()))
+                }
+
+                fn digit_product_trajectory(self) -> impl Iterator<Item = u64> {
+                    let n = self.unsigned_abs() as u128;
+                    assert!(n <= u64::MAX as u128, "value does not fit in u64");
+                    core::iter::successors(Some(n as u64), |&n| (n >= 10).then(|| n.digit_product()))
+                }
+
+                fn is_digit_reverse_of(self, other: Self) -> bool {
+                    self.is_digit_reverse_of_radix(other, 10)
+                }
+
+                fn is_digit_reverse_of_strict(self, other: Self) -> bool {
+                    self.is_digit_reverse_of_strict_radix(other, 10)
+                }
+
+                fn is_emirp(self) -> bool {
+                    self > 0 && is_emirp_u128(self as u128)
+                }
+
+                fn is_vampire(self) -> bool {
+                    self.vampire_fangs().is_some()
+                }
+
+                fn vampire_fangs(self) -> Option<(Self, Self)> {
+                    if self < 0 { return None; }
+                    vampire_fangs_u128(self as u128).map(|(x, y)| (x as $t, y as $t))
+                }
+
+                fn concat_many_10(parts: &[Self]) -> Self {
+                    Self::concat_many(parts, 10)
+                }
+
+                fn checked_concat_many_10(parts: &[Self]) -> Option<Self> {
+                    Self::checked_concat_many(parts, 10)
+                }
+
+                fn map_digits<F: Fn(u8) -> u8>(self, f: F) -> Self {
+                    self.map_digits_radix(f, 10)
+                }
+
+                fn checked_map_digits<F: Fn(u8) -> u8>(self, f: F) -> Option<Self> {
+                    self.checked_map_digits_radix(f, 10)
+                }
+
+                fn min_rotation(self) -> Self {
+                    self.min_rotation_radix(10)
+                }
+
+                fn max_rotation(self) -> Self {
+                    self.max_rotation_radix(10)
+                }
+
+                fn digit_signature(self) -> u64 {
+                    self.unsigned_abs().digit_signature()
+                }
+
+                fn digits_iter(self) -> impl Iterator<Item = u8> {
+                    self.unsigned_abs().digits_iter()
+                }
+
                 // --- Radix Implementations ---
+                #[cfg(feature = "alloc")]
                 fn digits_radix(self, base: u32) -> Vec<u8> {
                     if self == 0 { return vec![0]; }
-                    let mut n = self;
-                    let b = base as $t;
-                    // 汎用ベースのためヒューリスティックな容量確保
                     let mut ret = Vec::with_capacity(32);
-                    while n > 0 {
-                        ret.push((n % b) as u8);
-                        n /= b;
+                    match checked_radix_pow::<$t>(valid_radix(base), 1) {
+                        // abs を取らずに負のまま割り、余りの絶対値を取るので MIN でも溢れない
+                        Ok(b) => {
+                            let mut n = self;
+                            while n != 0 {
+                                ret.push((n % b).unsigned_abs() as u8);
+                                n /= b;
+                            }
+                        }
+                        // 基数が型に収まらない場合は絶対値を u128 で割る (i8::MIN の 128 進数など)
+                        Err(_) => {
+                            let mut n = self.unsigned_abs() as u128;
+                            while n > 0 {
+                                ret.push((n % base as u128) as u8);
+                                n /= base as u128;
+                            }
+                        }
                     }
                     ret.reverse();
                     ret
                 }
 
                 fn from_digits_radix(digits: &[u8], base: u32) -> Self {
+                    Self::try_from_digits_radix(digits, valid_radix(base))
+                        .expect("digit out of range for the base or value out of range for the type")
+                }
+
+                fn digit_sum_radix(self, base: u32) -> u64 {
+                    // abs を取らないので MIN でも溢れず、基数が型に収まらない場合も扱える
+                    self.unsigned_abs().digit_sum_radix(base)
+                }
+
+                fn digit_product_radix(self, base: u32) -> u64 {
+                    self.unsigned_abs().digit_product_radix(base)
+                }
+
+                fn digits_len_radix(self, base: u32) -> u32 {
+                    self.unsigned_abs().digits_len_radix(base)
+                }
+
+                fn reverse_radix(self, base: u32) -> Self {
+                    self.checked_reverse_radix(base).expect("reversed value overflows the type")
+                }
+
+                fn is_palindrome_radix(self, base: u32) -> bool {
+                    self == self.reverse_radix(base)
+                }
+
+                fn nth_digit_radix(self, i: u32, base: u32) -> Option<u8> {
+                    self.unsigned_abs().nth_digit_radix(i, base)
+                }
+
+                fn concat_radix(self, other: Self, base: u32) -> Self {
+                    valid_radix(base);
+                    self.checked_concat_radix(other, base).expect("concatenated value overflows the type")
+                }
+
+                fn contains_digit_radix(self, digit: u8, base: u32) -> bool {
+                    self.unsigned_abs().contains_digit_radix(digit, base)
+                }
+
+                // O(N) Algorithm: Frequency Distribution
+                fn make_max_radix(self, base: u32) -> Self {
+                    self.checked_make_max_radix(base).expect("rearranged value overflows the type")
+                }
+
+                // O(N) Algorithm: Frequency Distribution
+                fn make_min_radix(self, base: u32) -> Self {
+                    self.checked_make_min_radix(base).expect("rearranged value overflows the type")
+                }
+
+                fn count_digit_radix(self, digit: u8, base: u32) -> u32 {
+                    self.unsigned_abs().count_digit_radix(digit, base)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn digit_counts_radix(self, base: u32) -> Vec<u32> {
+                    self.unsigned_abs().digit_counts_radix(base)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn digit_positions_radix(self, digit: u8, base: u32) -> Vec<u32> {
+                    self.unsigned_abs().digit_positions_radix(digit, base)
+                }
+
+                fn is_pandigital_radix(self, base: u32) -> bool {
+                    self.digits_len_radix(base) == base
+                        && self.distinct_digit_count_radix(base) == base
+                }
+
+                fn rotate_digits_left_radix(self, n: u32, base: u32) -> Self {
+                    self.checked_rotate_digits_left_radix(n, base).expect("rotated value overflows the type")
+                }
+
+                fn rotate_digits_right_radix(self, n: u32, base: u32) -> Self {
+                    self.checked_rotate_digits_right_radix(n, base).expect("rotated value overflows the type")
+                }
+
+                fn checked_rotate_digits_left_radix(self, n: u32, base: u32) -> Option<Self> {
+                    let ret = self.unsigned_abs().checked_rotate_digits_left_radix(n, base)?;
+                    if self < 0 { <$t>::checked_sub_unsigned(0, ret) } else { <$t>::checked_add_unsigned(0, ret) }
+                }
+
+                fn checked_rotate_digits_right_radix(self, n: u32, base: u32) -> Option<Self> {
+                    let ret = self.unsigned_abs().checked_rotate_digits_right_radix(n, base)?;
+                    if self < 0 { <$t>::checked_sub_unsigned(0, ret) } else { <$t>::checked_add_unsigned(0, ret) }
+                }
+
+                fn digit_square_sum_radix(self, base: u32) -> u64 {
+                    self.unsigned_abs().digit_square_sum_radix(base)
+                }
+
+                fn is_happy_radix(self, base: u32) -> bool {
+                    let mut slow = self.digit_square_sum_radix(base);
+                    let mut fast = slow.digit_square_sum_radix(base);
+                    while slow != fast {
+                        slow = slow.digit_square_sum_radix(base);
+                        fast = fast.digit_square_sum_radix(base).digit_square_sum_radix(base);
+                    }
+                    slow == 1
+                }
+
+                fn is_armstrong_radix(self, base: u32) -> bool {
+                    self.unsigned_abs().is_armstrong_radix(base)
+                }
+
+                fn is_harshad_radix(self, base: u32) -> bool {
+                    self.unsigned_abs().is_harshad_radix(base)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn to_radix_string(self, base: u32) -> String {
+                    assert!((2..=36).contains(&base), "base must be in 2..=36 (got {})", base);
+                    let mut s = String::new();
+                    if self < 0 { s.push('-'); }
+                    s.extend(
+                        self.digits_radix(base)
+                            .into_iter()
+                            .map(|d| char::from_digit(d as u32, base).unwrap()),
+                    );
+                    s
+                }
+
+                #[cfg(feature = "alloc")]
+                fn to_radix_string_upper(self, base: u32) -> String {
+                    let mut s = self.to_radix_string(base);
+                    s.make_ascii_uppercase();
+                    s
+                }
+
+                fn from_radix_string(s: &str, base: u32) -> Result<Self, ParseKetaError> {
+                    assert!((2..=36).contains(&base), "base must be in 2..=36 (got {})", base);
+                    let (neg, body) = match s.strip_prefix('-') {
+                        Some(rest) => (true, rest),
+                        None => (false, s.strip_prefix('+').unwrap_or(s)),
+                    };
+                    if body.is_empty() { return Err(ParseKetaError::Empty); }
+                    let b = valid_radix(base) as $t;
+                    let mut ret: $t = 0;
+                    // 負の数は負の方向に積み上げることで MIN まで表現できる
+                    for c in body.chars() {
+                        let d = radix_char_value(c, base)? as $t;
+                        ret = ret
+                            .checked_mul(b)
+                            .and_then(|r| if neg { r.checked_sub(d) } else { r.checked_add(d) })
+                            .ok_or(ParseKetaError::Overflow)?;
+                    }
+                    Ok(ret)
+                }
+
+                fn try_from_digits_radix(digits: &[u8], base: u32) -> Option<Self> {
+                    if digits.iter().any(|&d| d as u32 >= base) { return None; }
+                    // 基数が型に収まらなければ、0 でない数字は最後の桁にしか置けない
+                    let Ok(b) = checked_radix_pow::<$t>(base, 1) else { return single_digit_from(digits); };
                     let mut ret: $t = 0;
-                    let b = base as $t;
                     for &d in digits {
-                        ret = ret * b + (d as $t);
+                        ret = ret.checked_mul(b)?.checked_add(d as $t)?;
+                    }
+                    Some(ret)
+                }
+
+                fn digit_slice_radix(self, start: u32, len: u32, base: u32) -> Self {
+                    let ret = self.unsigned_abs().digit_slice_radix(start, len, base) as $t;
+                    if self < 0 { ret.wrapping_neg() } else { ret }
+                }
+
+                fn with_digit_radix(self, i: u32, digit: u8, base: u32) -> Self {
+                    self.checked_with_digit_radix(i, digit, base)
+                        .expect("with_digit result overflows the type")
+                }
+
+                fn checked_with_digit_radix(self, i: u32, digit: u8, base: u32) -> Option<Self> {
+                    let ret = self.unsigned_abs().checked_with_digit_radix(i, digit, base)?;
+                    if self < 0 { <$t>::checked_sub_unsigned(0, ret) } else { <$t>::checked_add_unsigned(0, ret) }
+                }
+
+                fn nth_digit_from_right_radix(self, i: u32, base: u32) -> Option<u8> {
+                    self.unsigned_abs().nth_digit_from_right_radix(i, base)
+                }
+
+                fn max_digit_radix(self, base: u32) -> u8 {
+                    self.unsigned_abs().max_digit_radix(base)
+                }
+
+                fn min_digit_radix(self, base: u32) -> u8 {
+                    self.unsigned_abs().min_digit_radix(base)
+                }
+
+                fn distinct_digit_count_radix(self, base: u32) -> u32 {
+                    self.unsigned_abs().distinct_digit_count_radix(base)
+                }
+
+                fn is_repdigit_radix(self, base: u32) -> bool {
+                    self.distinct_digit_count_radix(base) == 1
+                }
+
+                fn is_repunit_radix(self, base: u32) -> bool {
+                    self.unsigned_abs().is_repunit_radix(base)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn next_palindrome_radix(self, base: u32) -> Self {
+                    self.checked_next_palindrome_radix(base)
+                        .expect("next palindrome overflows the type")
+                }
+
+                #[cfg(feature = "alloc")]
+                fn checked_next_palindrome_radix(self, base: u32) -> Option<Self> {
+                    if self < 0 {
+                        // 絶対値が小さくなる方向なので必ず収まる
+                        let d = prev_palindrome_digits(&self.digits_radix(base), base);
+                        return Self::try_from_digits_radix(&d, base).map(|v| -v);
+                    }
+                    let d = next_palindrome_digits(&self.digits_radix(base), base);
+                    Self::try_from_digits_radix(&d, base)
+                }
+
+                fn digit_power_sum_radix(self, exp: u32, base: u32) -> u128 {
+                    self.unsigned_abs().digit_power_sum_radix(exp, base)
+                }
+
+                // abs() を使わず負のまま桁を取り出すので MIN も扱える
+                fn checked_reverse_radix(self, base: u32) -> Option<Self> {
+                    // 絶対値で反転してから符号を戻す (MIN でも abs を取らない)
+                    let ret = self.unsigned_abs().checked_reverse_radix(base)?;
+                    if self < 0 { <$t>::checked_sub_unsigned(0, ret) } else { <$t>::checked_add_unsigned(0, ret) }
+                }
+
+                fn saturating_reverse_radix(self, base: u32) -> Self {
+                    match self.checked_reverse_radix(base) {
+                        Some(v) => v,
+                        None if self < 0 => <$t>::MIN,
+                        None => <$t>::MAX,
                     }
+                }
+
+                fn wrapping_reverse_radix(self, base: u32) -> Self {
+                    // 2^bits を法とすれば、符号なしで反転してから符号を戻しても同じ
+                    let ret = self.unsigned_abs().wrapping_reverse_radix(base) as $t;
+                    if self < 0 { ret.wrapping_neg() } else { ret }
+                }
+
+                fn checked_digit_product_radix(self, base: u32) -> Option<u64> {
+                    self.unsigned_abs().checked_digit_product_radix(base)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn digits_padded_radix(self, width: usize, base: u32) -> Vec<u8> {
+                    let d = self.digits_radix(base);
+                    assert!(d.len() <= width, "{} digits do not fit in width {}", d.len(), width);
+                    let mut ret = vec![0; width - d.len()];
+                    ret.extend_from_slice(&d);
                     ret
                 }
 
-                fn digit_sum_radix(self, base: u32) -> u64 {
-                    let mut n = self;
-                    let b = base as $t;
-                    let mut sum: u64 = 0;
-                    while n > 0 {
-                        sum += (n % b) as u64;
-                        n /= b;
+                #[cfg(feature = "alloc")]
+                fn to_radix_string_padded(self, width: usize, base: u32) -> String {
+                    assert!((2..=36).contains(&base), "base must be in 2..=36 (got {})", base);
+                    let mut s = String::new();
+                    if self < 0 { s.push('-'); }
+                    s.extend(
+                        self.digits_padded_radix(width, base)
+                            .into_iter()
+                            .map(|d| char::from_digit(d as u32, base).unwrap()),
+                    );
+                    s
+                }
+
+                fn interleave_radix(self, other: Self, base: u32) -> Self {
+                    self.checked_interleave_radix(other, base)
+                        .expect("interleaved value overflows the type")
+                }
+
+                fn checked_interleave_radix(self, other: Self, base: u32) -> Option<Self> {
+                    let ret = self.unsigned_abs().checked_interleave_radix(other.unsigned_abs(), base)?;
+                    if self < 0 { <$t>::checked_sub_unsigned(0, ret) } else { <$t>::checked_add_unsigned(0, ret) }
+                }
+
+                fn split_at_radix(self, i: u32, base: u32) -> (Self, Self) {
+                    // どちらも絶対値は元以下なので、wrapping_neg で符号を戻せる (|MIN| は MIN に戻る)
+                    let (hi, lo) = self.unsigned_abs().split_at_radix(i, base);
+                    let (hi, lo) = (hi as $t, lo as $t);
+                    if self < 0 { (hi.wrapping_neg(), lo.wrapping_neg()) } else { (hi, lo) }
+                }
+
+                fn remove_digit_radix(self, digit: u8, base: u32) -> Self {
+                    let ret = self.unsigned_abs().remove_digit_radix(digit, base) as $t;
+                    if self < 0 { ret.wrapping_neg() } else { ret }
+                }
+
+                fn digit_cmp_radix(self, other: Self, base: u32) -> Ordering {
+                    // 桁数が同じなら、上位桁からの辞書順は絶対値の大小と一致する
+                    self.digits_len_radix(base)
+                        .cmp(&other.digits_len_radix(base))
+                        .then(self.unsigned_abs().cmp(&other.unsigned_abs()))
+                }
+
+                fn cmp_by_digit_sum_radix(self, other: Self, base: u32) -> Ordering {
+                    self.digit_sum_radix(base)
+                        .cmp(&other.digit_sum_radix(base))
+                        .then(self.cmp(&other))
+                }
+
+                #[cfg(feature = "alloc")]
+                fn group_digits_radix(self, sep: char, group: usize, base: u32) -> String {
+                    assert!(group > 0, "group size must be positive");
+                    let s = self.to_radix_string(base);
+                    let (neg, body) = match s.strip_prefix('-') {
+                        Some(rest) => (true, rest),
+                        None => (false, s.as_str()),
+                    };
+                    let mut ret = String::with_capacity(s.len() + s.len() / group);
+                    if neg { ret.push('-'); }
+                    // 数字部分は ASCII なので、バイト長を桁数として扱える
+                    for (i, c) in body.chars().enumerate() {
+                        if i > 0 && (body.len() - i) % group == 0 {
+                            ret.push(sep);
+                        }
+                        ret.push(c);
                     }
-                    sum
+                    ret
                 }
 
-                fn digit_product_radix(self, base: u32) -> u64 {
-                    if self == 0 { return 0; }
-                    let mut n = self;
-                    let b = base as $t;
-                    let mut prod: u64 = 1;
-                    while n > 0 {
-                        prod *= (n % b) as u64;
-                        n /= b;
+                fn is_munchausen_radix(self, base: u32) -> bool {
+                    self >= 0 && self.unsigned_abs().is_munchausen_radix(base)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn sort_digits_radix(self, ascending: bool, base: u32) -> Vec<u8> {
+                    let mut d = self.digits_radix(base);
+                    if ascending {
+                        d.sort_unstable();
+                    } else {
+                        d.sort_unstable_by(|a, b| b.cmp(a));
                     }
-                    prod
+                    d
+                }
+
+                fn digit_sum_mod_radix(self, m: u64, base: u32) -> u64 {
+                    self.unsigned_abs().digit_sum_mod_radix(m, base)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn signed_digits_radix(self, base: u32) -> (bool, Vec<u8>) {
+                    (self < 0, self.digits_radix(base))
+                }
+
+                fn next_with_digit_sum_radix(self, base: u32) -> Self {
+                    self.checked_next_with_digit_sum_radix(base)
+                        .expect("no larger number with the same digit sum fits the type")
+                }
+
+                fn checked_next_with_digit_sum_radix(self, base: u32) -> Option<Self> {
+                    let b = valid_radix(base) as u128;
+                    let n = self.unsigned_abs() as u128;
+                    if self >= 0 {
+                        return next_with_digit_sum_u128(n, b).and_then(|n| <$t>::try_from(n).ok());
+                    }
+                    match prev_with_digit_sum_u128(n, b) {
+                        // 絶対値は self より小さいので必ず収まる
+                        Some(p) => Some(-(p as $t)),
+                        // 負の側に候補がなければ、0 をまたいで和が等しい最小の正の数
+                        None => {
+                            let s = self.digit_sum_radix(base);
+                            min_with_digit_sum_u128(s, b).and_then(|n| <$t>::try_from(n).ok())
+                        }
+                    }
+                }
+
+                fn weighted_digit_sum_radix<F: FnMut(u32, u8) -> i64>(self, f: F, base: u32) -> i64 {
+                    self.unsigned_abs().weighted_digit_sum_radix(f, base)
+                }
+
+                fn try_concat_radix(self, other: Self, base: u32) -> Result<Self, DigitError> {
+                    // digits_len_radix は基数を型にキャストするので、先に収まるか確かめる
+                    checked_radix_pow::<$t>(base, 1)?;
+                    let p: $t = checked_radix_pow(base, other.digits_len_radix(base))?;
+                    let added = other.checked_abs().ok_or(DigitError::Overflow)?;
+                    let shifted = self.checked_mul(p).ok_or(DigitError::Overflow)?;
+                    if self < 0 { shifted.checked_sub(added) } else { shifted.checked_add(added) }
+                        .ok_or(DigitError::Overflow)
+                }
+
+                fn checked_concat_radix(self, other: Self, base: u32) -> Option<Self> {
+                    self.try_concat_radix(other, base).ok()
                 }
 
-                fn digits_len_radix(self, base: u32) -> u32 {
-                    if self == 0 { return 1; }
-                    let mut n = self;
-                    let b = base as $t;
-                    let mut cnt = 0;
-                    while n > 0 {
-                        n /= b;
-                        cnt += 1;
-                    }
-                    cnt
+                fn has_increasing_digits_radix(self, base: u32) -> bool {
+                    self.unsigned_abs().has_increasing_digits_radix(base)
                 }
 
-                fn reverse_radix(self, base: u32) -> Self {
-                    let mut n = self;
-                    let b = base as $t;
-                    let mut ret: $t = 0;
-                    while n > 0 {
-                        ret = ret * b + (n % b);
-                        n /= b;
-                    }
-                    ret
+                fn has_decreasing_digits_radix(self, base: u32) -> bool {
+                    self.unsigned_abs().has_decreasing_digits_radix(base)
                 }
 
-                fn is_palindrome_radix(self, base: u32) -> bool {
-                    self == self.reverse_radix(base)
+                fn is_stepping_radix(self, base: u32) -> bool {
+                    self.unsigned_abs().is_stepping_radix(base)
                 }
 
-                fn nth_digit_radix(self, i: u32, base: u32) -> Option<u8> {
-                    let l = self.digits_len_radix(base);
-                    if i >= l { return None; }
-                    let pow = l - 1 - i;
-                    let b = base as $t;
-                    Some(((self / b.pow(pow)) % b) as u8)
+                #[cfg(feature = "alloc")]
+                fn is_palindrome_padded_radix(self, width: usize, base: u32) -> bool {
+                    let d = self.digits_padded_radix(width, base);
+                    d.iter().eq(d.iter().rev())
                 }
 
-                fn concat_radix(self, other: Self, base: u32) -> Self {
-                    let shift = other.digits_len_radix(base);
-                    let b = base as $t;
-                    self * b.pow(shift) + other
+                #[cfg(feature = "alloc")]
+                fn digit_prefix_sums_radix(self, base: u32) -> Vec<u64> {
+                    let mut sum = 0u64;
+                    self.digits_radix(base)
+                        .into_iter()
+                        .map(|d| {
+                            sum += d as u64;
+                            sum
+                        })
+                        .collect()
                 }
 
-                fn contains_digit_radix(self, digit: u8, base: u32) -> bool {
-                    let mut n = self;
-                    let b = base as $t;
-                    if n == 0 { return digit == 0; }
-                    while n > 0 {
-                        if (n % b) as u8 == digit {
-                            return true;
-                        }
-                        n /= b;
-                    }
-                    false
+                fn checked_make_max_radix(self, base: u32) -> Option<Self> {
+                    // 負の数は絶対値を最小にする (絶対値は元より小さくなるので必ず収まる)
+                    if self < 0 { return Some((self.unsigned_abs().make_min_radix(base) as $t).wrapping_neg()); }
+                    <$t>::try_from(self.unsigned_abs().checked_make_max_radix(base)?).ok()
                 }
 
-                fn make_max_radix(self, base: u32) -> Self {
-                    let mut d = self.digits_radix(base);
-                    d.sort_unstable_by(|a, b| b.cmp(a));
-                    Self::from_digits_radix(&d, base)
+                fn checked_make_min_radix(self, base: u32) -> Option<Self> {
+                    // 負の数は絶対値を最大にする
+                    if self < 0 { return <$t>::checked_sub_unsigned(0, self.unsigned_abs().checked_make_max_radix(base)?); }
+                    Some(self.unsigned_abs().make_min_radix(base) as $t)
                 }
 
-                fn make_min_radix(self, base: u32) -> Self {
-                    let mut d = self.digits_radix(base);
-                    d.sort_unstable();
-                    Self::from_digits_radix(&d, base)
+                fn fold_digits_radix<B, F: FnMut(B, u8) -> B>(self, init: B, f: F, base: u32) -> B {
+                    self.unsigned_abs().fold_digits_radix(init, f, base)
                 }
-            }
-        )*
-    };
-}
 
-// ----------------------------------------------------------------
-// 実装用マクロ (符号付き整数用: i32, i64...)
-// ----------------------------------------------------------------
-macro_rules! impl_keta_int {
-    ($($t:ty),*) => {
-        $(
-            impl Keta for $t {
-                // --- 10-base Shortcuts (Optimized) ---
+                fn as_single_digit_radix(self, base: u32) -> Option<u8> {
+                    let n = self.unsigned_abs();
+                    if (n as u128) < valid_radix(base) as u128 { Some(n as u8) } else { None }
+                }
 
-                fn digits(self) -> Vec<u8> {
-                    if self == 0 { return vec![0]; }
-                    let mut n = self.abs();
-                    // ilog10で正確な容量を計算 (再アロケーション防止)
-                    let cap = (n.ilog10() + 1) as usize;
-                    let mut ret = Vec::with_capacity(cap);
-                    while n > 0 {
-                        ret.push((n % 10) as u8);
-                        n /= 10;
-                    }
-                    ret.reverse();
-                    ret
+                fn is_bouncy_radix(self, base: u32) -> bool {
+                    self.unsigned_abs().is_bouncy_radix(base)
                 }
 
-                fn from_digits(digits: &[u8]) -> Self {
+                #[cfg(feature = "alloc")]
+                fn digit_view(self, base: u32) -> DigitView {
+                    DigitView::new(self.digits_radix(base), base)
+                }
+
+                #[cfg(feature = "alloc")]
+                fn to_radix_string_with(self, base: u32, alphabet: &[char]) -> String {
+                    valid_alphabet(alphabet, base);
+                    let mut s = String::new();
+                    if self < 0 { s.push('-'); }
+                    s.extend(self.digits_radix(base).into_iter().map(|d| alphabet[d as usize]));
+                    s
+                }
+
+                fn from_radix_string_with(s: &str, base: u32, alphabet: &[char]) -> Result<Self, ParseKetaError> {
+                    let index = AlphabetIndex::new(alphabet, base);
+                    let (neg, body) = match index.strip_sign(s, '-') {
+                        Some(rest) => (true, rest),
+                        None => (false, index.strip_sign(s, '+').unwrap_or(s)),
+                    };
+                    if body.is_empty() { return Err(ParseKetaError::Empty); }
+                    // 基数が型に収まらない場合 (u8 / i8 の 200 進数など) は i128 で掛ける
+                    let b: Option<$t> = checked_radix_pow(base, 1).ok();
                     let mut ret: $t = 0;
-                    for &d in digits {
-                        ret = ret * 10 + (d as $t);
+                    // 負の数は負の方向に積み上げることで MIN まで表現できる
+                    for c in body.chars() {
+                        let v = index.value(c)?;
+                        let d = if neg { <$t>::try_from(-i16::from(v)).ok() } else { <$t>::try_from(v).ok() };
+                        let shifted = match b {
+                            Some(b) => ret.checked_mul(b),
+                            None => <$t>::try_from(ret as i128 * base as i128).ok(),
+                        };
+                        ret = shifted
+                            .zip(d)
+                            .and_then(|(r, d)| r.checked_add(d))
+                            .ok_or(ParseKetaError::Overflow)?;
                     }
-                    ret
+                    Ok(ret)
                 }
 
-                fn digit_sum(self) -> u64 {
-                    let mut n = self.abs();
-                    let mut sum: u64 = 0;
-                    while n > 0 {
-                        sum += (n % 10) as u64;
-                        n /= 10;
-                    }
-                    sum
+                fn smallest_arrangement_fixed_radix(self, base: u32) -> Self {
+                    self.checked_smallest_arrangement_fixed_radix(base).expect("rearranged value overflows the type")
                 }
 
-                fn digit_product(self) -> u64 {
-                    let mut n = self.abs();
-                    if n == 0 { return 0; }
-                    let mut prod: u64 = 1;
-                    while n > 0 {
-                        prod *= (n % 10) as u64;
-                        n /= 10;
-                    }
-                    prod
+                fn checked_smallest_arrangement_fixed_radix(self, base: u32) -> Option<Self> {
+                    // 負の数は絶対値を最大にするので、型に収まらないことがある
+                    if self < 0 { return self.checked_make_min_radix(base); }
+                    // 元の数も先頭が 0 でない並びの1つなので、結果は元の数以下に収まる
+                    Some(self.unsigned_abs().smallest_arrangement_fixed_radix(base) as $t)
                 }
 
-                fn digits_len(self) -> u32 {
-                    if self == 0 { return 1; }
-                    self.abs().ilog10() + 1
+                fn largest_arrangement_fixed_radix(self, base: u32) -> Self {
+                    if self < 0 { return (self.unsigned_abs().smallest_arrangement_fixed_radix(base) as $t).wrapping_neg(); }
+                    self.make_max_radix(base)
                 }
 
-                fn reverse(self) -> Self {
-                    let mut n = self.abs();
-                    let mut ret: $t = 0;
-                    while n > 0 {
-                        ret = ret * 10 + (n % 10);
-                        n /= 10;
-                    }
-                    if self < 0 { -ret } else { ret }
+                fn checksum_mod_radix(self, weights: &[i64], modulus: i64, base: u32) -> i64 {
+                    self.unsigned_abs().checksum_mod_radix(weights, modulus, base)
                 }
 
-                fn is_palindrome(self) -> bool {
-                    self == self.reverse()
+                fn is_undulating_radix(self, base: u32) -> bool {
+                    self.unsigned_abs().is_undulating_radix(base)
                 }
 
-                fn nth_digit(self, i: u32) -> Option<u8> {
-                    let l = self.digits_len();
-                    if i >= l { return None; }
-                    let pow = l - 1 - i;
-                    Some(((self.abs() / (10 as $t).pow(pow)) % 10) as u8)
+                fn wrapping_concat_radix(self, other: Self, base: u32) -> Self {
+                    let shift = other.unsigned_abs().digits_len_radix(base);
+                    // 型に収まらない基数を切り詰めても、2^bits を法とした値は変わらない
+                    let b = valid_radix(base) as $t;
+                    let added = other.wrapping_abs();
+                    let shifted = self.wrapping_mul(b.wrapping_pow(shift));
+                    if self < 0 { shifted.wrapping_sub(added) } else { shifted.wrapping_add(added) }
                 }
 
-                fn concat(self, other: Self) -> Self {
-                    let shift = other.digits_len();
-                    let added = other.abs();
-                    let shifted = self * (10 as $t).pow(shift);
-                    if self < 0 { shifted - added } else { shifted + added }
+                fn wrapping_make_max_radix(self, base: u32) -> Self {
+                    if self < 0 { return (self.unsigned_abs().make_min_radix(base) as $t).wrapping_neg(); }
+                    self.unsigned_abs().wrapping_make_max_radix(base) as $t
                 }
 
-                fn contains_digit(self, digit: u8) -> bool {
-                    let mut n = self.abs();
-                    if n == 0 { return digit == 0; }
-                    while n > 0 {
-                        if (n % 10) as u8 == digit {
-                            return true;
-                        }
-                        n /= 10;
-                    }
-                    false
+                fn trailing_zeros_radix(self, base: u32) -> u32 {
+                    self.unsigned_abs().trailing_zeros_radix(base)
                 }
 
-                // O(N) Algorithm
-                fn make_max(self) -> Self {
-                    if self == 0 { return 0; }
-                    let mut counts = [0u32; 10];
-                    let mut n = self.abs();
-                    while n > 0 {
-                        counts[(n % 10) as usize] += 1;
-                        n /= 10;
-                    }
-                    let mut ret: $t = 0;
-                    for d in (0..=9).rev() {
-                        for _ in 0..counts[d] {
-                            ret *= 10;
-                            ret += d as $t;
-                        }
-                    }
-                    ret
+                fn leading_zeros_radix(self, width: u32, base: u32) -> u32 {
+                    width.saturating_sub(self.digits_len_radix(base))
                 }
 
-                // O(N) Algorithm
-                fn make_min(self) -> Self {
-                    if self == 0 { return 0; }
-                    let mut counts = [0u32; 10];
-                    let mut n = self.abs();
-                    while n > 0 {
-                        counts[(n % 10) as usize] += 1;
-                        n /= 10;
-                    }
-                    let mut ret: $t = 0;
-                    for d in 0..=9 {
-                        for _ in 0..counts[d] {
-                            ret *= 10;
-                            ret += d as $t;
-                        }
-                    }
-                    ret
+                #[cfg(feature = "alloc")]
+                fn reverse_digits_radix(self, base: u32) -> (Vec<u8>, bool) {
+                    let (neg, mut d) = self.signed_digits_radix(base);
+                    d.reverse();
+                    (d, neg)
                 }
 
-                // --- Radix Implementations ---
-                fn digits_radix(self, base: u32) -> Vec<u8> {
-                    if self == 0 { return vec![0]; }
-                    let mut n = self.abs();
-                    let b = base as $t;
-                    let mut ret = Vec::with_capacity(32);
-                    while n > 0 {
-                        ret.push((n % b) as u8);
-                        n /= b;
-                    }
-                    ret.reverse();
-                    ret
+                fn max_digits_radix(base: u32) -> u32 {
+                    // 2進数などでは |MIN| の方が MAX より1桁多くなる
+                    (<$t>::MIN.unsigned_abs() as u128).ilog(valid_radix(base) as u128) + 1
                 }
 
-                fn from_digits_radix(digits: &[u8], base: u32) -> Self {
-                    let mut ret: $t = 0;
-                    let b = base as $t;
-                    for &d in digits {
-                        ret = ret * b + (d as $t);
-                    }
-                    ret
+                fn is_pdi_radix(self, power: u32, base: u32) -> bool {
+                    self.unsigned_abs().is_pdi_radix(power, base)
                 }
 
-                fn digit_sum_radix(self, base: u32) -> u64 {
-                    let mut n = self.abs();
-                    let b = base as $t;
-                    let mut sum: u64 = 0;
-                    while n > 0 {
-                        sum += (n % b) as u64;
-                        n /= b;
-                    }
-                    sum
+                fn add_with_carry(self, other: Self, width: u32, base: u32) -> Option<(Self, bool)> {
+                    if self < 0 || other < 0 { return None; }
+                    let (ret, carry) = self.unsigned_abs().add_with_carry(other.unsigned_abs(), width, base)?;
+                    Some((<$t>::try_from(ret).ok()?, carry))
                 }
 
-                fn digit_product_radix(self, base: u32) -> u64 {
-                    let mut n = self.abs();
-                    if n == 0 { return 0; }
-                    let b = base as $t;
-                    let mut prod: u64 = 1;
-                    while n > 0 {
-                        prod *= (n % b) as u64;
-                        n /= b;
-                    }
-                    prod
+                fn sub_with_borrow(self, other: Self, width: u32, base: u32) -> Option<(Self, bool)> {
+                    if self < 0 || other < 0 { return None; }
+                    let (ret, borrow) = self.unsigned_abs().sub_with_borrow(other.unsigned_abs(), width, base)?;
+                    Some((<$t>::try_from(ret).ok()?, borrow))
                 }
 
-                fn digits_len_radix(self, base: u32) -> u32 {
-                    if self == 0 { return 1; }
-                    let mut n = self.abs();
-                    let b = base as $t;
-                    let mut cnt = 0;
-                    while n > 0 {
-                        n /= b;
-                        cnt += 1;
-                    }
-                    cnt
+                fn most_common_digit_radix(self, base: u32) -> u8 {
+                    self.unsigned_abs().most_common_digit_radix(base)
                 }
 
-                fn reverse_radix(self, base: u32) -> Self {
-                    let mut n = self.abs();
-                    let b = base as $t;
+                fn least_common_digit_radix(self, base: u32) -> u8 {
+                    self.unsigned_abs().least_common_digit_radix(base)
+                }
+
+                fn from_grouped_str(s: &str, base: u32) -> Result<Self, ParseKetaError> {
+                    assert!((2..=36).contains(&base), "base must be in 2..=36 (got {})", base);
+                    let (neg, body) = match s.strip_prefix('-') {
+                        Some(rest) => (true, rest),
+                        None => (false, s.strip_prefix('+').unwrap_or(s)),
+                    };
+                    if body.is_empty() { return Err(ParseKetaError::Empty); }
+                    let b = valid_radix(base) as $t;
                     let mut ret: $t = 0;
-                    while n > 0 {
-                        ret = ret * b + (n % b);
-                        n /= b;
+                    for c in strip_group_separators(body)? {
+                        let d = radix_char_value(c, base)? as $t;
+                        ret = ret
+                            .checked_mul(b)
+                            .and_then(|r| if neg { r.checked_sub(d) } else { r.checked_add(d) })
+                            .ok_or(ParseKetaError::Overflow)?;
                     }
-                    if self < 0 { -ret } else { ret }
+                    Ok(ret)
                 }
 
-                fn is_palindrome_radix(self, base: u32) -> bool {
-                    self == self.reverse_radix(base)
+                fn is_digit_reverse_of_radix(self, other: Self, base: u32) -> bool {
+                    self.checked_reverse_radix(base) == Some(other)
                 }
 
-                fn nth_digit_radix(self, i: u32, base: u32) -> Option<u8> {
-                    let l = self.digits_len_radix(base);
-                    if i >= l { return None; }
-                    let pow = l - 1 - i;
-                    let b = base as $t;
-                    Some(((self.abs() / b.pow(pow)) % b) as u8)
+                fn is_digit_reverse_of_strict_radix(self, other: Self, base: u32) -> bool {
+                    // 反転で末尾の 0 が落ちていれば桁数が変わる
+                    self.is_digit_reverse_of_radix(other, base)
+                        && self.digits_len_radix(base) == other.digits_len_radix(base)
                 }
 
-                fn concat_radix(self, other: Self, base: u32) -> Self {
-                    let shift = other.digits_len_radix(base);
-                    let added = other.abs();
-                    let b = base as $t;
-                    let shifted = self * b.pow(shift);
-                    if self < 0 { shifted - added } else { shifted + added }
+                fn concat_many(parts: &[Self], base: u32) -> Self {
+                    valid_radix(base);
+                    Self::checked_concat_many(parts, base).expect("concatenated value overflows the type")
                 }
 
-                fn contains_digit_radix(self, digit: u8, base: u32) -> bool {
-                    let mut n = self.abs();
-                    let b = base as $t;
-                    if n == 0 { return digit == 0; }
-                    while n > 0 {
-                        if (n % b) as u8 == digit {
-                            return true;
+                fn checked_concat_many(parts: &[Self], base: u32) -> Option<Self> {
+                    let b = <$t>::try_from(valid_radix(base)).ok();
+                    let Some((&first, rest)) = parts.split_first() else { return Some(0); };
+                    let neg = first < 0;
+                    let mut ret = first;
+                    for &p in rest {
+                        // 基数が型に収まらない場合 (i8 の 200 進数など) は i128 で掛ける
+                        let Some(b) = b else {
+                            let shift = (base as i128).checked_pow(p.unsigned_abs().digits_len_radix(base))?;
+                            let s = (ret as i128).checked_mul(shift)?;
+                            let s = if neg == (p < 0) { s + p as i128 } else { s - p as i128 };
+                            ret = <$t>::try_from(s).ok()?;
+                            continue;
+                        };
+                        let mut shift: $t = 1;
+                        let mut m = p;
+                        loop {
+                            shift = shift.checked_mul(b)?;
+                            m /= b;
+                            if m == 0 { break; }
                         }
-                        n /= b;
+                        let s = ret.checked_mul(shift)?;
+                        ret = if neg == (p < 0) { s.checked_add(p)? } else { s.checked_sub(p)? };
                     }
-                    false
+                    Some(ret)
                 }
 
-                fn make_max_radix(self, base: u32) -> Self {
-                    let mut d = self.digits_radix(base);
-                    d.sort_unstable_by(|a, b| b.cmp(a));
-                    Self::from_digits_radix(&d, base)
+                fn map_digits_radix<F: Fn(u8) -> u8>(self, f: F, base: u32) -> Self {
+                    self.checked_map_digits_radix(f, base).expect("mapped value overflows the type")
                 }
 
-                fn make_min_radix(self, base: u32) -> Self {
-                    let mut d = self.digits_radix(base);
-                    d.sort_unstable();
-                    Self::from_digits_radix(&d, base)
+                fn checked_map_digits_radix<F: Fn(u8) -> u8>(self, f: F, base: u32) -> Option<Self> {
+                    // 絶対値を変換してから符号を戻す (MIN でも abs を取らない)
+                    let ret = self.unsigned_abs().checked_map_digits_radix(f, base)?;
+                    if self < 0 { <$t>::checked_sub_unsigned(0, ret) } else { <$t>::checked_add_unsigned(0, ret) }
+                }
+
+                fn min_rotation_radix(self, base: u32) -> Self {
+                    // 負の数の回転も負なので、そのまま値として比べればよい
+                    let l = self.unsigned_abs().digits_len_radix(base);
+                    (1..l)
+                        .filter_map(|k| self.checked_rotate_digits_left_radix(k, base))
+                        .fold(self, <$t>::min)
+                }
+
+                fn max_rotation_radix(self, base: u32) -> Self {
+                    let l = self.unsigned_abs().digits_len_radix(base);
+                    (1..l)
+                        .filter_map(|k| self.checked_rotate_digits_left_radix(k, base))
+                        .fold(self, <$t>::max)
+                }
+
+                fn digits_iter_radix(self, base: u32) -> impl Iterator<Item = u8> {
+                    self.unsigned_abs().digits_iter_radix(base)
                 }
             }
         )*
@@ -669,3 +6163,369 @@ macro_rules! impl_keta_int {
 // 型への実装
 impl_keta_uint!(u8, u16, u32, u64, u128, usize);
 impl_keta_int!(i8, i16, i32, i64, i128, isize);
+
+// ----------------------------------------------------------------
+// 数字列のまま扱う関数
+// ----------------------------------------------------------------
+
+#[cfg(feature = "alloc")]
+/// `from_base` 進数の数字列を `to_base` 進数の数字列に変換する (上位桁から)
+///
+/// 整数型を経由せず数字列のまま割り算を繰り返すので、どの整数型にも収まらない
+/// 長さの数字列でも変換できる。先頭の 0 は取り除かれ、空の入力は `[0]` になる。
+///
+/// # Panics
+/// `from_base` が 2 未満、`to_base` が `2..=256` の範囲外、
+/// または `from_base` 以上の数字が含まれる場合
+///
+/// # Example
+/// ```
+/// use keta::convert_radix;
+/// assert_eq!(convert_radix(&[1, 1, 0], 2, 10), vec![6]);
+/// assert_eq!(convert_radix(&[15, 15], 16, 10), vec![2, 5, 5]);
+/// ```
+pub fn convert_radix(digits: &[u8], from_base: u32, to_base: u32) -> Vec<u8> {
+    assert!(
+        from_base >= 2,
+        "from_base must be at least 2 (got {})",
+        from_base
+    );
+    assert!(
+        (2..=256).contains(&to_base),
+        "to_base must be in 2..=256 (got {})",
+        to_base
+    );
+    assert!(
+        digits.iter().all(|&d| (d as u32) < from_base),
+        "digit out of range for base {}",
+        from_base
+    );
+
+    let from = from_base as u64;
+    let to = to_base as u64;
+    let mut num: Vec<u64> = digits
+        .iter()
+        .skip_while(|&&d| d == 0)
+        .map(|&d| d as u64)
+        .collect();
+    let mut ret = Vec::new();
+    // 筆算の割り算で to_base で割った余りを下位桁から求める
+    while !num.is_empty() {
+        let mut rem = 0;
+        let mut q = Vec::with_capacity(num.len());
+        for &d in &num {
+            let cur = rem * from + d;
+            if !q.is_empty() || cur >= to {
+                q.push(cur / to);
+            }
+            rem = cur % to;
+        }
+        ret.push(rem as u8);
+        num = q;
+    }
+    if ret.is_empty() {
+        ret.push(0);
+    }
+    ret.reverse();
+    ret
+}
+
+#[cfg(feature = "alloc")]
+/// n進数の2つの数字列 (上位桁から) を筆算で足し、和の数字列を返す
+///
+/// 長さが異なっていてもよい。先頭の 0 は取り除かれ、和が 0 なら `[0]` になる。
+///
+/// # Panics
+/// `base` が `2..=256` の範囲外、または `base` 以上の数字が含まれる場合
+///
+/// # Example
+/// ```
+/// use keta::add_digits;
+/// assert_eq!(add_digits(&[9, 9], &[1], 10), vec![1, 0, 0]);
+/// assert_eq!(add_digits(&[1, 1], &[1], 2), vec![1, 0, 0]);
+/// ```
+pub fn add_digits(a: &[u8], b: &[u8], base: u32) -> Vec<u8> {
+    assert!(
+        (2..=256).contains(&base),
+        "base must be in 2..=256 (got {})",
+        base
+    );
+    assert!(
+        a.iter().chain(b).all(|&d| (d as u32) < base),
+        "digit out of range for base {}",
+        base
+    );
+
+    let mut ret = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0;
+    // 下位桁から足し、繰り上がりを次の桁へ送る
+    let mut ia = a.iter().rev();
+    let mut ib = b.iter().rev();
+    loop {
+        let (x, y) = (ia.next(), ib.next());
+        if x.is_none() && y.is_none() {
+            break;
+        }
+        let s = x.map_or(0, |&d| d as u32) + y.map_or(0, |&d| d as u32) + carry;
+        ret.push((s % base) as u8);
+        carry = s / base;
+    }
+    if carry > 0 {
+        ret.push(carry as u8);
+    }
+    while ret.len() > 1 && ret.last() == Some(&0) {
+        ret.pop();
+    }
+    if ret.is_empty() {
+        ret.push(0);
+    }
+    ret.reverse();
+    ret
+}
+
+/// `u32` のスライスの各要素の各桁の和 (10進数) を `out` に書き込む
+///
+/// 各要素は `u32::digit_sum` と同じ表引きで計算するので、結果は `u32::digit_sum` と一致する。
+///
+/// # Panics
+/// `input` と `out` の長さが異なる場合
+///
+/// # Example
+/// ```
+/// use keta::digit_sum_batch;
+/// let mut out = [0; 3];
+/// digit_sum_batch(&[0, 1234, u32::MAX], &mut out);
+/// assert_eq!(out, [0, 10, 57]);
+/// ```
+pub fn digit_sum_batch(input: &[u32], out: &mut [u64]) {
+    assert_eq!(
+        input.len(),
+        out.len(),
+        "out must have the same length as input"
+    );
+    for (o, &n) in out.iter_mut().zip(input) {
+        *o = digit_sum_u64_table(n as u64);
+    }
+}
+
+/// ASCII の数字列 (`0-9`, `a-z`, `A-Z`) を n進数として `u64` に変換する
+///
+/// 符号は受け付けない。空文字列、数字でない文字や `base` 以上の数字を含む場合、
+/// `u64` に収まらない場合は `None`。
+///
+/// # Panics
+/// `base` が `2..=36` の範囲外の場合
+///
+/// # Example
+/// ```
+/// use keta::value_from_ascii_digits;
+/// assert_eq!(value_from_ascii_digits("DeadBeef", 16), Some(0xdeadbeef));
+/// assert_eq!(value_from_ascii_digits("12x", 10), None);
+/// ```
+pub fn value_from_ascii_digits(s: &str, base: u32) -> Option<u64> {
+    assert!(
+        (2..=36).contains(&base),
+        "base must be in 2..=36 (got {})",
+        base
+    );
+    if s.is_empty() {
+        return None;
+    }
+    let mut ret: u64 = 0;
+    for c in s.chars() {
+        let d = radix_char_value(c, base).ok()?;
+        ret = ret.checked_mul(base as u64)?.checked_add(d as u64)?;
+    }
+    Some(ret)
+}
+
+#[cfg(feature = "alloc")]
+/// `u64` を n進数の ASCII の数字列 (小文字) に変換する
+///
+/// # Panics
+/// `base` が `2..=36` の範囲外の場合
+///
+/// # Example
+/// ```
+/// use keta::ascii_digits_from_value;
+/// assert_eq!(ascii_digits_from_value(0xdeadbeef, 16), "deadbeef");
+/// ```
+pub fn ascii_digits_from_value(n: u64, base: u32) -> String {
+    n.to_radix_string(base)
+}
+
+#[cfg(feature = "alloc")]
+/// `low..=high` に含まれるステッピング数 (隣り合う各桁の差がすべて 1 の数) を昇順に列挙する
+///
+/// 全数を判定するのではなく、末尾の桁に ±1 した数字を付け足す幅優先探索で生成する。
+///
+/// # Example
+/// ```
+/// use keta::stepping_numbers_in_range;
+/// assert_eq!(stepping_numbers_in_range(0, 21), vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 12, 21]);
+/// ```
+pub fn stepping_numbers_in_range(low: u64, high: u64) -> Vec<u64> {
+    let mut ret = Vec::new();
+    if low > high {
+        return ret;
+    }
+    if low == 0 {
+        ret.push(0);
+    }
+    // 桁数ごと・上位桁の辞書順に取り出されるので、結果は昇順になる
+    let mut queue: VecDeque<u64> = (1..=9).collect();
+    while let Some(n) = queue.pop_front() {
+        if n > high {
+            continue;
+        }
+        if n >= low {
+            ret.push(n);
+        }
+        let last = n % 10;
+        for d in [last.wrapping_sub(1), last + 1] {
+            if d > 9 {
+                continue;
+            }
+            if let Some(next) = n.checked_mul(10).and_then(|m| m.checked_add(d)) {
+                queue.push_back(next);
+            }
+        }
+    }
+    ret
+}
+
+#[cfg(all(feature = "alloc", any(test, feature = "proptest-helpers")))]
+/// `value` を n進数の数字列に分解して復元し、元の値に戻ることを確かめる (テスト用)
+///
+/// 下流のプロパティテストやファジングで、このクレートの基本的な不変条件
+/// `T::from_digits_radix(&x.digits_radix(b), b) == x` を検査するための関数。
+/// 負の数は数字列が絶対値になるので、符号付きの文字列表現 (`to_radix_string_with`) でも往復させる。
+/// `proptest-helpers` feature で有効になる。
+///
+/// # Panics
+/// 往復した値が一致しない、または数字列に `base` 以上の数字や余分な先頭の 0 がある場合
+///
+/// # Example
+/// ```ignore
+/// keta::assert_radix_roundtrip(-1234i32, 7);
+/// ```
+pub fn assert_radix_roundtrip<T: Keta + PartialEq + core::fmt::Debug>(value: T, base: u32) {
+    let digits = value.digits_radix(base);
+    assert!(
+        digits.iter().all(|&d| (d as u32) < base),
+        "digit out of range for base {} in {:?}",
+        base,
+        value
+    );
+    assert!(
+        digits.len() == 1 || digits[0] != 0,
+        "leading zero in digits of {:?} (base {})",
+        value,
+        base
+    );
+    let (neg, abs_digits) = value.signed_digits_radix(base);
+    assert_eq!(
+        abs_digits, digits,
+        "signed_digits_radix mismatch for {:?}",
+        value
+    );
+    if !neg {
+        assert_eq!(
+            T::from_digits_radix(&digits, base),
+            value,
+            "radix round trip failed in base {}",
+            base
+        );
+    }
+    // 記号と重ならない文字を数字に割り当て、符号付きで往復させる
+    let alphabet: Vec<char> = (0..base)
+        .filter_map(|i| char::from_u32(0x100 + i))
+        .collect();
+    let s = value.to_radix_string_with(base, &alphabet);
+    assert_eq!(
+        T::from_radix_string_with(&s, base, &alphabet),
+        Ok(value),
+        "radix string round trip failed in base {}",
+        base
+    );
+}
+
+/// `low..=high` に含まれる n進数の回文数を昇順に返すイテレータ
+///
+/// すべての数を判定するのではなく、上半分の桁を数え上げて鏡映した回文だけを生成する。
+///
+/// # Panics
+/// `base` が 2 未満の場合
+///
+/// # Example
+/// ```
+/// use keta::palindromes_in_range;
+/// let p: Vec<u64> = palindromes_in_range(90, 130, 10).collect();
+/// assert_eq!(p, vec![99, 101, 111, 121]);
+/// ```
+pub fn palindromes_in_range(low: u64, high: u64, base: u32) -> impl Iterator<Item = u64> {
+    let b = valid_radix(base) as u128;
+    let (low, high) = (low as u128, high as u128);
+    let len_of = |n: u128| {
+        let mut l = 1;
+        let mut n = n / b;
+        while n > 0 {
+            l += 1;
+            n /= b;
+        }
+        l
+    };
+    let (lo_len, hi_len) = (len_of(low), len_of(high));
+    (lo_len..=hi_len)
+        .flat_map(move |l: u32| {
+            let h = l.div_ceil(2);
+            // 最初の桁数は low の上半分から始めて、手前の回文を数えないようにする
+            let first = if l == lo_len {
+                low / b.pow(l - h)
+            } else {
+                b.pow(h - 1)
+            };
+            (first..b.pow(h)).map(move |p| mirror_palindrome(p, l % 2 == 1, b))
+        })
+        .skip_while(move |&p| p < low)
+        .take_while(move |&p| p <= high)
+        .map(|p| p as u64)
+}
+
+// 0..=n の各数の各桁の和の総和 (各位で 0-9 が何回ずつ現れるかを数える)
+fn digit_sum_prefix(n: u128) -> u128 {
+    let mut total = 0;
+    let mut p: u128 = 1;
+    while p <= n {
+        let (high, cur, low) = (n / (p * 10), n / p % 10, n % p);
+        // 上位の桁が一周するごとに 0-9 がそれぞれ p 回ずつ現れる
+        total += high * 45 * p + cur * cur.saturating_sub(1) / 2 * p + cur * (low + 1);
+        p *= 10;
+    }
+    total
+}
+
+/// `low..=high` に含まれるすべての数の各桁の和の総和を返す (10進数)
+///
+/// 1つずつ足すのではなく各位の数字の出現回数から求めるので O(桁数)。`low > high` なら 0。
+///
+/// # Panics
+/// 結果が `u64` に収まらない場合
+///
+/// # Example
+/// ```
+/// use keta::digit_sum_range;
+/// assert_eq!(digit_sum_range(1, 10), 46); // 1 + 2 + ... + 9 + 1
+/// assert_eq!(digit_sum_range(10, 12), 6);
+/// ```
+pub fn digit_sum_range(low: u64, high: u64) -> u64 {
+    if low > high {
+        return 0;
+    }
+    let below = if low == 0 {
+        0
+    } else {
+        digit_sum_prefix(low as u128 - 1)
+    };
+    u64::try_from(digit_sum_prefix(high as u128) - below).expect("digit sum total overflows u64")
+}
@@ -0,0 +1,230 @@
+//! `core::num::NonZero*` 向けの桁操作
+//!
+//! `Keta` には `from_digits` や `remove_digit` のように 0 を返しうるメソッドがあるため、
+//! `NonZero*` には実装できない。代わりに `KetaNonZero` で、0 にならないことが保証できる
+//! 操作 (`reverse`, `make_max`, `make_min`) だけ `Self` を返し、残りは中の整数型の値を返す。
+//!
+//! # Example
+//! ```
+//! use core::num::NonZeroU32;
+//! use keta::KetaNonZero;
+//!
+//! let n = NonZeroU32::new(1200).unwrap();
+//! assert_eq!(n.digit_sum(), 3);
+//! assert_eq!(n.reverse(), NonZeroU32::new(21).unwrap());
+//! assert_eq!(NonZeroU32::from_digits(&[0, 0]), None);
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use crate::Keta;
+
+pub trait KetaNonZero: Copy {
+    // ============================================================
+    // 10進数ショートカット
+    // ============================================================
+
+    /// 10進数で各桁の数字(u8)のベクタに分解する (負の数は絶対値)
+    #[cfg(feature = "alloc")]
+    fn digits(self) -> Vec<u8>;
+
+    /// 数字の列から数値を復元する (10進数, 0 になる場合は `None`)
+    fn from_digits(digits: &[u8]) -> Option<Self>;
+
+    /// 10進数での各桁の和を計算する
+    fn digit_sum(self) -> u64;
+
+    /// 10進数での各桁の積を計算する
+    fn digit_product(self) -> u64;
+
+    /// 10進数での桁数を返す
+    fn digits_len(self) -> u32;
+
+    /// 数値の並びを反転させる (10進数, 先頭の桁は 0 でないので結果も 0 にならない)
+    fn reverse(self) -> Self;
+
+    /// 回文数かどうか判定する (10進数)
+    fn is_palindrome(self) -> bool;
+
+    /// 上からi番目の桁を取得する (10進数, 0-indexed)
+    fn nth_digit(self, i: u32) -> Option<u8>;
+
+    /// 特定の数字が含まれているか判定する (10進数)
+    fn contains_digit(self, digit: u8) -> bool;
+
+    /// 特定の数字の出現回数を数える (10進数)
+    fn count_digit(self, digit: u8) -> u32;
+
+    /// 桁を並び替えてできる「最大の数値」を返す (10進数)
+    fn make_max(self) -> Self;
+
+    /// 桁を並び替えてできる「最小の数値」を返す (10進数)
+    fn make_min(self) -> Self;
+
+    // ============================================================
+    // n進数対応
+    // ============================================================
+
+    /// n進数で各桁の数字(u8)のベクタに分解する
+    #[cfg(feature = "alloc")]
+    fn digits_radix(self, base: u32) -> Vec<u8>;
+
+    /// n進数の数字列から数値を復元する (0 になる場合は `None`)
+    fn from_digits_radix(digits: &[u8], base: u32) -> Option<Self>;
+
+    /// n進数での各桁の和を計算する
+    fn digit_sum_radix(self, base: u32) -> u64;
+
+    /// n進数での各桁の積を計算する
+    fn digit_product_radix(self, base: u32) -> u64;
+
+    /// n進数での桁数を返す
+    fn digits_len_radix(self, base: u32) -> u32;
+
+    /// 数値の並びを反転させる (n進数)
+    fn reverse_radix(self, base: u32) -> Self;
+
+    /// 回文数かどうか判定する (n進数)
+    fn is_palindrome_radix(self, base: u32) -> bool;
+
+    /// 上からi番目の桁を取得する (n進数, 0-indexed)
+    fn nth_digit_radix(self, i: u32, base: u32) -> Option<u8>;
+
+    /// 特定の数字が含まれているか判定する (n進数)
+    fn contains_digit_radix(self, digit: u8, base: u32) -> bool;
+
+    /// 特定の数字の出現回数を数える (n進数)
+    fn count_digit_radix(self, digit: u8, base: u32) -> u32;
+
+    /// 桁を並び替えてできる「最大の数値」を返す (n進数)
+    fn make_max_radix(self, base: u32) -> Self;
+
+    /// 桁を並び替えてできる「最小の数値」を返す (n進数)
+    fn make_min_radix(self, base: u32) -> Self;
+}
+
+// ----------------------------------------------------------------
+// 実装用マクロ (中の整数型に委譲する)
+// ----------------------------------------------------------------
+macro_rules! impl_keta_non_zero {
+    ($($nz:ty => $t:ty),*) => {
+        $(
+            impl KetaNonZero for $nz {
+                // --- 10-base Shortcuts ---
+
+                #[cfg(feature = "alloc")]
+                fn digits(self) -> Vec<u8> {
+                    self.get().digits()
+                }
+
+                fn from_digits(digits: &[u8]) -> Option<Self> {
+                    Self::new(<$t>::from_digits(digits))
+                }
+
+                fn digit_sum(self) -> u64 {
+                    self.get().digit_sum()
+                }
+
+                fn digit_product(self) -> u64 {
+                    self.get().digit_product()
+                }
+
+                fn digits_len(self) -> u32 {
+                    self.get().digits_len()
+                }
+
+                fn reverse(self) -> Self {
+                    self.reverse_radix(10)
+                }
+
+                fn is_palindrome(self) -> bool {
+                    self.get().is_palindrome()
+                }
+
+                fn nth_digit(self, i: u32) -> Option<u8> {
+                    self.get().nth_digit(i)
+                }
+
+                fn contains_digit(self, digit: u8) -> bool {
+                    self.get().contains_digit(digit)
+                }
+
+                fn count_digit(self, digit: u8) -> u32 {
+                    self.get().count_digit(digit)
+                }
+
+                fn make_max(self) -> Self {
+                    self.make_max_radix(10)
+                }
+
+                fn make_min(self) -> Self {
+                    self.make_min_radix(10)
+                }
+
+                // --- Radix Implementations ---
+
+                #[cfg(feature = "alloc")]
+                fn digits_radix(self, base: u32) -> Vec<u8> {
+                    self.get().digits_radix(base)
+                }
+
+                fn from_digits_radix(digits: &[u8], base: u32) -> Option<Self> {
+                    Self::new(<$t>::from_digits_radix(digits, base))
+                }
+
+                fn digit_sum_radix(self, base: u32) -> u64 {
+                    self.get().digit_sum_radix(base)
+                }
+
+                fn digit_product_radix(self, base: u32) -> u64 {
+                    self.get().digit_product_radix(base)
+                }
+
+                fn digits_len_radix(self, base: u32) -> u32 {
+                    self.get().digits_len_radix(base)
+                }
+
+                // 以下の3つは 0 でない桁が必ず残るので、結果も 0 にならない
+                fn reverse_radix(self, base: u32) -> Self {
+                    Self::new(self.get().reverse_radix(base)).unwrap()
+                }
+
+                fn is_palindrome_radix(self, base: u32) -> bool {
+                    self.get().is_palindrome_radix(base)
+                }
+
+                fn nth_digit_radix(self, i: u32, base: u32) -> Option<u8> {
+                    self.get().nth_digit_radix(i, base)
+                }
+
+                fn contains_digit_radix(self, digit: u8, base: u32) -> bool {
+                    self.get().contains_digit_radix(digit, base)
+                }
+
+                fn count_digit_radix(self, digit: u8, base: u32) -> u32 {
+                    self.get().count_digit_radix(digit, base)
+                }
+
+                fn make_max_radix(self, base: u32) -> Self {
+                    Self::new(self.get().make_max_radix(base)).unwrap()
+                }
+
+                fn make_min_radix(self, base: u32) -> Self {
+                    Self::new(self.get().make_min_radix(base)).unwrap()
+                }
+            }
+        )*
+    };
+}
+
+impl_keta_non_zero!(
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32, NonZeroU64 => u64,
+    NonZeroU128 => u128, NonZeroUsize => usize,
+    NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZeroI64 => i64,
+    NonZeroI128 => i128, NonZeroIsize => isize
+);
@@ -0,0 +1,1250 @@
+//! `Keta` のメソッドを自由関数として呼ぶためのモジュール
+//!
+//! 各関数はトレイトのメソッドに委譲するだけの薄いラッパーで、`use keta::Keta` なしで
+//! `keta::ops::digit_sum(x)` のように呼べる。関数ポインタとして `map` などにも渡せる。
+//! 値を受け取らない関数 (`from_digits` など) は型を `::<T>` で指定する。
+//!
+//! # Example
+//! ```
+//! let sums: Vec<u64> = [19u32, 1234, 99].into_iter().map(keta::ops::digit_sum).collect();
+//! assert_eq!(sums, vec![10, 10, 18]);
+//! assert_eq!(keta::ops::reverse(1230u32), 321);
+//! assert_eq!(keta::ops::from_digits::<u8>(&[4, 2]), 42);
+//! ```
+
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "alloc")]
+use crate::DigitView;
+use crate::{DigitError, Keta, KetaUnsigned, ParseKetaError};
+
+// ============================================================
+// 10進数ショートカット
+// ============================================================
+
+#[cfg(feature = "alloc")]
+/// [`Keta::digits`] の自由関数版
+pub fn digits<T: Keta>(n: T) -> Vec<u8> {
+    n.digits()
+}
+
+/// [`Keta::from_digits`] の自由関数版
+pub fn from_digits<T: Keta>(digits: &[u8]) -> T {
+    T::from_digits(digits)
+}
+
+/// [`Keta::digit_sum`] の自由関数版
+pub fn digit_sum<T: Keta>(n: T) -> u64 {
+    n.digit_sum()
+}
+
+/// [`Keta::digit_product`] の自由関数版
+pub fn digit_product<T: Keta>(n: T) -> u64 {
+    n.digit_product()
+}
+
+/// [`Keta::digits_len`] の自由関数版
+pub fn digits_len<T: Keta>(n: T) -> u32 {
+    n.digits_len()
+}
+
+/// [`Keta::reverse`] の自由関数版
+pub fn reverse<T: Keta>(n: T) -> T {
+    n.reverse()
+}
+
+/// [`Keta::is_palindrome`] の自由関数版
+pub fn is_palindrome<T: Keta>(n: T) -> bool {
+    n.is_palindrome()
+}
+
+/// [`Keta::nth_digit`] の自由関数版
+pub fn nth_digit<T: Keta>(n: T, i: u32) -> Option<u8> {
+    n.nth_digit(i)
+}
+
+/// [`Keta::concat`] の自由関数版
+pub fn concat<T: Keta>(n: T, other: T) -> T {
+    n.concat(other)
+}
+
+/// [`Keta::contains_digit`] の自由関数版
+pub fn contains_digit<T: Keta>(n: T, digit: u8) -> bool {
+    n.contains_digit(digit)
+}
+
+/// [`Keta::make_max`] の自由関数版
+pub fn make_max<T: Keta>(n: T) -> T {
+    n.make_max()
+}
+
+/// [`Keta::make_min`] の自由関数版
+pub fn make_min<T: Keta>(n: T) -> T {
+    n.make_min()
+}
+
+/// [`Keta::signed_digit_sum_pattern`] の自由関数版
+pub fn signed_digit_sum_pattern<T: Keta>(n: T, signs: &[i8]) -> i64 {
+    n.signed_digit_sum_pattern(signs)
+}
+
+/// [`Keta::count_digit`] の自由関数版
+pub fn count_digit<T: Keta>(n: T, digit: u8) -> u32 {
+    n.count_digit(digit)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::to_phone_letters`] の自由関数版
+pub fn to_phone_letters<T: Keta>(n: T) -> Vec<&'static str> {
+    n.to_phone_letters()
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::phone_letter_combinations`] の自由関数版
+pub fn phone_letter_combinations<T: Keta>(n: T) -> impl Iterator<Item = String> {
+    n.phone_letter_combinations()
+}
+
+/// [`Keta::digit_counts`] の自由関数版
+pub fn digit_counts<T: Keta>(n: T) -> [u32; 10] {
+    n.digit_counts()
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::digit_positions`] の自由関数版
+pub fn digit_positions<T: Keta>(n: T, digit: u8) -> Vec<u32> {
+    n.digit_positions(digit)
+}
+
+/// [`Keta::is_pandigital`] の自由関数版
+pub fn is_pandigital<T: Keta>(n: T) -> bool {
+    n.is_pandigital()
+}
+
+/// [`Keta::is_pandigital_1_to_n`] の自由関数版
+pub fn is_pandigital_1_to_n<T: Keta>(n: T) -> bool {
+    n.is_pandigital_1_to_n()
+}
+
+/// [`Keta::rotate_digits_left`] の自由関数版
+pub fn rotate_digits_left<T: Keta>(n: T, k: u32) -> T {
+    n.rotate_digits_left(k)
+}
+
+/// [`Keta::rotate_digits_right`] の自由関数版
+pub fn rotate_digits_right<T: Keta>(n: T, k: u32) -> T {
+    n.rotate_digits_right(k)
+}
+
+/// [`Keta::checked_rotate_digits_left`] の自由関数版
+pub fn checked_rotate_digits_left<T: Keta>(n: T, k: u32) -> Option<T> {
+    n.checked_rotate_digits_left(k)
+}
+
+/// [`Keta::checked_rotate_digits_right`] の自由関数版
+pub fn checked_rotate_digits_right<T: Keta>(n: T, k: u32) -> Option<T> {
+    n.checked_rotate_digits_right(k)
+}
+
+/// [`Keta::digit_square_sum`] の自由関数版
+pub fn digit_square_sum<T: Keta>(n: T) -> u64 {
+    n.digit_square_sum()
+}
+
+/// [`Keta::is_happy`] の自由関数版
+pub fn is_happy<T: Keta>(n: T) -> bool {
+    n.is_happy()
+}
+
+/// [`Keta::is_armstrong`] の自由関数版
+pub fn is_armstrong<T: Keta>(n: T) -> bool {
+    n.is_armstrong()
+}
+
+/// [`Keta::is_harshad`] の自由関数版
+pub fn is_harshad<T: Keta>(n: T) -> bool {
+    n.is_harshad()
+}
+
+/// [`Keta::try_from_digits`] の自由関数版
+pub fn try_from_digits<T: Keta>(digits: &[u8]) -> Option<T> {
+    T::try_from_digits(digits)
+}
+
+/// [`Keta::additive_persistence`] の自由関数版
+pub fn additive_persistence<T: Keta>(n: T) -> u32 {
+    n.additive_persistence()
+}
+
+/// [`Keta::multiplicative_persistence`] の自由関数版
+pub fn multiplicative_persistence<T: Keta>(n: T) -> u32 {
+    n.multiplicative_persistence()
+}
+
+/// [`Keta::digit_slice`] の自由関数版
+pub fn digit_slice<T: Keta>(n: T, start: u32, len: u32) -> T {
+    n.digit_slice(start, len)
+}
+
+/// [`Keta::with_digit`] の自由関数版
+pub fn with_digit<T: Keta>(n: T, i: u32, digit: u8) -> T {
+    n.with_digit(i, digit)
+}
+
+/// [`Keta::checked_with_digit`] の自由関数版
+pub fn checked_with_digit<T: Keta>(n: T, i: u32, digit: u8) -> Option<T> {
+    n.checked_with_digit(i, digit)
+}
+
+/// [`Keta::luhn_is_valid`] の自由関数版
+pub fn luhn_is_valid<T: Keta>(n: T) -> bool {
+    n.luhn_is_valid()
+}
+
+/// [`Keta::luhn_check_digit`] の自由関数版
+pub fn luhn_check_digit<T: Keta>(n: T) -> u8 {
+    n.luhn_check_digit()
+}
+
+/// [`Keta::is_automorphic`] の自由関数版
+pub fn is_automorphic<T: Keta>(n: T) -> bool {
+    n.is_automorphic()
+}
+
+/// [`Keta::nth_digit_from_right`] の自由関数版
+pub fn nth_digit_from_right<T: Keta>(n: T, i: u32) -> Option<u8> {
+    n.nth_digit_from_right(i)
+}
+
+/// [`Keta::max_digit`] の自由関数版
+pub fn max_digit<T: Keta>(n: T) -> u8 {
+    n.max_digit()
+}
+
+/// [`Keta::min_digit`] の自由関数版
+pub fn min_digit<T: Keta>(n: T) -> u8 {
+    n.min_digit()
+}
+
+/// [`Keta::distinct_digit_count`] の自由関数版
+pub fn distinct_digit_count<T: Keta>(n: T) -> u32 {
+    n.distinct_digit_count()
+}
+
+/// [`Keta::is_repdigit`] の自由関数版
+pub fn is_repdigit<T: Keta>(n: T) -> bool {
+    n.is_repdigit()
+}
+
+/// [`Keta::is_repunit`] の自由関数版
+pub fn is_repunit<T: Keta>(n: T) -> bool {
+    n.is_repunit()
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::next_palindrome`] の自由関数版
+pub fn next_palindrome<T: Keta>(n: T) -> T {
+    n.next_palindrome()
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::checked_next_palindrome`] の自由関数版
+pub fn checked_next_palindrome<T: Keta>(n: T) -> Option<T> {
+    n.checked_next_palindrome()
+}
+
+/// [`Keta::digit_power_sum`] の自由関数版
+pub fn digit_power_sum<T: Keta>(n: T, exp: u32) -> u128 {
+    n.digit_power_sum(exp)
+}
+
+/// [`Keta::checked_reverse`] の自由関数版
+pub fn checked_reverse<T: Keta>(n: T) -> Option<T> {
+    n.checked_reverse()
+}
+
+/// [`Keta::saturating_reverse`] の自由関数版
+pub fn saturating_reverse<T: Keta>(n: T) -> T {
+    n.saturating_reverse()
+}
+
+/// [`Keta::wrapping_reverse`] の自由関数版
+pub fn wrapping_reverse<T: Keta>(n: T) -> T {
+    n.wrapping_reverse()
+}
+
+/// [`Keta::checked_digit_product`] の自由関数版
+pub fn checked_digit_product<T: Keta>(n: T) -> Option<u64> {
+    n.checked_digit_product()
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::digits_padded`] の自由関数版
+pub fn digits_padded<T: Keta>(n: T, width: usize) -> Vec<u8> {
+    n.digits_padded(width)
+}
+
+/// [`Keta::interleave`] の自由関数版
+pub fn interleave<T: Keta>(n: T, other: T) -> T {
+    n.interleave(other)
+}
+
+/// [`Keta::checked_interleave`] の自由関数版
+pub fn checked_interleave<T: Keta>(n: T, other: T) -> Option<T> {
+    n.checked_interleave(other)
+}
+
+/// [`Keta::split_at`] の自由関数版
+pub fn split_at<T: Keta>(n: T, i: u32) -> (T, T) {
+    n.split_at(i)
+}
+
+/// [`Keta::remove_digit`] の自由関数版
+pub fn remove_digit<T: Keta>(n: T, digit: u8) -> T {
+    n.remove_digit(digit)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::to_balanced_ternary`] の自由関数版
+pub fn to_balanced_ternary<T: Keta>(n: T) -> Vec<i8> {
+    n.to_balanced_ternary()
+}
+
+/// [`Keta::from_balanced_ternary`] の自由関数版
+pub fn from_balanced_ternary<T: Keta>(digits: &[i8]) -> T {
+    T::from_balanced_ternary(digits)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::digits_negative_base`] の自由関数版
+pub fn digits_negative_base<T: Keta>(n: T, base: i32) -> Vec<u8> {
+    n.digits_negative_base(base)
+}
+
+/// [`Keta::from_digits_negative_base`] の自由関数版
+pub fn from_digits_negative_base<T: Keta>(digits: &[u8], base: i32) -> T {
+    T::from_digits_negative_base(digits, base)
+}
+
+/// [`Keta::kaprekar_step`] の自由関数版
+pub fn kaprekar_step<T: Keta>(n: T) -> T {
+    n.kaprekar_step()
+}
+
+/// [`Keta::kaprekar_iterations`] の自由関数版
+pub fn kaprekar_iterations<T: Keta>(n: T) -> Option<u32> {
+    n.kaprekar_iterations()
+}
+
+/// [`Keta::digit_cmp`] の自由関数版
+pub fn digit_cmp<T: Keta>(n: T, other: T) -> Ordering {
+    n.digit_cmp(other)
+}
+
+/// [`Keta::cmp_by_digit_sum`] の自由関数版
+pub fn cmp_by_digit_sum<T: Keta>(n: T, other: T) -> Ordering {
+    n.cmp_by_digit_sum(other)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::group_digits`] の自由関数版
+pub fn group_digits<T: Keta>(n: T, sep: char, group: usize) -> String {
+    n.group_digits(sep, group)
+}
+
+/// [`Keta::sum_of_digit_sums`] の自由関数版
+pub fn sum_of_digit_sums<T: Keta, I: IntoIterator<Item = T>>(iter: I) -> u64 {
+    T::sum_of_digit_sums(iter)
+}
+
+/// [`Keta::is_munchausen`] の自由関数版
+pub fn is_munchausen<T: Keta>(n: T) -> bool {
+    n.is_munchausen()
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::sort_digits`] の自由関数版
+pub fn sort_digits<T: Keta>(n: T, ascending: bool) -> Vec<u8> {
+    n.sort_digits(ascending)
+}
+
+/// [`Keta::digit_sum_mod`] の自由関数版
+pub fn digit_sum_mod<T: Keta>(n: T, m: u64) -> u64 {
+    n.digit_sum_mod(m)
+}
+
+/// [`Keta::casting_out_nines`] の自由関数版
+pub fn casting_out_nines<T: Keta>(n: T) -> u8 {
+    n.casting_out_nines()
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::signed_digits`] の自由関数版
+pub fn signed_digits<T: Keta>(n: T) -> (bool, Vec<u8>) {
+    n.signed_digits()
+}
+
+/// [`Keta::next_with_digit_sum`] の自由関数版
+pub fn next_with_digit_sum<T: Keta>(n: T) -> T {
+    n.next_with_digit_sum()
+}
+
+/// [`Keta::checked_next_with_digit_sum`] の自由関数版
+pub fn checked_next_with_digit_sum<T: Keta>(n: T) -> Option<T> {
+    n.checked_next_with_digit_sum()
+}
+
+/// [`Keta::is_smith`] の自由関数版
+pub fn is_smith<T: Keta>(n: T) -> bool {
+    n.is_smith()
+}
+
+/// [`Keta::reverse_add_steps`] の自由関数版
+pub fn reverse_add_steps<T: Keta>(n: T, max_iters: u32) -> Option<u32> {
+    n.reverse_add_steps(max_iters)
+}
+
+/// [`Keta::popcount`] の自由関数版
+pub fn popcount<T: Keta>(n: T) -> u32 {
+    n.popcount()
+}
+
+/// [`Keta::bit_len`] の自由関数版
+pub fn bit_len<T: Keta>(n: T) -> u32 {
+    n.bit_len()
+}
+
+/// [`Keta::weighted_digit_sum`] の自由関数版
+pub fn weighted_digit_sum<T: Keta, F: FnMut(u32, u8) -> i64>(n: T, f: F) -> i64 {
+    n.weighted_digit_sum(f)
+}
+
+/// [`Keta::checked_concat`] の自由関数版
+pub fn checked_concat<T: Keta>(n: T, other: T) -> Option<T> {
+    n.checked_concat(other)
+}
+
+/// [`Keta::has_increasing_digits`] の自由関数版
+pub fn has_increasing_digits<T: Keta>(n: T) -> bool {
+    n.has_increasing_digits()
+}
+
+/// [`Keta::has_decreasing_digits`] の自由関数版
+pub fn has_decreasing_digits<T: Keta>(n: T) -> bool {
+    n.has_decreasing_digits()
+}
+
+/// [`Keta::is_stepping`] の自由関数版
+pub fn is_stepping<T: Keta>(n: T) -> bool {
+    n.is_stepping()
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::is_palindrome_padded`] の自由関数版
+pub fn is_palindrome_padded<T: Keta>(n: T, width: usize) -> bool {
+    n.is_palindrome_padded(width)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::digit_prefix_sums`] の自由関数版
+pub fn digit_prefix_sums<T: Keta>(n: T) -> Vec<u64> {
+    n.digit_prefix_sums()
+}
+
+/// [`Keta::checked_make_max`] の自由関数版
+pub fn checked_make_max<T: Keta>(n: T) -> Option<T> {
+    n.checked_make_max()
+}
+
+/// [`Keta::checked_make_min`] の自由関数版
+pub fn checked_make_min<T: Keta>(n: T) -> Option<T> {
+    n.checked_make_min()
+}
+
+/// [`Keta::count_with_digit_sum`] の自由関数版
+pub fn count_with_digit_sum<T: Keta>(limit: T, target: u64) -> u64 {
+    T::count_with_digit_sum(limit, target)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::to_bcd`] の自由関数版
+pub fn to_bcd<T: Keta>(n: T) -> Vec<u8> {
+    n.to_bcd()
+}
+
+/// [`Keta::from_bcd`] の自由関数版
+pub fn from_bcd<T: Keta>(bytes: &[u8]) -> T {
+    T::from_bcd(bytes)
+}
+
+/// [`Keta::fold_digits`] の自由関数版
+pub fn fold_digits<T: Keta, B, F: FnMut(B, u8) -> B>(n: T, init: B, f: F) -> B {
+    n.fold_digits(init, f)
+}
+
+/// [`Keta::as_single_digit`] の自由関数版
+pub fn as_single_digit<T: Keta>(n: T) -> Option<u8> {
+    n.as_single_digit()
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::factorial_digit_sum`] の自由関数版
+pub fn factorial_digit_sum<T: Keta>(k: u32) -> u64 {
+    T::factorial_digit_sum(k)
+}
+
+/// [`Keta::is_bouncy`] の自由関数版
+pub fn is_bouncy<T: Keta>(n: T) -> bool {
+    n.is_bouncy()
+}
+
+/// [`Keta::smallest_arrangement_fixed`] の自由関数版
+pub fn smallest_arrangement_fixed<T: Keta>(n: T) -> T {
+    n.smallest_arrangement_fixed()
+}
+
+/// [`Keta::checked_smallest_arrangement_fixed`] の自由関数版
+pub fn checked_smallest_arrangement_fixed<T: Keta>(n: T) -> Option<T> {
+    n.checked_smallest_arrangement_fixed()
+}
+
+/// [`Keta::largest_arrangement_fixed`] の自由関数版
+pub fn largest_arrangement_fixed<T: Keta>(n: T) -> T {
+    n.largest_arrangement_fixed()
+}
+
+/// [`Keta::checksum_mod`] の自由関数版
+pub fn checksum_mod<T: Keta>(n: T, weights: &[i64], modulus: i64) -> i64 {
+    n.checksum_mod(weights, modulus)
+}
+
+/// [`Keta::is_undulating`] の自由関数版
+pub fn is_undulating<T: Keta>(n: T) -> bool {
+    n.is_undulating()
+}
+
+/// [`Keta::wrapping_concat`] の自由関数版
+pub fn wrapping_concat<T: Keta>(n: T, other: T) -> T {
+    n.wrapping_concat(other)
+}
+
+/// [`Keta::wrapping_make_max`] の自由関数版
+pub fn wrapping_make_max<T: Keta>(n: T) -> T {
+    n.wrapping_make_max()
+}
+
+/// [`Keta::trailing_zeros_10`] の自由関数版
+pub fn trailing_zeros_10<T: Keta>(n: T) -> u32 {
+    n.trailing_zeros_10()
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::reverse_digits`] の自由関数版
+pub fn reverse_digits<T: Keta>(n: T) -> (Vec<u8>, bool) {
+    n.reverse_digits()
+}
+
+/// [`Keta::is_pdi`] の自由関数版
+pub fn is_pdi<T: Keta>(n: T, power: u32) -> bool {
+    n.is_pdi(power)
+}
+
+/// [`Keta::most_common_digit`] の自由関数版
+pub fn most_common_digit<T: Keta>(n: T) -> u8 {
+    n.most_common_digit()
+}
+
+/// [`Keta::least_common_digit`] の自由関数版
+pub fn least_common_digit<T: Keta>(n: T) -> u8 {
+    n.least_common_digit()
+}
+
+/// [`Keta::digit_sum_trajectory`] の自由関数版
+pub fn digit_sum_trajectory<T: Keta>(n: T) -> impl Iterator<Item = u64> {
+    n.digit_sum_trajectory()
+}
+
+/// [`Keta::digit_product_trajectory`] の自由関数版
+pub fn digit_product_trajectory<T: Keta>(n: T) -> impl Iterator<Item = u64> {
+    n.digit_product_trajectory()
+}
+
+/// [`Keta::is_digit_reverse_of`] の自由関数版
+pub fn is_digit_reverse_of<T: Keta>(n: T, other: T) -> bool {
+    n.is_digit_reverse_of(other)
+}
+
+/// [`Keta::is_digit_reverse_of_strict`] の自由関数版
+pub fn is_digit_reverse_of_strict<T: Keta>(n: T, other: T) -> bool {
+    n.is_digit_reverse_of_strict(other)
+}
+
+/// [`Keta::is_emirp`] の自由関数版
+pub fn is_emirp<T: Keta>(n: T) -> bool {
+    n.is_emirp()
+}
+
+/// [`Keta::is_vampire`] の自由関数版
+pub fn is_vampire<T: Keta>(n: T) -> bool {
+    n.is_vampire()
+}
+
+/// [`Keta::vampire_fangs`] の自由関数版
+pub fn vampire_fangs<T: Keta>(n: T) -> Option<(T, T)> {
+    n.vampire_fangs()
+}
+
+/// [`Keta::concat_many_10`] の自由関数版
+pub fn concat_many_10<T: Keta>(parts: &[T]) -> T {
+    T::concat_many_10(parts)
+}
+
+/// [`Keta::checked_concat_many_10`] の自由関数版
+pub fn checked_concat_many_10<T: Keta>(parts: &[T]) -> Option<T> {
+    T::checked_concat_many_10(parts)
+}
+
+/// [`Keta::map_digits`] の自由関数版
+pub fn map_digits<T: Keta, F: Fn(u8) -> u8>(n: T, f: F) -> T {
+    n.map_digits(f)
+}
+
+/// [`Keta::checked_map_digits`] の自由関数版
+pub fn checked_map_digits<T: Keta, F: Fn(u8) -> u8>(n: T, f: F) -> Option<T> {
+    n.checked_map_digits(f)
+}
+
+/// [`Keta::min_rotation`] の自由関数版
+pub fn min_rotation<T: Keta>(n: T) -> T {
+    n.min_rotation()
+}
+
+/// [`Keta::max_rotation`] の自由関数版
+pub fn max_rotation<T: Keta>(n: T) -> T {
+    n.max_rotation()
+}
+
+/// [`Keta::digit_signature`] の自由関数版
+pub fn digit_signature<T: Keta>(n: T) -> u64 {
+    n.digit_signature()
+}
+
+/// [`Keta::digits_iter`] の自由関数版
+pub fn digits_iter<T: Keta>(n: T) -> impl Iterator<Item = u8> {
+    n.digits_iter()
+}
+
+// ============================================================
+// n進数対応
+// ============================================================
+
+#[cfg(feature = "alloc")]
+/// [`Keta::digits_radix`] の自由関数版
+pub fn digits_radix<T: Keta>(n: T, base: u32) -> Vec<u8> {
+    n.digits_radix(base)
+}
+
+/// [`Keta::from_digits_radix`] の自由関数版
+pub fn from_digits_radix<T: Keta>(digits: &[u8], base: u32) -> T {
+    T::from_digits_radix(digits, base)
+}
+
+/// [`Keta::digit_sum_radix`] の自由関数版
+pub fn digit_sum_radix<T: Keta>(n: T, base: u32) -> u64 {
+    n.digit_sum_radix(base)
+}
+
+/// [`Keta::digit_product_radix`] の自由関数版
+pub fn digit_product_radix<T: Keta>(n: T, base: u32) -> u64 {
+    n.digit_product_radix(base)
+}
+
+/// [`Keta::digits_len_radix`] の自由関数版
+pub fn digits_len_radix<T: Keta>(n: T, base: u32) -> u32 {
+    n.digits_len_radix(base)
+}
+
+/// [`Keta::reverse_radix`] の自由関数版
+pub fn reverse_radix<T: Keta>(n: T, base: u32) -> T {
+    n.reverse_radix(base)
+}
+
+/// [`Keta::is_palindrome_radix`] の自由関数版
+pub fn is_palindrome_radix<T: Keta>(n: T, base: u32) -> bool {
+    n.is_palindrome_radix(base)
+}
+
+/// [`Keta::nth_digit_radix`] の自由関数版
+pub fn nth_digit_radix<T: Keta>(n: T, i: u32, base: u32) -> Option<u8> {
+    n.nth_digit_radix(i, base)
+}
+
+/// [`Keta::concat_radix`] の自由関数版
+pub fn concat_radix<T: Keta>(n: T, other: T, base: u32) -> T {
+    n.concat_radix(other, base)
+}
+
+/// [`Keta::contains_digit_radix`] の自由関数版
+pub fn contains_digit_radix<T: Keta>(n: T, digit: u8, base: u32) -> bool {
+    n.contains_digit_radix(digit, base)
+}
+
+/// [`Keta::make_max_radix`] の自由関数版
+pub fn make_max_radix<T: Keta>(n: T, base: u32) -> T {
+    n.make_max_radix(base)
+}
+
+/// [`Keta::make_min_radix`] の自由関数版
+pub fn make_min_radix<T: Keta>(n: T, base: u32) -> T {
+    n.make_min_radix(base)
+}
+
+/// [`Keta::count_digit_radix`] の自由関数版
+pub fn count_digit_radix<T: Keta>(n: T, digit: u8, base: u32) -> u32 {
+    n.count_digit_radix(digit, base)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::digit_counts_radix`] の自由関数版
+pub fn digit_counts_radix<T: Keta>(n: T, base: u32) -> Vec<u32> {
+    n.digit_counts_radix(base)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::digit_positions_radix`] の自由関数版
+pub fn digit_positions_radix<T: Keta>(n: T, digit: u8, base: u32) -> Vec<u32> {
+    n.digit_positions_radix(digit, base)
+}
+
+/// [`Keta::is_pandigital_radix`] の自由関数版
+pub fn is_pandigital_radix<T: Keta>(n: T, base: u32) -> bool {
+    n.is_pandigital_radix(base)
+}
+
+/// [`Keta::rotate_digits_left_radix`] の自由関数版
+pub fn rotate_digits_left_radix<T: Keta>(n: T, k: u32, base: u32) -> T {
+    n.rotate_digits_left_radix(k, base)
+}
+
+/// [`Keta::rotate_digits_right_radix`] の自由関数版
+pub fn rotate_digits_right_radix<T: Keta>(n: T, k: u32, base: u32) -> T {
+    n.rotate_digits_right_radix(k, base)
+}
+
+/// [`Keta::checked_rotate_digits_left_radix`] の自由関数版
+pub fn checked_rotate_digits_left_radix<T: Keta>(n: T, k: u32, base: u32) -> Option<T> {
+    n.checked_rotate_digits_left_radix(k, base)
+}
+
+/// [`Keta::checked_rotate_digits_right_radix`] の自由関数版
+pub fn checked_rotate_digits_right_radix<T: Keta>(n: T, k: u32, base: u32) -> Option<T> {
+    n.checked_rotate_digits_right_radix(k, base)
+}
+
+/// [`Keta::digit_square_sum_radix`] の自由関数版
+pub fn digit_square_sum_radix<T: Keta>(n: T, base: u32) -> u64 {
+    n.digit_square_sum_radix(base)
+}
+
+/// [`Keta::is_happy_radix`] の自由関数版
+pub fn is_happy_radix<T: Keta>(n: T, base: u32) -> bool {
+    n.is_happy_radix(base)
+}
+
+/// [`Keta::is_armstrong_radix`] の自由関数版
+pub fn is_armstrong_radix<T: Keta>(n: T, base: u32) -> bool {
+    n.is_armstrong_radix(base)
+}
+
+/// [`Keta::is_harshad_radix`] の自由関数版
+pub fn is_harshad_radix<T: Keta>(n: T, base: u32) -> bool {
+    n.is_harshad_radix(base)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::to_radix_string`] の自由関数版
+pub fn to_radix_string<T: Keta>(n: T, base: u32) -> String {
+    n.to_radix_string(base)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::to_radix_string_upper`] の自由関数版
+pub fn to_radix_string_upper<T: Keta>(n: T, base: u32) -> String {
+    n.to_radix_string_upper(base)
+}
+
+/// [`Keta::from_radix_string`] の自由関数版
+pub fn from_radix_string<T: Keta>(s: &str, base: u32) -> Result<T, ParseKetaError> {
+    T::from_radix_string(s, base)
+}
+
+/// [`Keta::try_from_digits_radix`] の自由関数版
+pub fn try_from_digits_radix<T: Keta>(digits: &[u8], base: u32) -> Option<T> {
+    T::try_from_digits_radix(digits, base)
+}
+
+/// [`Keta::digit_slice_radix`] の自由関数版
+pub fn digit_slice_radix<T: Keta>(n: T, start: u32, len: u32, base: u32) -> T {
+    n.digit_slice_radix(start, len, base)
+}
+
+/// [`Keta::with_digit_radix`] の自由関数版
+pub fn with_digit_radix<T: Keta>(n: T, i: u32, digit: u8, base: u32) -> T {
+    n.with_digit_radix(i, digit, base)
+}
+
+/// [`Keta::checked_with_digit_radix`] の自由関数版
+pub fn checked_with_digit_radix<T: Keta>(n: T, i: u32, digit: u8, base: u32) -> Option<T> {
+    n.checked_with_digit_radix(i, digit, base)
+}
+
+/// [`Keta::nth_digit_from_right_radix`] の自由関数版
+pub fn nth_digit_from_right_radix<T: Keta>(n: T, i: u32, base: u32) -> Option<u8> {
+    n.nth_digit_from_right_radix(i, base)
+}
+
+/// [`Keta::max_digit_radix`] の自由関数版
+pub fn max_digit_radix<T: Keta>(n: T, base: u32) -> u8 {
+    n.max_digit_radix(base)
+}
+
+/// [`Keta::min_digit_radix`] の自由関数版
+pub fn min_digit_radix<T: Keta>(n: T, base: u32) -> u8 {
+    n.min_digit_radix(base)
+}
+
+/// [`Keta::distinct_digit_count_radix`] の自由関数版
+pub fn distinct_digit_count_radix<T: Keta>(n: T, base: u32) -> u32 {
+    n.distinct_digit_count_radix(base)
+}
+
+/// [`Keta::is_repdigit_radix`] の自由関数版
+pub fn is_repdigit_radix<T: Keta>(n: T, base: u32) -> bool {
+    n.is_repdigit_radix(base)
+}
+
+/// [`Keta::is_repunit_radix`] の自由関数版
+pub fn is_repunit_radix<T: Keta>(n: T, base: u32) -> bool {
+    n.is_repunit_radix(base)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::next_palindrome_radix`] の自由関数版
+pub fn next_palindrome_radix<T: Keta>(n: T, base: u32) -> T {
+    n.next_palindrome_radix(base)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::checked_next_palindrome_radix`] の自由関数版
+pub fn checked_next_palindrome_radix<T: Keta>(n: T, base: u32) -> Option<T> {
+    n.checked_next_palindrome_radix(base)
+}
+
+/// [`Keta::digit_power_sum_radix`] の自由関数版
+pub fn digit_power_sum_radix<T: Keta>(n: T, exp: u32, base: u32) -> u128 {
+    n.digit_power_sum_radix(exp, base)
+}
+
+/// [`Keta::checked_reverse_radix`] の自由関数版
+pub fn checked_reverse_radix<T: Keta>(n: T, base: u32) -> Option<T> {
+    n.checked_reverse_radix(base)
+}
+
+/// [`Keta::saturating_reverse_radix`] の自由関数版
+pub fn saturating_reverse_radix<T: Keta>(n: T, base: u32) -> T {
+    n.saturating_reverse_radix(base)
+}
+
+/// [`Keta::wrapping_reverse_radix`] の自由関数版
+pub fn wrapping_reverse_radix<T: Keta>(n: T, base: u32) -> T {
+    n.wrapping_reverse_radix(base)
+}
+
+/// [`Keta::checked_digit_product_radix`] の自由関数版
+pub fn checked_digit_product_radix<T: Keta>(n: T, base: u32) -> Option<u64> {
+    n.checked_digit_product_radix(base)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::digits_padded_radix`] の自由関数版
+pub fn digits_padded_radix<T: Keta>(n: T, width: usize, base: u32) -> Vec<u8> {
+    n.digits_padded_radix(width, base)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::to_radix_string_padded`] の自由関数版
+pub fn to_radix_string_padded<T: Keta>(n: T, width: usize, base: u32) -> String {
+    n.to_radix_string_padded(width, base)
+}
+
+/// [`Keta::interleave_radix`] の自由関数版
+pub fn interleave_radix<T: Keta>(n: T, other: T, base: u32) -> T {
+    n.interleave_radix(other, base)
+}
+
+/// [`Keta::checked_interleave_radix`] の自由関数版
+pub fn checked_interleave_radix<T: Keta>(n: T, other: T, base: u32) -> Option<T> {
+    n.checked_interleave_radix(other, base)
+}
+
+/// [`Keta::split_at_radix`] の自由関数版
+pub fn split_at_radix<T: Keta>(n: T, i: u32, base: u32) -> (T, T) {
+    n.split_at_radix(i, base)
+}
+
+/// [`Keta::remove_digit_radix`] の自由関数版
+pub fn remove_digit_radix<T: Keta>(n: T, digit: u8, base: u32) -> T {
+    n.remove_digit_radix(digit, base)
+}
+
+/// [`Keta::digit_cmp_radix`] の自由関数版
+pub fn digit_cmp_radix<T: Keta>(n: T, other: T, base: u32) -> Ordering {
+    n.digit_cmp_radix(other, base)
+}
+
+/// [`Keta::cmp_by_digit_sum_radix`] の自由関数版
+pub fn cmp_by_digit_sum_radix<T: Keta>(n: T, other: T, base: u32) -> Ordering {
+    n.cmp_by_digit_sum_radix(other, base)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::group_digits_radix`] の自由関数版
+pub fn group_digits_radix<T: Keta>(n: T, sep: char, group: usize, base: u32) -> String {
+    n.group_digits_radix(sep, group, base)
+}
+
+/// [`Keta::is_munchausen_radix`] の自由関数版
+pub fn is_munchausen_radix<T: Keta>(n: T, base: u32) -> bool {
+    n.is_munchausen_radix(base)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::sort_digits_radix`] の自由関数版
+pub fn sort_digits_radix<T: Keta>(n: T, ascending: bool, base: u32) -> Vec<u8> {
+    n.sort_digits_radix(ascending, base)
+}
+
+/// [`Keta::digit_sum_mod_radix`] の自由関数版
+pub fn digit_sum_mod_radix<T: Keta>(n: T, m: u64, base: u32) -> u64 {
+    n.digit_sum_mod_radix(m, base)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::signed_digits_radix`] の自由関数版
+pub fn signed_digits_radix<T: Keta>(n: T, base: u32) -> (bool, Vec<u8>) {
+    n.signed_digits_radix(base)
+}
+
+/// [`Keta::next_with_digit_sum_radix`] の自由関数版
+pub fn next_with_digit_sum_radix<T: Keta>(n: T, base: u32) -> T {
+    n.next_with_digit_sum_radix(base)
+}
+
+/// [`Keta::checked_next_with_digit_sum_radix`] の自由関数版
+pub fn checked_next_with_digit_sum_radix<T: Keta>(n: T, base: u32) -> Option<T> {
+    n.checked_next_with_digit_sum_radix(base)
+}
+
+/// [`Keta::weighted_digit_sum_radix`] の自由関数版
+pub fn weighted_digit_sum_radix<T: Keta, F: FnMut(u32, u8) -> i64>(n: T, f: F, base: u32) -> i64 {
+    n.weighted_digit_sum_radix(f, base)
+}
+
+/// [`Keta::try_concat_radix`] の自由関数版
+pub fn try_concat_radix<T: Keta>(n: T, other: T, base: u32) -> Result<T, DigitError> {
+    n.try_concat_radix(other, base)
+}
+
+/// [`Keta::checked_concat_radix`] の自由関数版
+pub fn checked_concat_radix<T: Keta>(n: T, other: T, base: u32) -> Option<T> {
+    n.checked_concat_radix(other, base)
+}
+
+/// [`Keta::has_increasing_digits_radix`] の自由関数版
+pub fn has_increasing_digits_radix<T: Keta>(n: T, base: u32) -> bool {
+    n.has_increasing_digits_radix(base)
+}
+
+/// [`Keta::has_decreasing_digits_radix`] の自由関数版
+pub fn has_decreasing_digits_radix<T: Keta>(n: T, base: u32) -> bool {
+    n.has_decreasing_digits_radix(base)
+}
+
+/// [`Keta::is_stepping_radix`] の自由関数版
+pub fn is_stepping_radix<T: Keta>(n: T, base: u32) -> bool {
+    n.is_stepping_radix(base)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::is_palindrome_padded_radix`] の自由関数版
+pub fn is_palindrome_padded_radix<T: Keta>(n: T, width: usize, base: u32) -> bool {
+    n.is_palindrome_padded_radix(width, base)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::digit_prefix_sums_radix`] の自由関数版
+pub fn digit_prefix_sums_radix<T: Keta>(n: T, base: u32) -> Vec<u64> {
+    n.digit_prefix_sums_radix(base)
+}
+
+/// [`Keta::checked_make_max_radix`] の自由関数版
+pub fn checked_make_max_radix<T: Keta>(n: T, base: u32) -> Option<T> {
+    n.checked_make_max_radix(base)
+}
+
+/// [`Keta::checked_make_min_radix`] の自由関数版
+pub fn checked_make_min_radix<T: Keta>(n: T, base: u32) -> Option<T> {
+    n.checked_make_min_radix(base)
+}
+
+/// [`Keta::fold_digits_radix`] の自由関数版
+pub fn fold_digits_radix<T: Keta, B, F: FnMut(B, u8) -> B>(n: T, init: B, f: F, base: u32) -> B {
+    n.fold_digits_radix(init, f, base)
+}
+
+/// [`Keta::as_single_digit_radix`] の自由関数版
+pub fn as_single_digit_radix<T: Keta>(n: T, base: u32) -> Option<u8> {
+    n.as_single_digit_radix(base)
+}
+
+/// [`Keta::is_bouncy_radix`] の自由関数版
+pub fn is_bouncy_radix<T: Keta>(n: T, base: u32) -> bool {
+    n.is_bouncy_radix(base)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::digit_view`] の自由関数版
+pub fn digit_view<T: Keta>(n: T, base: u32) -> DigitView {
+    n.digit_view(base)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::to_radix_string_with`] の自由関数版
+pub fn to_radix_string_with<T: Keta>(n: T, base: u32, alphabet: &[char]) -> String {
+    n.to_radix_string_with(base, alphabet)
+}
+
+/// [`Keta::from_radix_string_with`] の自由関数版
+pub fn from_radix_string_with<T: Keta>(
+    s: &str,
+    base: u32,
+    alphabet: &[char],
+) -> Result<T, ParseKetaError> {
+    T::from_radix_string_with(s, base, alphabet)
+}
+
+/// [`Keta::smallest_arrangement_fixed_radix`] の自由関数版
+pub fn smallest_arrangement_fixed_radix<T: Keta>(n: T, base: u32) -> T {
+    n.smallest_arrangement_fixed_radix(base)
+}
+
+/// [`Keta::checked_smallest_arrangement_fixed_radix`] の自由関数版
+pub fn checked_smallest_arrangement_fixed_radix<T: Keta>(n: T, base: u32) -> Option<T> {
+    n.checked_smallest_arrangement_fixed_radix(base)
+}
+
+/// [`Keta::largest_arrangement_fixed_radix`] の自由関数版
+pub fn largest_arrangement_fixed_radix<T: Keta>(n: T, base: u32) -> T {
+    n.largest_arrangement_fixed_radix(base)
+}
+
+/// [`Keta::checksum_mod_radix`] の自由関数版
+pub fn checksum_mod_radix<T: Keta>(n: T, weights: &[i64], modulus: i64, base: u32) -> i64 {
+    n.checksum_mod_radix(weights, modulus, base)
+}
+
+/// [`Keta::is_undulating_radix`] の自由関数版
+pub fn is_undulating_radix<T: Keta>(n: T, base: u32) -> bool {
+    n.is_undulating_radix(base)
+}
+
+/// [`Keta::wrapping_concat_radix`] の自由関数版
+pub fn wrapping_concat_radix<T: Keta>(n: T, other: T, base: u32) -> T {
+    n.wrapping_concat_radix(other, base)
+}
+
+/// [`Keta::wrapping_make_max_radix`] の自由関数版
+pub fn wrapping_make_max_radix<T: Keta>(n: T, base: u32) -> T {
+    n.wrapping_make_max_radix(base)
+}
+
+/// [`Keta::trailing_zeros_radix`] の自由関数版
+pub fn trailing_zeros_radix<T: Keta>(n: T, base: u32) -> u32 {
+    n.trailing_zeros_radix(base)
+}
+
+/// [`Keta::leading_zeros_radix`] の自由関数版
+pub fn leading_zeros_radix<T: Keta>(n: T, width: u32, base: u32) -> u32 {
+    n.leading_zeros_radix(width, base)
+}
+
+#[cfg(feature = "alloc")]
+/// [`Keta::reverse_digits_radix`] の自由関数版
+pub fn reverse_digits_radix<T: Keta>(n: T, base: u32) -> (Vec<u8>, bool) {
+    n.reverse_digits_radix(base)
+}
+
+/// [`Keta::max_digits_radix`] の自由関数版
+pub fn max_digits_radix<T: Keta>(base: u32) -> u32 {
+    T::max_digits_radix(base)
+}
+
+/// [`Keta::is_pdi_radix`] の自由関数版
+pub fn is_pdi_radix<T: Keta>(n: T, power: u32, base: u32) -> bool {
+    n.is_pdi_radix(power, base)
+}
+
+/// [`Keta::add_with_carry`] の自由関数版
+pub fn add_with_carry<T: Keta>(n: T, other: T, width: u32, base: u32) -> Option<(T, bool)> {
+    n.add_with_carry(other, width, base)
+}
+
+/// [`Keta::sub_with_borrow`] の自由関数版
+pub fn sub_with_borrow<T: Keta>(n: T, other: T, width: u32, base: u32) -> Option<(T, bool)> {
+    n.sub_with_borrow(other, width, base)
+}
+
+/// [`Keta::most_common_digit_radix`] の自由関数版
+pub fn most_common_digit_radix<T: Keta>(n: T, base: u32) -> u8 {
+    n.most_common_digit_radix(base)
+}
+
+/// [`Keta::least_common_digit_radix`] の自由関数版
+pub fn least_common_digit_radix<T: Keta>(n: T, base: u32) -> u8 {
+    n.least_common_digit_radix(base)
+}
+
+/// [`Keta::from_grouped_str`] の自由関数版
+pub fn from_grouped_str<T: Keta>(s: &str, base: u32) -> Result<T, ParseKetaError> {
+    T::from_grouped_str(s, base)
+}
+
+/// [`Keta::is_digit_reverse_of_radix`] の自由関数版
+pub fn is_digit_reverse_of_radix<T: Keta>(n: T, other: T, base: u32) -> bool {
+    n.is_digit_reverse_of_radix(other, base)
+}
+
+/// [`Keta::is_digit_reverse_of_strict_radix`] の自由関数版
+pub fn is_digit_reverse_of_strict_radix<T: Keta>(n: T, other: T, base: u32) -> bool {
+    n.is_digit_reverse_of_strict_radix(other, base)
+}
+
+/// [`Keta::concat_many`] の自由関数版
+pub fn concat_many<T: Keta>(parts: &[T], base: u32) -> T {
+    T::concat_many(parts, base)
+}
+
+/// [`Keta::checked_concat_many`] の自由関数版
+pub fn checked_concat_many<T: Keta>(parts: &[T], base: u32) -> Option<T> {
+    T::checked_concat_many(parts, base)
+}
+
+/// [`Keta::map_digits_radix`] の自由関数版
+pub fn map_digits_radix<T: Keta, F: Fn(u8) -> u8>(n: T, f: F, base: u32) -> T {
+    n.map_digits_radix(f, base)
+}
+
+/// [`Keta::checked_map_digits_radix`] の自由関数版
+pub fn checked_map_digits_radix<T: Keta, F: Fn(u8) -> u8>(n: T, f: F, base: u32) -> Option<T> {
+    n.checked_map_digits_radix(f, base)
+}
+
+/// [`Keta::min_rotation_radix`] の自由関数版
+pub fn min_rotation_radix<T: Keta>(n: T, base: u32) -> T {
+    n.min_rotation_radix(base)
+}
+
+/// [`Keta::max_rotation_radix`] の自由関数版
+pub fn max_rotation_radix<T: Keta>(n: T, base: u32) -> T {
+    n.max_rotation_radix(base)
+}
+
+/// [`Keta::digits_iter_radix`] の自由関数版
+pub fn digits_iter_radix<T: Keta>(n: T, base: u32) -> impl Iterator<Item = u8> {
+    n.digits_iter_radix(base)
+}
+
+// ============================================================
+// 符号なし整数専用の操作
+// ============================================================
+
+/// [`KetaUnsigned::to_gray`] の自由関数版
+pub fn to_gray<T: KetaUnsigned>(n: T) -> T {
+    n.to_gray()
+}
+
+/// [`KetaUnsigned::from_gray`] の自由関数版
+pub fn from_gray<T: KetaUnsigned>(n: T) -> T {
+    n.from_gray()
+}
+
+/// [`KetaUnsigned::reverse_bits_width`] の自由関数版
+pub fn reverse_bits_width<T: KetaUnsigned>(n: T, width: u32) -> T {
+    n.reverse_bits_width(width)
+}
+
+// ============================================================
+// 数字列の操作
+// 数値に戻さずに、上位桁から並んだ数字のスライスを直接扱う
+// ============================================================
+
+/// 数字の列の和を計算する ([`Keta::digit_sum`] の数字列版)
+///
+/// # Example
+/// ```
+/// assert_eq!(keta::ops::slice_digit_sum(&[1, 2, 3, 4]), 10);
+/// ```
+pub fn slice_digit_sum(d: &[u8]) -> u64 {
+    d.iter().map(|&x| x as u64).sum()
+}
+
+/// 数字の列が回文か判定する ([`Keta::is_palindrome`] の数字列版)
+///
+/// 先頭の 0 も数字として扱う。
+///
+/// # Example
+/// ```
+/// assert!(keta::ops::slice_is_palindrome(&[1, 2, 1]));
+/// assert!(!keta::ops::slice_is_palindrome(&[0, 1, 1]));
+/// ```
+pub fn slice_is_palindrome(d: &[u8]) -> bool {
+    d.iter().eq(d.iter().rev())
+}
+
+/// 数字の列をその場で反転する ([`Keta::reverse`] の数字列版, 0 は失われない)
+///
+/// # Example
+/// ```
+/// let mut d = [1, 2, 0, 0];
+/// keta::ops::slice_reverse(&mut d);
+/// assert_eq!(d, [0, 0, 2, 1]);
+/// ```
+pub fn slice_reverse(d: &mut [u8]) {
+    d.reverse();
+}
+
+/// 数字の列をその場で降順に並べ替える ([`Keta::make_max`] の数字列版)
+///
+/// # Example
+/// ```
+/// let mut d = [2, 0, 2, 6];
+/// keta::ops::slice_make_max(&mut d);
+/// assert_eq!(d, [6, 2, 2, 0]);
+/// ```
+pub fn slice_make_max(d: &mut [u8]) {
+    // make_max と同じく出現回数を数えて並べ直す
+    let mut counts = [0usize; 256];
+    for &x in d.iter() {
+        counts[x as usize] += 1;
+    }
+    let mut i = 0;
+    for x in (0..256).rev() {
+        for _ in 0..counts[x] {
+            d[i] = x as u8;
+            i += 1;
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! イテレータで各桁の和を集計するための `DigitSum`
+//!
+//! `From` で各桁の和 (10進数) に変換し、`Sum` で合計できるので、
+//! `map(DigitSum::from).sum::<DigitSum>()` のようにアダプタの連鎖に組み込める。
+//!
+//! # Example
+//! ```
+//! use keta::DigitSum;
+//!
+//! let nums = [19u32, 28, 37];
+//! let total: DigitSum = nums.iter().copied().map(DigitSum::from).sum();
+//! assert_eq!(total, DigitSum(30));
+//! ```
+
+use core::iter::Sum;
+
+use crate::Keta;
+
+/// 10進数での各桁の和
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct DigitSum(pub u64);
+
+impl<T: Keta> From<T> for DigitSum {
+    fn from(n: T) -> Self {
+        DigitSum(n.digit_sum())
+    }
+}
+
+impl Sum for DigitSum {
+    fn sum<I: Iterator<Item = DigitSum>>(iter: I) -> Self {
+        DigitSum(iter.map(|s| s.0).sum())
+    }
+}
+
+impl<'a> Sum<&'a DigitSum> for DigitSum {
+    fn sum<I: Iterator<Item = &'a DigitSum>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
@@ -0,0 +1,61 @@
+//! 同じ数の桁を何度も参照するためのビュー `DigitView`
+//!
+//! `nth_digit` は呼ぶたびに桁数と `pow` を計算し直す。`Keta::digit_view` で
+//! 一度だけ桁を分解しておけば、以降の位置指定の参照はどれも O(1) になる。
+//!
+//! # Example
+//! ```
+//! use keta::Keta;
+//!
+//! let v = 12345u32.digit_view(10);
+//! assert_eq!(v.len(), 5);
+//! assert_eq!(v.get(0), Some(1));
+//! assert_eq!(v.get_from_right(0), Some(5));
+//! assert_eq!(v.get(5), None);
+//! ```
+
+use alloc::vec::Vec;
+
+/// 分解済みの桁 (上位桁から, 負の数は絶対値)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DigitView {
+    digits: Vec<u8>,
+    base: u32,
+}
+
+impl DigitView {
+    pub(crate) fn new(digits: Vec<u8>, base: u32) -> Self {
+        DigitView { digits, base }
+    }
+
+    /// 分解したときの基数
+    pub fn base(&self) -> u32 {
+        self.base
+    }
+
+    /// 桁数 (0 も1桁なので常に 1 以上)
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u32 {
+        self.digits.len() as u32
+    }
+
+    /// 上からi番目の桁を取得する (0-indexed, `Keta::nth_digit_radix` と同じ)
+    pub fn get(&self, i: u32) -> Option<u8> {
+        self.digits.get(i as usize).copied()
+    }
+
+    /// 下からi番目の桁を取得する (0-indexed, `Keta::nth_digit_from_right_radix` と同じ)
+    pub fn get_from_right(&self, i: u32) -> Option<u8> {
+        let i = i as usize;
+        if i < self.digits.len() {
+            Some(self.digits[self.digits.len() - 1 - i])
+        } else {
+            None
+        }
+    }
+
+    /// 各桁の数字のスライス (上位桁から)
+    pub fn as_slice(&self) -> &[u8] {
+        &self.digits
+    }
+}
@@ -13,6 +13,19 @@ fn test_digits() {
     assert_eq!(u64::from_digits(&d), max_u64);
 }
 
+#[test]
+fn test_signed_digits() {
+    assert_eq!((-123).signed_digits(), (true, vec![1, 2, 3]));
+    assert_eq!(123.signed_digits(), (false, vec![1, 2, 3]));
+    assert_eq!(0.signed_digits(), (false, vec![0]));
+    assert_eq!(255u8.signed_digits(), (false, vec![2, 5, 5]));
+    assert_eq!((-6).signed_digits_radix(2), (true, vec![1, 1, 0]));
+    // 符号と数字列から元の値に戻せる
+    let (neg, d) = (-4567i32).signed_digits();
+    let abs = i32::from_digits(&d);
+    assert_eq!(if neg { -abs } else { abs }, -4567);
+}
+
 #[test]
 fn test_digit_sum() {
     assert_eq!(123.digit_sum(), 6);
@@ -68,6 +81,127 @@ fn test_concat() {
     assert_eq!(0.concat(12), 12);
 }
 
+#[test]
+fn test_balanced_ternary() {
+    assert_eq!(8.to_balanced_ternary(), vec![1, 0, -1]);
+    assert_eq!(0.to_balanced_ternary(), vec![0]);
+    assert_eq!(1.to_balanced_ternary(), vec![1]);
+    assert_eq!(2.to_balanced_ternary(), vec![1, -1]);
+    assert_eq!((-2).to_balanced_ternary(), vec![-1, 1]);
+    for n in -1000..=1000 {
+        assert_eq!(i32::from_balanced_ternary(&n.to_balanced_ternary()), n);
+    }
+    for n in 0..=1000u32 {
+        assert_eq!(u32::from_balanced_ternary(&n.to_balanced_ternary()), n);
+    }
+    for n in [i64::MIN, i64::MAX, u64::MAX as i64] {
+        assert_eq!(i64::from_balanced_ternary(&n.to_balanced_ternary()), n);
+    }
+    assert_eq!(
+        u64::from_balanced_ternary(&u64::MAX.to_balanced_ternary()),
+        u64::MAX
+    );
+}
+
+#[test]
+fn test_negative_base() {
+    // negabinary
+    assert_eq!((-2).digits_negative_base(-2), vec![1, 0]);
+    assert_eq!(2.digits_negative_base(-2), vec![1, 1, 0]);
+    assert_eq!((-1).digits_negative_base(-2), vec![1, 1]);
+    assert_eq!(0.digits_negative_base(-2), vec![0]);
+    assert_eq!(i32::from_digits_negative_base(&[1, 0], -2), -2);
+    assert_eq!(i32::from_digits_negative_base(&[], -2), 0);
+    // negadecimal: 10 = 1*100 + 9*(-10) + 0
+    assert_eq!(10.digits_negative_base(-10), vec![1, 9, 0]);
+    for base in [-2, -3, -10, -16] {
+        for n in -500..=500 {
+            let d = n.digits_negative_base(base);
+            assert_eq!(i32::from_digits_negative_base(&d, base), n);
+        }
+    }
+    for n in [i128::MIN, i128::MAX, -1, 1] {
+        assert_eq!(
+            i128::from_digits_negative_base(&n.digits_negative_base(-2), -2),
+            n
+        );
+    }
+    for n in [u8::MAX as u128, u64::MAX as u128, u128::MAX] {
+        assert_eq!(
+            u128::from_digits_negative_base(&n.digits_negative_base(-2), -2),
+            n
+        );
+        assert_eq!(
+            u128::from_digits_negative_base(&n.digits_negative_base(-7), -7),
+            n
+        );
+    }
+    assert_eq!(
+        u8::from_digits_negative_base(&255u8.digits_negative_base(-256), -256),
+        255
+    );
+}
+
+#[test]
+#[should_panic(expected = "value out of range")]
+fn test_negative_base_negative_into_unsigned() {
+    u32::from_digits_negative_base(&[1, 0], -2);
+}
+
+#[test]
+fn test_remove_digit() {
+    assert_eq!(102030.remove_digit(0), 123);
+    // 含まれない数字なら変わらない
+    assert_eq!(12345.remove_digit(9), 12345);
+    // 先頭の桁を取り除く
+    assert_eq!(91929.remove_digit(9), 12);
+    assert_eq!(1111.remove_digit(1), 0);
+    assert_eq!(0.remove_digit(0), 0);
+    assert_eq!((-1213).remove_digit(1), -23);
+    assert_eq!(0b10110.remove_digit_radix(0, 2), 0b111);
+    assert_eq!(u64::MAX.remove_digit(0), 184467447379551615);
+}
+
+#[test]
+#[should_panic]
+fn test_remove_digit_invalid_digit() {
+    123.remove_digit_radix(2, 2);
+}
+
+#[test]
+fn test_split_at() {
+    assert_eq!(12345.split_at(2), (12, 345));
+    assert_eq!(10045.split_at(2), (10, 45));
+    assert_eq!(12345.split_at(0), (0, 12345));
+    assert_eq!(12345.split_at(5), (12345, 0));
+    assert_eq!(12345.split_at(100), (12345, 0));
+    assert_eq!(255u8.split_at(0), (0, 255));
+    assert_eq!((-12345).split_at(2), (-12, -345));
+    assert_eq!(0b1101.split_at_radix(1, 2), (1, 0b101));
+    // concat の逆
+    let (a, b) = 98765u32.split_at(3);
+    assert_eq!(a.concat(b), 98765);
+}
+
+#[test]
+fn test_interleave() {
+    // 同じ桁数
+    assert_eq!(123.interleave(456), 142536);
+    assert_eq!(10.interleave(20), 1200);
+    // 桁数が異なる場合は長い方の残りを末尾に続ける
+    assert_eq!(12.interleave(3456), 132456);
+    assert_eq!(3456.interleave(12), 314256);
+    assert_eq!(0.interleave(12), 12);
+    // 符号は concat と同様に self に従う
+    assert_eq!((-12).interleave(34), -1324);
+    assert_eq!(12.interleave(-34), 1324);
+    assert_eq!(0b11.interleave_radix(0b10, 2), 0b1110);
+    assert_eq!(0b11.interleave_radix(0, 2), 0b101);
+    assert_eq!(12u8.checked_interleave(3), Some(132));
+    assert_eq!(12u8.checked_interleave(34), None);
+    assert_eq!(u64::MAX.checked_interleave(1), None);
+}
+
 #[test]
 fn test_contains_digit() {
     assert!(12345.contains_digit(3));
@@ -81,7 +215,8 @@ fn test_make_max() {
     assert_eq!(2026.make_max(), 6220);
     assert_eq!(111.make_max(), 111);
     assert_eq!(0.make_max(), 0);
-    assert_eq!((-2026).make_max(), 6220); // Returns positive max formation
+    assert_eq!((-2026).make_max(), -226); // Keeps the sign: least-negative formation
+    assert_eq!((-5).make_max(), -5);
 }
 
 #[test]
@@ -89,7 +224,8 @@ fn test_make_min() {
     assert_eq!(2026.make_min(), 226); // 0226 -> 226 (no leading zero unless 0)
     assert_eq!(2001.make_min(), 12);
     assert_eq!(0.make_min(), 0);
-    assert_eq!((-5).make_min(), 5);
+    assert_eq!((-5).make_min(), -5);
+    assert_eq!((-2026).make_min(), -6220); // Keeps the sign: most-negative formation
 }
 
 #[test]
@@ -111,10 +247,63 @@ fn test_digits_radix() {
     assert_eq!(0.digits_radix(2), vec![0]);
 }
 
+#[test]
+#[should_panic(expected = "base must be at least 2")]
+fn test_digits_radix_base_zero() {
+    6.digits_radix(0);
+}
+
+#[test]
+#[should_panic(expected = "base must be at least 2")]
+fn test_digits_radix_base_one() {
+    // n /= 1 では終わらないので、無限ループではなくパニックになること
+    6.digits_radix(1);
+}
+
+#[test]
+#[should_panic(expected = "base must be at least 2")]
+fn test_digit_sum_radix_base_one_signed() {
+    (-6i32).digit_sum_radix(1);
+}
+
+#[test]
+#[should_panic(expected = "base must be at least 2")]
+fn test_reverse_radix_base_zero() {
+    123u8.reverse_radix(0);
+}
+
 #[test]
 fn test_from_digits_radix() {
     assert_eq!(u64::from_digits_radix(&[1, 1, 0], 2), 6);
     assert_eq!(u64::from_digits_radix(&[15, 15], 16), 255);
+    // 検査付きの try_from_digits_radix と同じ値になる
+    for n in 0..=u8::MAX {
+        for base in [2, 3, 10, 16, 255] {
+            let d = n.digits_radix(base);
+            assert_eq!(
+                Some(u8::from_digits_radix(&d, base)),
+                u8::try_from_digits_radix(&d, base)
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "value out of range for the type")]
+fn test_from_digits_radix_overflow() {
+    u8::from_digits_radix(&[1, 0, 0], 16); // 256
+}
+
+#[test]
+#[should_panic(expected = "digit out of range for the base")]
+fn test_from_digits_radix_invalid_digit() {
+    u64::from_digits_radix(&[2], 2);
+}
+
+#[test]
+#[should_panic(expected = "value out of range for the type")]
+fn test_from_digits_overflow() {
+    i8::from_digits(&[1, 2, 8]);
 }
 
 #[test]
@@ -128,9 +317,2878 @@ fn test_digit_product_radix() {
     assert_eq!(6.digit_product_radix(2), 0); // 110 -> 1*1*0=0
 }
 
+#[test]
+fn test_checked_digit_product() {
+    assert_eq!(1234.checked_digit_product(), Some(24));
+    assert_eq!(0.checked_digit_product(), Some(0));
+    assert_eq!((-1234).checked_digit_product(), Some(24));
+    // 9 が 22 個: 9^22 > u64::MAX
+    let nines: u128 = 9_999_999_999_999_999_999_999;
+    assert_eq!(nines.checked_digit_product(), None);
+    // 9^20 は u64 に収まる
+    assert_eq!(
+        99_999_999_999_999_999_999u128.checked_digit_product(),
+        Some(9u64.pow(20))
+    );
+    // 溢れた後でも 0 の桁があれば 0
+    assert_eq!((nines * 10).checked_digit_product(), Some(0));
+    assert_eq!(u128::MAX.checked_digit_product(), Some(0));
+    assert_eq!(
+        (-i128::MAX).checked_digit_product(),
+        i128::MAX.checked_digit_product()
+    );
+    // 2^64 - 1 を 16進数にすると F が 16 個: 15^16 は u64 に収まる
+    assert_eq!(
+        u64::MAX.checked_digit_product_radix(16),
+        Some(15u64.pow(16))
+    );
+    assert_eq!(u128::MAX.checked_digit_product_radix(16), None);
+}
+
 #[test]
 fn test_digits_len_radix() {
     assert_eq!(16.digits_len_radix(2), 5); // 10000
     assert_eq!(15.digits_len_radix(2), 4); // 1111
     assert_eq!(0.digits_len_radix(2), 1);
 }
+
+#[test]
+fn test_signed_digit_sum_pattern() {
+    // [1]: 桁和 -> 3, 9 の倍数判定
+    assert_eq!(123.signed_digit_sum_pattern(&[1]), 123.digit_sum() as i64);
+    assert_eq!(981.signed_digit_sum_pattern(&[1]) % 9, 0);
+
+    // [1, -1]: 交代和 -> 11 の倍数判定 (最下位桁から +, -, +, ...)
+    assert_eq!(121.signed_digit_sum_pattern(&[1, -1]), 0);
+    assert_eq!(918_082.signed_digit_sum_pattern(&[1, -1]) % 11, 0);
+    assert_ne!(918_083.signed_digit_sum_pattern(&[1, -1]) % 11, 0);
+    for n in 0..2000u32 {
+        let alt = n.signed_digit_sum_pattern(&[1, -1]);
+        assert_eq!(alt % 11 == 0, n % 11 == 0);
+    }
+
+    // 整列方向は最下位桁から: 12 -> 2 - 1
+    assert_eq!(12.signed_digit_sum_pattern(&[1, -1]), 1);
+    assert_eq!(12.signed_digit_sum_pattern(&[-1, 1]), -1);
+
+    assert_eq!(0.signed_digit_sum_pattern(&[1, -1]), 0);
+    assert_eq!((-121).signed_digit_sum_pattern(&[1, -1]), 0);
+    assert_eq!(123.signed_digit_sum_pattern(&[]), 0);
+    assert_eq!(i8::MIN.signed_digit_sum_pattern(&[1, -1]), 7); // 8 - 2 + 1
+    assert_eq!(
+        i64::MIN.signed_digit_sum_pattern(&[1]),
+        i64::MIN.digit_sum() as i64
+    );
+}
+
+#[test]
+fn test_count_digit() {
+    assert_eq!(1_717_171.count_digit(7), 3);
+    assert_eq!(1_717_171.count_digit(1), 4);
+    assert_eq!(12345.count_digit(9), 0);
+    assert_eq!(0.count_digit(0), 1);
+    assert_eq!(0.count_digit(1), 0);
+    assert_eq!(1000.count_digit(0), 3);
+    assert_eq!((-707).count_digit(7), 2);
+
+    // base 以上の数字は常に 0
+    assert_eq!(12345.count_digit(10), 0);
+    assert_eq!(0.count_digit(10), 0);
+    assert_eq!(i8::MIN.count_digit(8), 1);
+    assert_eq!(i64::MIN.count_digit(8), 3); // 9223372036854775808
+}
+
+#[test]
+fn test_count_digit_radix() {
+    assert_eq!(6.count_digit_radix(1, 2), 2); // 110
+    assert_eq!(6.count_digit_radix(0, 2), 1);
+    assert_eq!(255.count_digit_radix(15, 16), 2); // FF
+    assert_eq!(0.count_digit_radix(0, 2), 1);
+    assert_eq!((-6).count_digit_radix(1, 2), 2);
+
+    // base 以上の数字は常に 0
+    for n in 0..100u32 {
+        assert_eq!(n.count_digit_radix(2, 2), 0);
+        assert_eq!(n.count_digit_radix(16, 16), 0);
+    }
+}
+
+#[test]
+fn test_to_phone_letters() {
+    assert_eq!(23.to_phone_letters(), vec!["abc", "def"]);
+    assert_eq!(
+        1_234_567_890.to_phone_letters(),
+        vec!["", "abc", "def", "ghi", "jkl", "mno", "pqrs", "tuv", "wxyz", " "]
+    );
+    assert_eq!(0.to_phone_letters(), vec![" "]);
+    assert_eq!((-79).to_phone_letters(), vec!["pqrs", "wxyz"]);
+}
+
+#[test]
+fn test_phone_letter_combinations() {
+    let v: Vec<String> = 23u32.phone_letter_combinations().collect();
+    assert_eq!(v, ["ad", "ae", "af", "bd", "be", "bf", "cd", "ce", "cf"]);
+
+    // 7, 9 は4文字
+    assert_eq!(79u32.phone_letter_combinations().count(), 16);
+
+    // 1 は読み飛ばし、0 は空白
+    let v: Vec<String> = 102u32.phone_letter_combinations().collect();
+    assert_eq!(v, [" a", " b", " c"]);
+    assert_eq!(
+        1u32.phone_letter_combinations().collect::<Vec<_>>(),
+        vec![String::new()]
+    );
+}
+
+#[test]
+fn test_digit_counts() {
+    assert_eq!(2026.digit_counts(), [1, 0, 2, 0, 0, 0, 1, 0, 0, 0]);
+    assert_eq!(0.digit_counts(), [1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(1_234_567_890u64.digit_counts(), [1; 10]);
+    assert_eq!((-112).digit_counts(), [0, 2, 1, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(
+        u64::MAX.digit_counts().iter().sum::<u32>(),
+        u64::MAX.digits_len()
+    );
+    assert_eq!(i8::MIN.digit_counts(), [0, 1, 1, 0, 0, 0, 0, 0, 1, 0]);
+    assert_eq!(i32::MIN.digit_counts(), [0, 1, 1, 1, 3, 0, 1, 1, 2, 0]);
+}
+
+#[test]
+fn test_digit_counts_radix() {
+    assert_eq!(6.digit_counts_radix(2), vec![1, 2]); // 110
+    assert_eq!(255.digit_counts_radix(16).len(), 16);
+    assert_eq!(255.digit_counts_radix(16)[15], 2); // FF
+    assert_eq!(0.digit_counts_radix(3), vec![1, 0, 0]);
+    assert_eq!((-6).digit_counts_radix(2), vec![1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "base must be at least 2")]
+fn test_digit_counts_radix_base_zero() {
+    0u32.digit_counts_radix(0);
+}
+
+#[test]
+#[should_panic(expected = "base must be at least 2")]
+fn test_digit_counts_radix_base_one() {
+    // 0 は早期リターンするので、ここで基数を検査しないと [1] が返ってしまう
+    0u32.digit_counts_radix(1);
+}
+
+#[test]
+#[should_panic(expected = "base must be at least 2")]
+fn test_digit_counts_radix_base_one_signed() {
+    (-6i32).digit_counts_radix(1);
+}
+
+#[test]
+fn test_digit_positions() {
+    assert_eq!(12121.digit_positions(1), vec![0, 2, 4]);
+    assert_eq!(12121.digit_positions(2), vec![1, 3]);
+    assert_eq!(12121.digit_positions(9), Vec::<u32>::new());
+    assert_eq!(1002.digit_positions(0), vec![1, 2]);
+    assert_eq!(0.digit_positions(0), vec![0]);
+    assert_eq!(0.digit_positions(5), Vec::<u32>::new());
+    assert_eq!((-505).digit_positions(5), vec![0, 2]);
+    assert_eq!(i8::MIN.digit_positions(8), vec![2]);
+    assert_eq!(i64::MIN.digit_positions(8), vec![10, 16, 18]); // 9223372036854775808
+}
+
+#[test]
+fn test_digit_positions_radix() {
+    assert_eq!(10.digit_positions_radix(1, 2), vec![0, 2]); // 1010
+    assert_eq!(10.digit_positions_radix(0, 2), vec![1, 3]);
+    assert_eq!(0xF0F.digit_positions_radix(15, 16), vec![0, 2]);
+    assert_eq!(10.digit_positions_radix(2, 2), Vec::<u32>::new());
+}
+
+#[test]
+fn test_is_pandigital() {
+    assert!(1_234_567_890u64.is_pandigital());
+    assert!(9_876_543_210u64.is_pandigital());
+    assert!(!1_234_567_891u64.is_pandigital()); // 1 が重複
+    assert!(!123_456_789.is_pandigital()); // 0 が欠けている
+    assert!(!12_345_678_900u64.is_pandigital()); // 11桁
+    assert!(!0.is_pandigital());
+    assert!((-1_234_567_890i64).is_pandigital());
+}
+
+#[test]
+fn test_is_pandigital_1_to_n() {
+    assert!(1.is_pandigital_1_to_n());
+    assert!(2143.is_pandigital_1_to_n());
+    assert!(123_456_789.is_pandigital_1_to_n());
+    assert!(!2144.is_pandigital_1_to_n());
+    assert!(!1235.is_pandigital_1_to_n());
+    assert!(!0.is_pandigital_1_to_n());
+    assert!(!1_234_567_890u64.is_pandigital_1_to_n());
+    assert!(!i8::MIN.is_pandigital_1_to_n());
+    assert!(!i32::MIN.is_pandigital_1_to_n());
+}
+
+#[test]
+fn test_is_pandigital_radix() {
+    assert!(2.is_pandigital_radix(2)); // 10
+    assert!(!1.is_pandigital_radix(2));
+    assert!(!6.is_pandigital_radix(2)); // 110
+    assert!(u64::from_digits_radix(&[3, 0, 2, 1], 4).is_pandigital_radix(4));
+    assert!(!0x0123_4567_89ab_cdefu64.is_pandigital_radix(16)); // 先頭の 0 は消える
+    assert!(0xfedc_ba98_7654_3210u64.is_pandigital_radix(16));
+}
+
+#[test]
+fn test_rotate_digits_left() {
+    assert_eq!(1234.rotate_digits_left(0), 1234);
+    assert_eq!(1234.rotate_digits_left(1), 2341);
+    assert_eq!(1234.rotate_digits_left(3), 4123);
+    assert_eq!(1234.rotate_digits_left(4), 1234);
+    assert_eq!(1234.rotate_digits_left(9), 2341); // n > 桁数
+    assert_eq!(100.rotate_digits_left(1), 1); // 001 -> 1
+    assert_eq!(0.rotate_digits_left(3), 0);
+    assert_eq!(7.rotate_digits_left(2), 7);
+    assert_eq!((-123).rotate_digits_left(1), -231);
+}
+
+#[test]
+fn test_rotate_digits_right() {
+    assert_eq!(1234.rotate_digits_right(1), 4123);
+    assert_eq!(1234.rotate_digits_right(3), 2341);
+    assert_eq!(1234.rotate_digits_right(6), 3412); // n > 桁数
+    assert_eq!(120.rotate_digits_right(1), 12); // 012 -> 12
+    assert_eq!((-123).rotate_digits_right(1), -312);
+    // 0 を含まなければ左右の回転で元に戻る
+    for n in [1u32, 12, 123, 9876, 45678] {
+        for k in 0..10 {
+            assert_eq!(n.rotate_digits_left(k).rotate_digits_right(k), n);
+        }
+    }
+}
+
+#[test]
+fn test_rotate_digits_radix() {
+    assert_eq!(6.rotate_digits_left_radix(1, 2), 5); // 110 -> 101
+    assert_eq!(6.rotate_digits_right_radix(1, 2), 3); // 110 -> 011
+    assert_eq!(0x12.rotate_digits_left_radix(1, 16), 0x21);
+    assert_eq!(0x123.rotate_digits_right_radix(4, 16), 0x312);
+    assert_eq!((-6).rotate_digits_left_radix(1, 2), -5);
+}
+
+#[test]
+fn test_checked_rotate_digits() {
+    assert_eq!(123u8.checked_rotate_digits_left(1), Some(231));
+    assert_eq!(129u8.checked_rotate_digits_left(1), None); // 291
+    assert_eq!(129u8.checked_rotate_digits_right(1), None); // 912
+    assert_eq!(129u8.checked_rotate_digits_right(2), None); // 291
+    assert_eq!(210u8.checked_rotate_digits_left(1), Some(102));
+    assert_eq!(u64::MAX.checked_rotate_digits_left(1), None);
+    assert_eq!((-123i8).checked_rotate_digits_left(1), None); // -231
+    assert_eq!(i8::MIN.checked_rotate_digits_left(2), None); // -812
+    assert_eq!(i8::MIN.checked_rotate_digits_left(3), Some(i8::MIN));
+    assert_eq!(
+        0b1000_0001u8.checked_rotate_digits_left_radix(1, 2),
+        Some(0b11)
+    );
+    assert_eq!(i8::MIN.checked_rotate_digits_left_radix(1, 2), Some(-1)); // -0000001
+    assert_eq!(0x1fu8.checked_rotate_digits_right_radix(1, 16), Some(0xf1));
+}
+
+#[test]
+#[should_panic(expected = "rotated value overflows the type")]
+fn test_rotate_digits_overflow() {
+    129u8.rotate_digits_left(1);
+}
+
+#[test]
+fn test_digit_square_sum() {
+    assert_eq!(123.digit_square_sum(), 14);
+    assert_eq!(0.digit_square_sum(), 0);
+    assert_eq!((-99).digit_square_sum(), 162);
+    assert_eq!(6.digit_square_sum_radix(2), 2); // 110
+    assert_eq!(i8::MIN.digit_square_sum(), 69); // 1 + 4 + 64
+}
+
+#[test]
+fn test_is_happy() {
+    // OEIS A007770
+    let happy: Vec<u32> = (1..50).filter(|n| n.is_happy()).collect();
+    assert_eq!(happy, vec![1, 7, 10, 13, 19, 23, 28, 31, 32, 44, 49]);
+    assert!(1.is_happy());
+    assert!(!4.is_happy());
+    assert!(!0.is_happy());
+    assert!((-7).is_happy());
+    assert!(u64::MAX.is_happy() == u64::MAX.digit_square_sum().is_happy());
+    assert!(!i8::MIN.is_happy());
+    assert_eq!(i32::MIN.is_happy(), i32::MIN.unsigned_abs().is_happy());
+}
+
+#[test]
+fn test_is_happy_radix() {
+    // 2進数と4進数では全ての正の整数がハッピー数
+    assert!((1..200u32).all(|n| n.is_happy_radix(2)));
+    assert!((1..200u32).all(|n| n.is_happy_radix(4)));
+    assert!(!0.is_happy_radix(2));
+    assert_eq!(
+        (1..200u32).filter(|n| n.is_happy_radix(10)).count(),
+        (1..200u32).filter(|n| n.is_happy()).count()
+    );
+}
+
+#[test]
+fn test_is_armstrong() {
+    // 1桁の数は自明にアームストロング数
+    assert!((0..10).all(|n| n.is_armstrong()));
+    assert!(153.is_armstrong());
+    assert!(370.is_armstrong());
+    assert!(9474.is_armstrong());
+    assert!(!154.is_armstrong());
+    assert!(!10.is_armstrong());
+    assert!((-153).is_armstrong());
+    assert!(4_679_307_774u64.is_armstrong());
+    // 桁数乗の和が大きくなる入力でもオーバーフローしない
+    assert!(!u128::MAX.is_armstrong());
+    assert!(!(10u128.pow(38) - 1).is_armstrong());
+    assert!(!u8::MAX.is_armstrong());
+    assert!(!i8::MIN.is_armstrong()); // 1 + 8 + 512 != 128
+    assert!(!i128::MIN.is_armstrong());
+}
+
+#[test]
+fn test_is_armstrong_radix() {
+    assert!((0..2).all(|n| n.is_armstrong_radix(2)));
+    assert!(!2.is_armstrong_radix(2)); // 10 -> 1^2 + 0^2 = 1
+    assert!(5.is_armstrong_radix(3)); // 12 -> 1^2 + 2^2 = 5
+    assert!(153.is_armstrong_radix(10));
+    assert!(!u64::MAX.is_armstrong_radix(16));
+}
+
+#[test]
+fn test_is_harshad() {
+    assert!(18.is_harshad());
+    assert!(!19.is_harshad());
+    assert!(!0.is_harshad());
+    assert!((1..10).all(|n| n.is_harshad()));
+    assert!((-18).is_harshad());
+    // OEIS A005349
+    let h: Vec<u32> = (1..50).filter(|n| n.is_harshad()).collect();
+    assert_eq!(
+        h,
+        vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 12, 18, 20, 21, 24, 27, 30, 36, 40, 42, 45, 48]
+    );
+    assert!(!u8::MAX.is_harshad()); // 255 % 12 != 0
+    assert!(!i8::MIN.is_harshad()); // 128 % 11 != 0
+    assert_eq!(i64::MIN.is_harshad(), i64::MIN.unsigned_abs().is_harshad());
+}
+
+#[test]
+fn test_is_harshad_radix() {
+    assert!(6.is_harshad_radix(2)); // 110 -> 2
+    assert!(!7.is_harshad_radix(2)); // 111 -> 3
+    assert!(!0.is_harshad_radix(2));
+    assert!((-6).is_harshad_radix(2));
+    assert!(i8::MIN.is_harshad_radix(2)); // 10000000 -> 1
+    assert!(i32::MIN.is_harshad_radix(16)); // 80000000 -> 8
+}
+
+#[test]
+fn test_to_radix_string() {
+    assert_eq!(255.to_radix_string(16), "ff");
+    assert_eq!(255.to_radix_string_upper(16), "FF");
+    assert_eq!(6.to_radix_string(2), "110");
+    assert_eq!(0.to_radix_string(2), "0");
+    assert_eq!(35.to_radix_string(36), "z");
+    assert_eq!((-255).to_radix_string(16), "-ff");
+    assert_eq!(12345.to_radix_string(10), "12345");
+    assert_eq!(u64::MAX.to_radix_string(16), format!("{:x}", u64::MAX));
+    assert_eq!(
+        i64::MIN.wrapping_add(1).to_radix_string(16),
+        format!("-{:x}", i64::MAX)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_to_radix_string_invalid_base() {
+    255.to_radix_string(37);
+}
+
+#[test]
+fn test_digits_padded() {
+    assert_eq!(5.digits_padded(3), vec![0, 0, 5]);
+    assert_eq!(123.digits_padded(3), vec![1, 2, 3]);
+    assert_eq!(0.digits_padded(2), vec![0, 0]);
+    assert_eq!((-7).digits_padded(2), vec![0, 7]);
+    assert_eq!(5.digits_padded_radix(4, 2), vec![0, 1, 0, 1]);
+    assert_eq!(5.to_radix_string_padded(3, 10), "005");
+    assert_eq!(255.to_radix_string_padded(2, 16), "ff");
+    assert_eq!((-5).to_radix_string_padded(4, 2), "-0101");
+}
+
+#[test]
+#[should_panic(expected = "do not fit in width")]
+fn test_digits_padded_too_narrow() {
+    1234.digits_padded(3);
+}
+
+#[test]
+fn test_group_digits() {
+    assert_eq!(1234567.group_digits(',', 3), "1,234,567");
+    // ちょうど group の倍数の桁数
+    assert_eq!(123456.group_digits(',', 3), "123,456");
+    // 1 グループに満たない
+    assert_eq!(12.group_digits(',', 3), "12");
+    assert_eq!(0.group_digits(',', 3), "0");
+    assert_eq!((-1234567).group_digits(',', 3), "-1,234,567");
+    assert_eq!((-123).group_digits(',', 3), "-123");
+    assert_eq!(12345.group_digits('\'', 1), "1'2'3'4'5");
+    assert_eq!(0xdeadbeef_u32.group_digits_radix(' ', 2, 16), "de ad be ef");
+    assert_eq!(0b101101.group_digits_radix('_', 4, 2), "10_1101");
+}
+
+#[test]
+fn test_from_radix_string() {
+    use keta::ParseKetaError;
+
+    assert_eq!(u32::from_radix_string("ff", 16), Ok(255));
+    assert_eq!(u32::from_radix_string("FF", 16), Ok(255));
+    assert_eq!(u32::from_radix_string("+110", 2), Ok(6));
+    assert_eq!(i32::from_radix_string("-ff", 16), Ok(-255));
+    assert_eq!(i8::from_radix_string("-128", 10), Ok(i8::MIN));
+    assert_eq!(u64::from_radix_string("0", 36), Ok(0));
+
+    // 往復変換
+    for n in [0u64, 1, 35, 36, 255, 123_456_789, u64::MAX] {
+        for base in [2, 8, 10, 16, 36] {
+            assert_eq!(
+                u64::from_radix_string(&n.to_radix_string(base), base),
+                Ok(n)
+            );
+        }
+    }
+    for n in [i64::MIN + 1, -1, 0, i64::MAX] {
+        assert_eq!(i64::from_radix_string(&n.to_radix_string(36), 36), Ok(n));
+    }
+
+    // エラー
+    assert_eq!(
+        u8::from_radix_string("256", 10),
+        Err(ParseKetaError::Overflow)
+    );
+    assert_eq!(
+        i8::from_radix_string("128", 10),
+        Err(ParseKetaError::Overflow)
+    );
+    assert_eq!(
+        i8::from_radix_string("-129", 10),
+        Err(ParseKetaError::Overflow)
+    );
+    assert_eq!(
+        u32::from_radix_string("1g", 16),
+        Err(ParseKetaError::DigitOutOfRange('g'))
+    );
+    assert_eq!(
+        u32::from_radix_string("12!", 10),
+        Err(ParseKetaError::InvalidDigit('!'))
+    );
+    assert_eq!(
+        u32::from_radix_string("-1", 10),
+        Err(ParseKetaError::InvalidDigit('-'))
+    );
+    assert_eq!(
+        i32::from_radix_string("+-1", 10),
+        Err(ParseKetaError::InvalidDigit('-'))
+    );
+    assert_eq!(u32::from_radix_string("", 10), Err(ParseKetaError::Empty));
+    assert_eq!(i32::from_radix_string("-", 10), Err(ParseKetaError::Empty));
+}
+
+#[test]
+fn test_try_from_digits() {
+    assert_eq!(u64::try_from_digits(&[1, 2, 3]), Some(123));
+    assert_eq!(u64::try_from_digits(&[]), Some(0));
+    assert_eq!(i32::try_from_digits(&[0, 0, 7]), Some(7));
+    assert_eq!(u8::try_from_digits(&[2, 5, 5]), Some(255));
+    assert_eq!(u8::try_from_digits(&[2, 5, 6]), None);
+    assert_eq!(i8::try_from_digits(&[1, 2, 8]), None);
+    assert_eq!(u64::try_from_digits(&[9, 9]), Some(99));
+    assert_eq!(u64::try_from_digits(&[99]), None);
+    assert_eq!(u64::try_from_digits(&u64::MAX.digits()), Some(u64::MAX));
+}
+
+#[test]
+fn test_try_from_digits_radix() {
+    assert_eq!(u64::try_from_digits_radix(&[1, 1, 0], 2), Some(6));
+    assert_eq!(u64::try_from_digits_radix(&[15, 15], 16), Some(255));
+    assert_eq!(u64::try_from_digits_radix(&[2], 2), None);
+    assert_eq!(u8::try_from_digits_radix(&[1, 0, 0], 16), None); // 256
+    assert_eq!(u8::try_from_digits_radix(&[15, 15], 16), Some(255));
+    // 基数が u8 に収まらなければ、0 でない数字は最後の桁にしか置けない
+    assert_eq!(u8::try_from_digits_radix(&[5], 1000), Some(5));
+    assert_eq!(u8::try_from_digits_radix(&[0, 0, 200], 256), Some(200));
+    assert_eq!(u8::try_from_digits_radix(&[1, 0], 256), None);
+    assert_eq!(i8::try_from_digits_radix(&[200], 256), None);
+}
+
+#[test]
+fn test_make_max_min_radix_sign() {
+    assert_eq!(6.make_max_radix(2), 6); // 110
+    assert_eq!(6.make_min_radix(2), 3); // 011
+    assert_eq!((-6).make_max_radix(2), -3);
+    assert_eq!((-6).make_min_radix(2), -6);
+    for n in [-2026, -101, -9, 0, 9, 101, 2026] {
+        assert!(n.make_min() <= n && n <= n.make_max());
+    }
+}
+
+#[test]
+fn test_additive_persistence() {
+    assert_eq!(0.additive_persistence(), 0);
+    assert_eq!(9.additive_persistence(), 0);
+    assert_eq!(10.additive_persistence(), 1);
+    assert_eq!(199.additive_persistence(), 3);
+    assert_eq!((-199).additive_persistence(), 3);
+    // OEIS A006050: 加法的持続性 4 となる最小の数
+    assert_eq!(19_999_999_999_999_999_999_999u128.additive_persistence(), 4);
+}
+
+#[test]
+fn test_multiplicative_persistence() {
+    assert_eq!(0.multiplicative_persistence(), 0);
+    assert_eq!(10.multiplicative_persistence(), 1);
+    assert_eq!(39.multiplicative_persistence(), 3);
+    assert_eq!(999.multiplicative_persistence(), 4);
+    assert_eq!((-39).multiplicative_persistence(), 3);
+    assert_eq!(277_777_788_888_899u64.multiplicative_persistence(), 11);
+    assert_eq!(277_777_788_888_899u128.multiplicative_persistence(), 11);
+}
+
+#[test]
+fn test_digit_slice() {
+    assert_eq!(123456.digit_slice(1, 3), 234);
+    assert_eq!(123456.digit_slice(0, 6), 123456);
+    assert_eq!(123456.digit_slice(0, 2), 12);
+    assert_eq!(123456.digit_slice(5, 1), 6);
+    assert_eq!(123456.digit_slice(3, 0), 0);
+    // 範囲外は切り詰め
+    assert_eq!(123456.digit_slice(4, 100), 56);
+    assert_eq!(123456.digit_slice(6, 1), 0);
+    assert_eq!(123456.digit_slice(u32::MAX, u32::MAX), 0);
+    // 途中の 0
+    assert_eq!(1002.digit_slice(1, 2), 0);
+    assert_eq!(1002.digit_slice(1, 3), 2);
+    assert_eq!(1002.digit_slice(0, 2), 10);
+    assert_eq!(0.digit_slice(0, 1), 0);
+    assert_eq!((-123456).digit_slice(1, 3), -234);
+    // 型の最大桁数でも pow がオーバーフローしない
+    assert_eq!(u8::MAX.digit_slice(0, 3), 255);
+    assert_eq!(u8::MAX.digit_slice(1, 2), 55);
+    // 長さ 0 は最大桁数の値でも溢れない
+    assert_eq!(255u8.digit_slice(0, 0), 0);
+    assert_eq!(u64::MAX.digit_slice(0, 0), 0);
+    assert_eq!(u8::MAX.digit_slice_radix(0, 0, 2), 0);
+    assert_eq!(i8::MIN.digit_slice(0, 0), 0);
+    // MIN でも abs を取らずに切り出す
+    assert_eq!((-128i8).digit_slice(0, 1), -1);
+    assert_eq!(i8::MIN.digit_slice(0, 3), i8::MIN);
+    assert_eq!(i8::MIN.digit_slice_radix(0, 1, 2), -1);
+    assert_eq!(i64::MIN.digit_slice(1, 2), -22);
+}
+
+#[test]
+fn test_digit_slice_radix() {
+    assert_eq!(0b110110.digit_slice_radix(1, 3, 2), 0b101);
+    assert_eq!(0b1001.digit_slice_radix(1, 2, 2), 0);
+    assert_eq!(0xabcd.digit_slice_radix(2, 9, 16), 0xcd);
+    assert_eq!((-0xabcd).digit_slice_radix(0, 2, 16), -0xab);
+}
+
+#[test]
+fn test_with_digit() {
+    assert_eq!(12345.with_digit(2, 9), 12945);
+    assert_eq!(12345.with_digit(4, 0), 12340);
+    assert_eq!(12345.with_digit(0, 9), 92345);
+    assert_eq!(12345.with_digit(0, 0), 2345); // 先頭が 0 になると桁数が減る
+    assert_eq!(0.with_digit(0, 7), 7);
+    assert_eq!((-12345).with_digit(2, 9), -12945);
+    for i in 0..5 {
+        let d = 12345.nth_digit(i).unwrap();
+        assert_eq!(12345.with_digit(i, d), 12345);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_with_digit_invalid_digit() {
+    12345.with_digit(0, 10);
+}
+
+#[test]
+#[should_panic]
+fn test_with_digit_out_of_range() {
+    12345.with_digit(5, 1);
+}
+
+#[test]
+fn test_with_digit_radix() {
+    assert_eq!(0b110.with_digit_radix(2, 1, 2), 0b111);
+    assert_eq!(0b110.with_digit_radix(0, 0, 2), 0b10);
+    assert_eq!(0xabc.with_digit_radix(1, 0xf, 16), 0xafc);
+    assert_eq!((-0xabc).with_digit_radix(1, 0, 16), -0xa0c);
+}
+
+#[test]
+fn test_checked_with_digit() {
+    assert_eq!(255u8.checked_with_digit(0, 1), Some(155));
+    assert_eq!(255u8.checked_with_digit(0, 9), None); // 955
+    assert_eq!(255u8.checked_with_digit(2, 9), None); // 259
+    assert_eq!(255u8.checked_with_digit(1, 0), Some(205));
+    assert_eq!(12345u32.checked_with_digit(2, 9), Some(12945));
+    assert_eq!(u64::MAX.checked_with_digit(0, 2), None);
+    // 符号付きは MIN でも abs を取らない
+    assert_eq!(i8::MIN.checked_with_digit(2, 7), Some(-127));
+    assert_eq!(i8::MIN.checked_with_digit(2, 9), None); // -129
+    assert_eq!(127i8.checked_with_digit(2, 8), None);
+    assert_eq!(i8::MIN.with_digit(0, 0), -28);
+    assert_eq!(0b1111u8.checked_with_digit_radix(0, 0, 2), Some(0b111));
+    assert_eq!(0xffu8.checked_with_digit_radix(1, 0, 16), Some(0xf0));
+    assert_eq!(i8::MIN.checked_with_digit_radix(7, 1, 2), None);
+    assert_eq!(i8::MIN.checked_with_digit_radix(0, 0, 2), Some(0));
+}
+
+#[test]
+#[should_panic(expected = "with_digit result overflows the type")]
+fn test_with_digit_overflow() {
+    let _ = 255u8.with_digit(0, 9);
+}
+
+#[test]
+#[should_panic]
+fn test_with_digit_radix_invalid_digit() {
+    0b110.with_digit_radix(0, 2, 2);
+}
+
+#[test]
+fn test_luhn_is_valid() {
+    assert!(79_927_398_713u64.luhn_is_valid());
+    assert!(!79_927_398_714u64.luhn_is_valid());
+    assert!(!79_927_398_712u64.luhn_is_valid());
+    // テスト用のカード番号
+    assert!(4_111_111_111_111_111u64.luhn_is_valid());
+    assert!(!4_111_111_111_111_112u64.luhn_is_valid());
+    assert!(5_555_555_555_554_444u64.luhn_is_valid());
+    assert!(18.luhn_is_valid()); // 1*2 + 8 = 10
+    assert!(59.luhn_is_valid()); // 5*2 -> 1 + 9 = 10
+    assert!(0.luhn_is_valid());
+    assert!((-18).luhn_is_valid());
+    assert!(!i8::MIN.luhn_is_valid()); // 8 + 4 + 1
+    assert_eq!(
+        i64::MIN.luhn_is_valid(),
+        i64::MIN.unsigned_abs().luhn_is_valid()
+    );
+}
+
+#[test]
+fn test_luhn_check_digit() {
+    assert_eq!(7_992_739_871u64.luhn_check_digit(), 3);
+    assert_eq!(411_111_111_111_111u64.luhn_check_digit(), 1);
+    assert_eq!(0.luhn_check_digit(), 0);
+    for n in 0..2000u64 {
+        let full = n * 10 + n.luhn_check_digit() as u64;
+        assert!(full.luhn_is_valid());
+        // 検査数字以外の値では不正になる
+        assert!(!(full - full % 10 + (full + 1) % 10).luhn_is_valid());
+    }
+    assert_eq!(i8::MIN.luhn_check_digit(), 9); // 1289
+    assert_eq!(
+        i64::MIN.luhn_check_digit(),
+        i64::MIN.unsigned_abs().luhn_check_digit()
+    );
+}
+
+#[test]
+fn test_is_automorphic() {
+    // OEIS A003226
+    let a: Vec<u64> = (0..100_000u64).filter(|n| n.is_automorphic()).collect();
+    assert_eq!(a, vec![0, 1, 5, 6, 25, 76, 376, 625, 9376, 90625]);
+    assert!(!7.is_automorphic());
+    assert!((-76).is_automorphic());
+    // 2乗が u64 に収まらない入力でも正しく判定できる
+    assert!(7_109_376u32.is_automorphic());
+    assert!(81_787_109_376u64.is_automorphic());
+    assert!(!u64::MAX.is_automorphic());
+    assert!(!u128::MAX.is_automorphic());
+    // 39桁 (2乗は u128 を大きく超える)
+    assert!(103_890_995_893_380_022_607_743_740_081_787_109_376u128.is_automorphic());
+}
+
+#[test]
+fn test_convert_radix() {
+    use keta::convert_radix;
+
+    assert_eq!(convert_radix(&[1, 1, 0], 2, 10), vec![6]);
+    assert_eq!(convert_radix(&[15, 15], 16, 10), vec![2, 5, 5]);
+    assert_eq!(
+        convert_radix(&[2, 5, 5], 10, 2),
+        vec![1, 1, 1, 1, 1, 1, 1, 1]
+    );
+    assert_eq!(convert_radix(&[0, 0, 1, 2], 10, 10), vec![1, 2]);
+    assert_eq!(convert_radix(&[], 10, 2), vec![0]);
+    assert_eq!(convert_radix(&[0, 0], 10, 2), vec![0]);
+
+    for n in [0u64, 1, 9, 10, 255, 256, 123_456_789, u64::MAX] {
+        for (from, to) in [(2, 10), (10, 16), (16, 3), (7, 256)] {
+            assert_eq!(
+                convert_radix(&n.digits_radix(from), from, to),
+                n.digits_radix(to)
+            );
+        }
+    }
+
+    // u128 にも収まらない長さ: 10^40 は 16進数で 34 桁
+    let mut big = vec![1];
+    big.extend([0; 40]);
+    let hex = convert_radix(&big, 10, 16);
+    assert_eq!(hex.len(), 34);
+    assert_eq!(convert_radix(&hex, 16, 10), big);
+}
+
+#[test]
+#[should_panic]
+fn test_convert_radix_invalid_digit() {
+    keta::convert_radix(&[1, 2], 2, 10);
+}
+
+#[test]
+fn test_nth_digit_from_right() {
+    assert_eq!(12345.nth_digit_from_right(0), Some(5));
+    assert_eq!(12345.nth_digit_from_right(4), Some(1));
+    assert_eq!(12345.nth_digit_from_right(5), None);
+    assert_eq!(12345.nth_digit_from_right(u32::MAX), None);
+    assert_eq!(1000.nth_digit_from_right(1), Some(0));
+    assert_eq!(0.nth_digit_from_right(0), Some(0));
+    assert_eq!(0.nth_digit_from_right(1), None);
+    assert_eq!((-12345).nth_digit_from_right(1), Some(4));
+    for i in 0..5 {
+        assert_eq!(12345.nth_digit_from_right(i), 12345.nth_digit(4 - i));
+    }
+    assert_eq!(i8::MIN.nth_digit_from_right(0), Some(8));
+    assert_eq!(i8::MIN.nth_digit_from_right(2), Some(1));
+    assert_eq!(i8::MIN.nth_digit_from_right(3), None);
+}
+
+#[test]
+fn test_nth_digit_from_right_radix() {
+    assert_eq!(6.nth_digit_from_right_radix(0, 2), Some(0)); // 110
+    assert_eq!(6.nth_digit_from_right_radix(2, 2), Some(1));
+    assert_eq!(6.nth_digit_from_right_radix(3, 2), None);
+    assert_eq!(0xabc.nth_digit_from_right_radix(1, 16), Some(0xb));
+}
+
+#[test]
+fn test_max_min_digit() {
+    assert_eq!(2026.max_digit(), 6);
+    assert_eq!(2026.min_digit(), 0);
+    assert_eq!(0.max_digit(), 0);
+    assert_eq!(0.min_digit(), 0);
+    assert_eq!(7.max_digit(), 7);
+    assert_eq!(7.min_digit(), 7);
+    assert_eq!(987.min_digit(), 7);
+    assert_eq!((-2026).max_digit(), 6);
+    assert_eq!((-2026).min_digit(), 0);
+    for n in [1u64, 10, 2026, 98_765, u64::MAX] {
+        assert_eq!(n.max_digit(), *n.digits().iter().max().unwrap());
+        assert_eq!(n.min_digit(), *n.digits().iter().min().unwrap());
+    }
+    assert_eq!(i8::MIN.max_digit(), 8);
+    assert_eq!(i8::MIN.min_digit(), 1);
+    assert_eq!(i64::MIN.max_digit(), 9);
+    assert_eq!(i64::MIN.min_digit(), 0);
+}
+
+#[test]
+fn test_max_min_digit_radix() {
+    assert_eq!(6.max_digit_radix(2), 1); // 110
+    assert_eq!(6.min_digit_radix(2), 0);
+    assert_eq!(7.min_digit_radix(2), 1); // 111
+    assert_eq!(0xa5c.max_digit_radix(16), 0xc);
+    assert_eq!(0xa5c.min_digit_radix(16), 5);
+    assert_eq!(0.min_digit_radix(16), 0);
+}
+
+#[test]
+fn test_distinct_digit_count() {
+    assert_eq!(112233.distinct_digit_count(), 3);
+    assert_eq!(123_456_789.distinct_digit_count(), 9);
+    assert_eq!(1_234_567_890u64.distinct_digit_count(), 10);
+    assert_eq!(7777.distinct_digit_count(), 1);
+    assert_eq!(0.distinct_digit_count(), 1);
+    assert_eq!(1000.distinct_digit_count(), 2);
+    assert_eq!((-112).distinct_digit_count(), 2);
+    assert_eq!(i8::MIN.distinct_digit_count(), 3);
+    assert_eq!(i32::MIN.distinct_digit_count(), 7); // 2147483648
+}
+
+#[test]
+fn test_distinct_digit_count_radix() {
+    assert_eq!(6.distinct_digit_count_radix(2), 2); // 110
+    assert_eq!(7.distinct_digit_count_radix(2), 1); // 111
+    assert_eq!(0.distinct_digit_count_radix(2), 1);
+    assert_eq!(0xfedc_ba98_7654_3210u64.distinct_digit_count_radix(16), 16);
+    assert_eq!(
+        u64::from_digits_radix(&[200, 1, 200, 130], 256).distinct_digit_count_radix(256),
+        3
+    );
+    assert_eq!((-6).distinct_digit_count_radix(2), 2);
+}
+
+#[test]
+fn test_is_repdigit() {
+    assert!(555.is_repdigit());
+    assert!(11111.is_repdigit());
+    assert!(!12321.is_repdigit());
+    assert!(!10.is_repdigit());
+    assert!((0..10).all(|n| n.is_repdigit()));
+    assert!((-777).is_repdigit());
+    assert!(!i8::MIN.is_repdigit());
+    assert!(!i128::MIN.is_repdigit());
+}
+
+#[test]
+fn test_is_repunit() {
+    assert!(1.is_repunit());
+    assert!(11111.is_repunit());
+    assert!(!12321.is_repunit());
+    assert!(!555.is_repunit());
+    assert!(!0.is_repunit());
+    assert!((-11).is_repunit());
+    assert!(!i8::MIN.is_repunit());
+    assert!(!i128::MIN.is_repunit());
+}
+
+#[test]
+fn test_repdigit_repunit_radix() {
+    assert!(7.is_repunit_radix(2)); // 111
+    assert!(15.is_repunit_radix(2)); // 1111
+    assert!(!5.is_repunit_radix(2)); // 101
+    assert!(13.is_repunit_radix(3)); // 111
+    assert!(0xfff.is_repdigit_radix(16));
+    assert!(!0xffe.is_repdigit_radix(16));
+    assert!(7.is_repdigit_radix(2));
+    assert!(!0.is_repunit_radix(2));
+}
+
+#[test]
+fn test_next_palindrome() {
+    assert_eq!(0.next_palindrome(), 1);
+    assert_eq!(5.next_palindrome(), 6);
+    assert_eq!(9.next_palindrome(), 11);
+    assert_eq!(10.next_palindrome(), 11);
+    assert_eq!(11.next_palindrome(), 22);
+    assert_eq!(123.next_palindrome(), 131);
+    assert_eq!(191.next_palindrome(), 202);
+    assert_eq!(999.next_palindrome(), 1001);
+    assert_eq!(1221.next_palindrome(), 1331);
+    assert_eq!(1991.next_palindrome(), 2002);
+    assert_eq!(12_921.next_palindrome(), 13_031);
+
+    // 総当たりと一致する
+    for n in 0..3000u32 {
+        let brute = (n + 1..).find(|m| m.is_palindrome()).unwrap();
+        assert_eq!(n.next_palindrome(), brute);
+    }
+    for n in -3000..0i32 {
+        let brute = (n + 1..).find(|m| m.is_palindrome()).unwrap();
+        assert_eq!(n.next_palindrome(), brute, "{}", n);
+    }
+}
+
+#[test]
+fn test_checked_next_palindrome() {
+    assert_eq!(200u8.checked_next_palindrome(), Some(202));
+    assert_eq!(251u8.checked_next_palindrome(), Some(252));
+    assert_eq!(252u8.checked_next_palindrome(), None);
+    assert_eq!(u8::MAX.checked_next_palindrome(), None);
+    assert_eq!(126i8.checked_next_palindrome(), None);
+    assert_eq!((-101i8).checked_next_palindrome(), Some(-99));
+    assert_eq!((u64::MAX - 1).checked_next_palindrome(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_next_palindrome_overflow() {
+    u8::MAX.next_palindrome();
+}
+
+#[test]
+fn test_next_palindrome_radix() {
+    assert_eq!(5.next_palindrome_radix(2), 7); // 101 -> 111
+    assert_eq!(7.next_palindrome_radix(2), 9); // 111 -> 1001
+    assert_eq!(0xfe.next_palindrome_radix(16), 0xff);
+    for n in 0..1000u32 {
+        let brute = (n + 1..).find(|m| m.is_palindrome_radix(3)).unwrap();
+        assert_eq!(n.next_palindrome_radix(3), brute);
+    }
+    assert_eq!(u8::MAX.checked_next_palindrome_radix(2), None);
+}
+
+#[test]
+fn test_digit_power_sum() {
+    assert_eq!(123.digit_power_sum(2), 14);
+    assert_eq!(0.digit_power_sum(3), 0);
+    assert_eq!((-123).digit_power_sum(2), 14);
+    // exp = 0 は桁数 (0^0 = 1 として)
+    assert_eq!(1020.digit_power_sum(0), 4);
+    for n in [0u64, 7, 123, 98_765, u64::MAX] {
+        assert_eq!(n.digit_power_sum(1), n.digit_sum() as u128);
+        assert_eq!(n.digit_power_sum(2), n.digit_square_sum() as u128);
+    }
+    // 9474 = 9^4 + 4^4 + 7^4 + 4^4
+    assert_eq!(9474.digit_power_sum(4), 9474);
+    // 大きな指数でもパニックしない
+    assert_eq!(99.digit_power_sum(40), 2 * 9u128.pow(40));
+    assert_eq!(99.digit_power_sum(41), u128::MAX);
+    assert_eq!(i8::MIN.digit_power_sum(3), 521); // 1 + 8 + 512
+    assert_eq!(i128::MIN.digit_power_sum(1), i128::MIN.digit_sum() as u128);
+}
+
+#[test]
+fn test_digit_power_sum_radix() {
+    assert_eq!(6.digit_power_sum_radix(5, 2), 2); // 110
+    assert_eq!(0xff.digit_power_sum_radix(2, 16), 450);
+}
+
+#[test]
+fn test_checked_reverse() {
+    assert_eq!(123.checked_reverse(), Some(321));
+    assert_eq!((-123).checked_reverse(), Some(-321));
+    assert_eq!(1200.checked_reverse(), Some(21));
+    assert_eq!(0.checked_reverse(), Some(0));
+
+    // i32 の境界
+    assert_eq!(1_463_847_412i32.checked_reverse(), Some(2_147_483_641));
+    assert_eq!((-1_463_847_412i32).checked_reverse(), Some(-2_147_483_641));
+    assert_eq!(1_534_236_469i32.checked_reverse(), None);
+    assert_eq!(i32::MAX.checked_reverse(), None);
+    assert_eq!(i32::MIN.checked_reverse(), None);
+    assert_eq!(u8::MAX.checked_reverse(), None);
+    assert_eq!(152u8.checked_reverse(), Some(251));
+}
+
+#[test]
+fn test_saturating_reverse() {
+    assert_eq!(123.saturating_reverse(), 321);
+    assert_eq!(1_534_236_469i32.saturating_reverse(), i32::MAX);
+    assert_eq!((-1_534_236_469i32).saturating_reverse(), i32::MIN);
+    assert_eq!(i32::MIN.saturating_reverse(), i32::MIN);
+    assert_eq!(199u8.saturating_reverse(), u8::MAX);
+}
+
+#[test]
+fn test_wrapping_reverse() {
+    assert_eq!(123.wrapping_reverse(), 321);
+    assert_eq!(199u8.wrapping_reverse(), (991u32 % 256) as u8);
+    assert_eq!(1_534_236_469i32.wrapping_reverse(), 9_646_324_351i64 as i32);
+    assert_eq!(i32::MIN.wrapping_reverse(), -8_463_847_412i64 as i32);
+    assert_eq!(i32::MAX.wrapping_reverse(), 7_463_847_412i64 as i32);
+}
+
+#[test]
+fn test_checked_reverse_radix() {
+    assert_eq!(6.checked_reverse_radix(2), Some(3)); // 110 -> 011
+    assert_eq!(0x1f.checked_reverse_radix(16), Some(0xf1));
+    assert_eq!(0x1fu8.checked_reverse_radix(16), Some(0xf1));
+    assert_eq!(0x1fi8.checked_reverse_radix(16), None);
+    assert_eq!(0x1fi8.saturating_reverse_radix(16), i8::MAX);
+    assert_eq!(0x1fi8.wrapping_reverse_radix(16), 0xf1u8 as i8);
+    assert_eq!(i8::MIN.checked_reverse_radix(2), Some(-1)); // -10000000 -> -1
+}
+
+#[test]
+fn test_cst_matches_trait() {
+    use keta::cst;
+
+    const SUM: u64 = cst::digit_sum_u64(12345);
+    const REV: u64 = cst::reverse_u64(1200);
+    const LEN: u32 = cst::digits_len_u64(u64::MAX);
+    assert_eq!(SUM, 15);
+    assert_eq!(REV, 21);
+    assert_eq!(LEN, 20);
+
+    let samples = (0..10_000u64).chain([
+        99_999,
+        1_000_000,
+        1_234_567_890,
+        u32::MAX as u64,
+        u64::MAX / 10,
+        u64::MAX,
+    ]);
+    for n in samples {
+        assert_eq!(cst::digit_sum_u64(n), n.digit_sum());
+        assert_eq!(cst::digit_product_u64(n), n.digit_product());
+        assert_eq!(cst::digits_len_u64(n), n.digits_len());
+        // 反転がオーバーフローしない範囲
+        if n <= u64::MAX / 10 {
+            assert_eq!(cst::reverse_u64(n), n.reverse());
+            assert_eq!(cst::is_palindrome_u64(n), n.is_palindrome());
+        }
+    }
+}
+
+#[cfg(feature = "bigint")]
+#[test]
+fn test_keta_big() {
+    use keta::KetaBig;
+    use num_bigint::{BigInt, BigUint};
+
+    // Project Euler 20: 100! の桁和
+    let fact = (1..=100u32).fold(BigUint::from(1u32), |acc, k| acc * k);
+    assert_eq!(fact.digit_sum(), 648);
+    assert_eq!(fact.digits_len(), 158);
+    // Project Euler 16: 2^1000 の桁和
+    assert_eq!(BigUint::from(2u32).pow(1000).digit_sum(), 1366);
+
+    // u128 に収まる範囲では Keta と一致する
+    for n in [
+        0u128,
+        7,
+        1200,
+        2026,
+        12321,
+        u64::MAX as u128,
+        u128::MAX / 10,
+    ] {
+        let b = BigUint::from(n);
+        assert_eq!(b.digits(), n.digits());
+        assert_eq!(b.digit_sum(), n.digit_sum());
+        assert_eq!(b.digits_len(), n.digits_len() as u64);
+        assert_eq!(b.reverse(), BigUint::from(n.reverse()));
+        assert_eq!(b.is_palindrome(), n.is_palindrome());
+        assert_eq!(b.make_max(), BigUint::from(n.make_max()));
+        assert_eq!(b.make_min(), BigUint::from(n.make_min()));
+        assert_eq!(b.digits_radix(16), n.digits_radix(16));
+        assert_eq!(BigUint::from_digits(&n.digits()), b);
+    }
+    for n in [-2026i64, -121, -1, 0, 1200] {
+        let b = BigInt::from(n);
+        assert_eq!(b.digits(), n.digits());
+        assert_eq!(b.reverse(), BigInt::from(n.reverse()));
+        assert_eq!(b.is_palindrome(), n.is_palindrome());
+        assert_eq!(b.make_max(), BigInt::from(n.make_max()));
+        assert_eq!(b.make_min(), BigInt::from(n.make_min()));
+    }
+
+    // u128 を超える回文
+    let big = BigUint::from_digits(&[1; 50]);
+    assert!(big.is_palindrome());
+    assert_eq!(big.reverse(), big);
+}
+
+#[test]
+fn test_based() {
+    use keta::based::Based;
+
+    let x = Based::<_, 16>::new(255u32);
+    assert_eq!(x.digits(), 255u32.digits_radix(16));
+    assert_eq!(x.digit_sum(), 30);
+    assert_eq!(x.digits_len(), 2);
+    assert!(x.is_palindrome());
+    assert_eq!(x.get(), 255);
+
+    let y: Based<i64, 2> = Based::from(-6);
+    assert_eq!(y.reverse().get(), -3);
+    assert_eq!(y.make_max().get(), -3); // -110 -> -011
+    assert_eq!(y.concat(Based::new(1)).get(), -13);
+    assert_eq!(y.nth_digit(1), Some(1));
+    assert_eq!(y.count_digit(1), 2);
+    assert_eq!(Based::<u8, 10>::from_digits(&[2, 5, 5]).get(), 255);
+}
+
+#[test]
+fn test_radix_wider_than_type() {
+    use keta::based::Based;
+
+    // 基数が型に収まらなければ、どの値も1桁として扱う
+    assert_eq!(100u8.digit_sum_radix(256), 100);
+    assert_eq!(100u8.digits_len_radix(256), 1);
+    assert_eq!(100u8.reverse_radix(256), 100);
+    assert_eq!(100u8.make_max_radix(256), 100);
+    assert!(100u8.contains_digit_radix(100, 256));
+    assert_eq!(100u8.count_digit_radix(7, 256), 0);
+    assert_eq!(100u8.nth_digit_from_right_radix(0, 256), Some(100));
+    assert_eq!(100u8.max_digit_radix(256), 100);
+    assert!(100u8.is_palindrome_radix(256));
+    assert!(!100u8.is_bouncy_radix(256));
+    assert_eq!(100u8.rotate_digits_left_radix(3, 256), 100);
+    assert_eq!(100u8.digit_slice_radix(0, 1, 256), 100);
+    assert_eq!(100u8.with_digit_radix(0, 7, 256), 7);
+    assert_eq!(100u8.map_digits_radix(|d| 255 - d, 256), 155);
+    assert_eq!(100u8.add_with_carry(200, 1, 256), Some((44, true)));
+    assert_eq!(100u8.sub_with_borrow(200, 1, 256), Some((156, true)));
+    assert_eq!(1000u16.digit_sum_radix(70000), 1000);
+    assert_eq!(u8::from_digits_radix(&[0, 5], 256), 5);
+    assert_eq!(u8::concat_many(&[0, 5], 256), 5);
+    assert_eq!(u8::checked_concat_many(&[1, 5], 256), None);
+    assert_eq!(5u8.checked_next_palindrome_radix(256), Some(6));
+    #[cfg(feature = "alloc")]
+    assert_eq!(5u8.digit_counts_radix(256)[5], 1);
+
+    let x = Based::<u8, 256>::new(5);
+    assert_eq!(x.digit_sum(), 5);
+    assert_eq!(x.digits_len(), 1);
+    assert_eq!(x.reverse().get(), 5);
+
+    // i8 では 128 以上の基数が収まらないが、|MIN| = 128 は 128 進数で2桁
+    assert_eq!((-100i8).digit_sum_radix(200), 100);
+    assert_eq!(i8::MIN.digit_sum_radix(200), 128);
+    assert_eq!(i8::MIN.digits_len_radix(128), 2);
+    assert_eq!(i8::MIN.digit_sum_radix(128), 1);
+    assert_eq!(i8::MIN.make_max_radix(128), -1); // -10 -> -01
+    assert_eq!(i8::MIN.reverse_radix(128), -1);
+    assert_eq!((-100i8).split_at_radix(1, 200), (-100, 0));
+    assert_eq!(i8::concat_many(&[-1, 0], 128), i8::MIN);
+    assert_eq!(i8::checked_concat_many(&[1, 0], 128), None);
+}
+
+#[test]
+fn test_kaprekar() {
+    assert_eq!(3524.kaprekar_step(), 3087);
+    assert_eq!(999.kaprekar_step(), 8991); // 9990 - 0999
+    assert_eq!(1000.kaprekar_step(), 999); // 1000 - 0001
+    assert_eq!(1.kaprekar_step(), 999); // 1000 - 0001
+    assert_eq!(6174.kaprekar_step(), 6174);
+    assert_eq!((-3524).kaprekar_step(), 3087);
+    assert_eq!(5555.kaprekar_step(), 0);
+    assert_eq!(3524.kaprekar_iterations(), Some(3));
+    assert_eq!(6174.kaprekar_iterations(), Some(0));
+    assert_eq!(0.kaprekar_iterations(), None);
+    assert_eq!(7777.kaprekar_iterations(), None);
+    assert_eq!(12345.kaprekar_iterations(), None);
+    // ゾロ目以外の 4 桁以下の数は 7 回以内に 6174 に到達する
+    for n in 0..10000u32 {
+        let repdigit = n % 1111 == 0;
+        match n.kaprekar_iterations() {
+            Some(k) => assert!(!repdigit && k <= 7, "{}", n),
+            None => assert!(repdigit, "{}", n),
+        }
+    }
+}
+
+#[test]
+fn test_digit_cmp() {
+    use std::cmp::Ordering;
+
+    assert_eq!(91.digit_cmp(19), Ordering::Greater);
+    assert_eq!(19.digit_cmp(91), Ordering::Less);
+    assert_eq!(9.digit_cmp(10), Ordering::Less);
+    assert_eq!(0.digit_cmp(0), Ordering::Equal);
+    assert_eq!((-91).digit_cmp(19), Ordering::Greater);
+    assert_eq!(0b100.digit_cmp_radix(0b11, 2), Ordering::Greater);
+
+    // 91 と 19 は桁和が同じなので数値で比べる
+    assert_eq!(91.cmp_by_digit_sum(19), Ordering::Greater);
+    assert_eq!(19.cmp_by_digit_sum(91), Ordering::Less);
+    assert_eq!(100.cmp_by_digit_sum(9), Ordering::Less);
+    assert_eq!(0b111.cmp_by_digit_sum_radix(0b1000, 2), Ordering::Greater);
+    let mut v = vec![91, 19, 100, 5, 28, -19];
+    v.sort_by(|a, b| a.cmp_by_digit_sum(*b));
+    assert_eq!(v, vec![100, 5, -19, 19, 28, 91]);
+}
+
+#[test]
+fn test_sum_of_digit_sums() {
+    let naive: u64 = (0..100_000u32).map(|n| n.digit_sum()).sum();
+    assert_eq!(u32::sum_of_digit_sums(0..100_000u32), naive);
+    // 0..10^5 の各桁には 0..=9 が 10^4 回ずつ現れる
+    assert_eq!(naive, 5 * 45 * 10_000);
+    assert_eq!(i32::sum_of_digit_sums(-99..=99), 2 * 900);
+    assert_eq!(u8::sum_of_digit_sums(std::iter::empty()), 0);
+}
+
+#[test]
+fn test_digit_sum_batch() {
+    use keta::digit_sum_batch;
+
+    let mut input: Vec<u32> = (0..200_000).collect();
+    input.extend((0..100_000u32).map(|i| i.wrapping_mul(2_654_435_761)));
+    input.extend([
+        99_999_999,
+        100_000_000,
+        999_999_999,
+        1_000_000_000,
+        u32::MAX,
+    ]);
+    let mut out = vec![0; input.len()];
+    digit_sum_batch(&input, &mut out);
+    for (&n, &s) in input.iter().zip(&out) {
+        assert_eq!(s, n.digit_sum(), "{}", n);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_digit_sum_batch_length_mismatch() {
+    keta::digit_sum_batch(&[1, 2], &mut [0]);
+}
+
+#[test]
+fn test_keta_non_zero() {
+    use keta::KetaNonZero;
+    use std::num::{NonZeroI32, NonZeroU64, NonZeroU8};
+
+    let n = NonZeroU64::new(2001).unwrap();
+    assert_eq!(n.digits(), vec![2, 0, 0, 1]);
+    assert_eq!(n.digit_sum(), 3);
+    assert_eq!(n.digits_len(), 4);
+    assert_eq!(n.reverse().get(), 1002);
+    assert_eq!(n.make_max().get(), 2100);
+    assert_eq!(n.make_min().get(), 12);
+    assert_eq!(n.count_digit(0), 2);
+    assert_eq!(NonZeroU64::new(100).unwrap().reverse().get(), 1);
+    assert_eq!(NonZeroU8::from_digits(&[2, 5, 5]), NonZeroU8::new(255));
+    assert_eq!(NonZeroU8::from_digits(&[0]), None);
+    assert_eq!(NonZeroU8::from_digits_radix(&[1, 0], 2), NonZeroU8::new(2));
+
+    let m = NonZeroI32::new(-120).unwrap();
+    assert_eq!(m.reverse().get(), -21);
+    assert_eq!(m.make_max().get(), -12);
+    assert_eq!(m.make_min().get(), -210);
+    assert_eq!(m.digit_sum_radix(2), (-120i32).digit_sum_radix(2));
+    assert!(NonZeroI32::new(-121).unwrap().is_palindrome());
+}
+
+#[test]
+fn test_is_munchausen() {
+    assert!(3435.is_munchausen());
+    assert!(438579088u64.is_munchausen());
+    assert!(1.is_munchausen());
+    // 0^0 = 0 とみなす
+    assert!(0.is_munchausen());
+    assert!(!3436.is_munchausen());
+    assert!(!(-3435).is_munchausen());
+    assert!(!u128::MAX.is_munchausen());
+    let found: Vec<u32> = (0..10_000).filter(|n: &u32| n.is_munchausen()).collect();
+    assert_eq!(found, vec![0, 1, 3435]);
+    // 2進数では 0 と 1 だけ、3進数では 12 (= 5 = 1^1 + 2^2) も該当する
+    assert!(0b1.is_munchausen_radix(2));
+    assert!(!0b10.is_munchausen_radix(2));
+    assert!(5.is_munchausen_radix(3));
+}
+
+#[test]
+fn test_sort_digits() {
+    assert_eq!(2001.sort_digits(true), vec![0, 0, 1, 2]);
+    assert_eq!(2001.sort_digits(false), vec![2, 1, 0, 0]);
+    assert_eq!(0.sort_digits(true), vec![0]);
+    assert_eq!((-312).sort_digits(true), vec![1, 2, 3]);
+    assert_eq!(0b1010.sort_digits_radix(true, 2), vec![0, 0, 1, 1]);
+    // 降順の並びは make_max と一致する
+    assert_eq!(
+        u32::from_digits(&2001u32.sort_digits(false)),
+        2001u32.make_max()
+    );
+}
+
+#[test]
+fn test_digit_sum_mod() {
+    assert_eq!(12345.digit_sum_mod(7), 1);
+    assert_eq!(12345.digit_sum_mod(1), 0);
+    assert_eq!((-12345).digit_sum_mod(100), 15);
+    assert_eq!(u128::MAX.digit_sum_mod(1000), u128::MAX.digit_sum() % 1000);
+    assert_eq!(0b1111.digit_sum_mod_radix(3, 2), 1);
+
+    assert_eq!(0.casting_out_nines(), 0);
+    assert_eq!(9.casting_out_nines(), 9);
+    assert_eq!(18.casting_out_nines(), 9);
+    assert_eq!(12345.casting_out_nines(), 6);
+    assert_eq!((-12345).casting_out_nines(), 6);
+    for n in 1..1000u32 {
+        // 1 桁になるまで桁和を取った結果と一致する
+        let mut r = n as u64;
+        while r >= 10 {
+            r = r.digit_sum();
+        }
+        assert_eq!(n.casting_out_nines() as u64, r);
+    }
+    assert_eq!(
+        i64::MIN.casting_out_nines(),
+        i64::MIN.unsigned_abs().casting_out_nines()
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_digit_sum_mod_zero() {
+    123.digit_sum_mod(0);
+}
+
+#[test]
+fn test_next_with_digit_sum() {
+    assert_eq!(19.next_with_digit_sum(), 28);
+    assert_eq!(28.next_with_digit_sum(), 37);
+    assert_eq!(91.next_with_digit_sum(), 109);
+    assert_eq!(1.next_with_digit_sum(), 10);
+    assert_eq!(999.next_with_digit_sum(), 1899);
+    // 総当たりと一致する
+    for n in 1..3000u32 {
+        let s = n.digit_sum();
+        let brute = (n + 1..).find(|m| m.digit_sum() == s).unwrap();
+        assert_eq!(n.next_with_digit_sum(), brute, "{}", n);
+        assert_eq!(
+            n.next_with_digit_sum_radix(3),
+            (n + 1..)
+                .find(|m| m.digit_sum_radix(3) == n.digit_sum_radix(3))
+                .unwrap()
+        );
+    }
+    // 負の数は絶対値が小さくなる側、候補がなければ 0 をまたぐ
+    for n in -3000..0i32 {
+        let s = n.digit_sum();
+        let brute = (n + 1..).find(|m| m.digit_sum() == s).unwrap();
+        assert_eq!(n.next_with_digit_sum(), brute, "{}", n);
+    }
+    assert_eq!(0.checked_next_with_digit_sum(), None);
+    assert_eq!(253u8.checked_next_with_digit_sum(), None);
+    assert_eq!(u64::MAX.checked_next_with_digit_sum(), None);
+    assert_eq!(i8::MIN.checked_next_with_digit_sum(), Some(-119));
+}
+
+#[test]
+fn test_is_smith() {
+    assert!(4.is_smith());
+    assert!(22.is_smith());
+    assert!(27.is_smith()); // 3 * 3 * 3
+    assert!(4937775u32.is_smith());
+    assert!(!7.is_smith());
+    assert!(!1.is_smith());
+    assert!(!0.is_smith());
+    assert!(!(-4).is_smith());
+    assert!(!10.is_smith());
+    // OEIS A006753
+    let smith: Vec<u32> = (0..200).filter(|n: &u32| n.is_smith()).collect();
+    assert_eq!(smith, vec![4, 22, 27, 58, 85, 94, 121, 166]);
+}
+
+#[test]
+fn test_reverse_add_steps() {
+    assert_eq!(56.reverse_add_steps(10), Some(1));
+    assert_eq!(57.reverse_add_steps(10), Some(2)); // 57 + 75 = 132, 132 + 231 = 363
+    assert_eq!(121.reverse_add_steps(10), Some(1)); // 121 + 121 = 242
+    assert_eq!((-56).reverse_add_steps(10), Some(1));
+    assert_eq!(89u128.reverse_add_steps(100), Some(24));
+    assert_eq!(89u128.reverse_add_steps(23), None);
+    // 196 は回文にならないまま u128 の範囲を超える
+    assert_eq!(196u128.reverse_add_steps(30), None);
+    assert_eq!(196u128.reverse_add_steps(1000), None);
+    // u32 では途中で溢れる
+    assert_eq!(89u32.reverse_add_steps(100), None);
+}
+
+#[test]
+fn test_popcount_bit_len() {
+    assert_eq!(0.popcount(), 0);
+    assert_eq!(0.bit_len(), 1);
+    assert_eq!(0b1011.popcount(), 3);
+    assert_eq!(0b1011.bit_len(), 4);
+    assert_eq!(u128::MAX.popcount(), 128);
+    assert_eq!(u128::MAX.bit_len(), 128);
+    assert_eq!(i8::MIN.popcount(), 1); // -10000000
+    assert_eq!(i8::MIN.bit_len(), 8);
+    // 汎用の n進数メソッドと一致する
+    for n in -1000..=1000i32 {
+        assert_eq!(n.popcount() as u64, n.digit_sum_radix(2));
+        assert_eq!(n.bit_len(), n.digits_len_radix(2));
+    }
+    for n in (0..64).map(|k| 1u64 << k).chain([u64::MAX]) {
+        assert_eq!(n.popcount() as u64, n.digit_sum_radix(2));
+        assert_eq!(n.bit_len(), n.digits_len_radix(2));
+    }
+}
+
+#[test]
+fn test_gray() {
+    use keta::KetaUnsigned;
+
+    let g: Vec<u32> = (0..8u32).map(|n| n.to_gray()).collect();
+    assert_eq!(g, vec![0, 1, 3, 2, 6, 7, 5, 4]);
+    for n in 0..=u16::MAX {
+        assert_eq!(n.to_gray().from_gray(), n);
+        // 隣り合う数のグレイコードは 1 ビットだけ異なる
+        if n > 0 {
+            assert_eq!((n.to_gray() ^ (n - 1).to_gray()).count_ones(), 1);
+        }
+    }
+    for n in [u128::MAX, u128::MAX / 3, 1 << 127] {
+        assert_eq!(n.to_gray().from_gray(), n);
+        assert_eq!(n.from_gray().to_gray(), n);
+    }
+}
+
+#[test]
+fn test_weighted_digit_sum() {
+    // 重みが常に 1 なら digit_sum と一致する
+    assert_eq!(12345.weighted_digit_sum(|_, d| d as i64), 15);
+    // 位置は最下位の桁から数える
+    assert_eq!(123.weighted_digit_sum(|i, d| i as i64 * d as i64), 2 + 2);
+    assert_eq!(0.weighted_digit_sum(|i, _| i as i64 + 7), 7);
+    assert_eq!((-12).weighted_digit_sum(|_, d| d as i64), 3);
+
+    // ISBN-10: Σ (i + 1) * d ≡ 0 (mod 11)
+    let isbn10 = |n: u64| n.weighted_digit_sum(|i, d| (i as i64 + 1) * d as i64) % 11 == 0;
+    assert!(isbn10(306406152)); // 0-306-40615-2
+    assert!(isbn10(4_873_113_687)); // 4-87311-368-7
+    assert!(!isbn10(306406153));
+
+    // Luhn: 下から奇数番目の桁を 2 倍し、9 を超えたら 9 を引く
+    let luhn = |n: u64| {
+        n.weighted_digit_sum(|i, d| {
+            let d = d as i64;
+            if i % 2 == 1 {
+                if d * 2 > 9 {
+                    d * 2 - 9
+                } else {
+                    d * 2
+                }
+            } else {
+                d
+            }
+        }) % 10
+            == 0
+    };
+    assert_eq!(luhn(79927398713), 79927398713u64.luhn_is_valid());
+    assert_eq!(luhn(79927398710), 79927398710u64.luhn_is_valid());
+
+    assert_eq!(
+        0b1011.weighted_digit_sum_radix(|i, d| (d as i64) << i, 2),
+        0b1011
+    );
+}
+
+#[test]
+#[should_panic(expected = "concatenated value overflows the type")]
+fn test_concat_overflow() {
+    let _ = 25u8.concat(6);
+}
+
+#[test]
+#[should_panic(expected = "concatenated value overflows the type")]
+fn test_concat_radix_overflow() {
+    let _ = 0xffu8.concat_radix(0, 16);
+}
+
+#[test]
+#[should_panic(expected = "concatenated value overflows the type")]
+fn test_concat_signed_overflow() {
+    let _ = (-12i8).concat(9);
+}
+
+#[test]
+fn test_checked_concat() {
+    use keta::DigitError;
+
+    assert_eq!(12.checked_concat(34), Some(1234));
+    assert_eq!((-12).checked_concat(34), Some(-1234));
+    assert_eq!(12u8.checked_concat(3), Some(123));
+    assert_eq!(25u8.checked_concat(6), None);
+    assert_eq!(u64::MAX.checked_concat(0), None);
+    assert_eq!((-12i8).checked_concat(8), Some(-128));
+    assert_eq!((-12i8).checked_concat(9), None);
+
+    // u8 の 16進数: 0xff の次の桁は入らない
+    assert_eq!(0xfu8.checked_concat_radix(0xf, 16), Some(0xff));
+    assert_eq!(0xffu8.checked_concat_radix(0, 16), None);
+    assert_eq!(1u8.try_concat_radix(0xff, 16), Err(DigitError::Overflow)); // 16^2 = 256
+    assert_eq!(
+        0u8.try_concat_radix(1, 256),
+        Err(DigitError::InvalidRadix(256))
+    );
+    assert_eq!(
+        DigitError::Overflow.to_string(),
+        "result too large to fit in target type"
+    );
+
+    // nth_digit_radix は型の上限付近でも溢れない
+    assert_eq!(0xffu8.nth_digit_radix(0, 16), Some(0xf));
+    assert_eq!(0xffu8.nth_digit_radix(1, 16), Some(0xf));
+    assert_eq!(0xffu8.nth_digit_radix(2, 16), None);
+    assert_eq!(0xffu8.nth_digit_radix(0, 256), Some(0xff)); // 基数が型に収まらなければ1桁
+    assert_eq!(0xffu8.nth_digit_radix(1, 256), None);
+    assert_eq!(0x80u8.nth_digit_radix(0, 16), Some(8));
+    assert_eq!(i8::MIN.wrapping_add(1).nth_digit_radix(0, 16), Some(7));
+}
+
+#[test]
+fn test_ascii_digits() {
+    use keta::{ascii_digits_from_value, value_from_ascii_digits};
+
+    // 大文字小文字が混ざった16進数
+    assert_eq!(value_from_ascii_digits("DeadBeef", 16), Some(0xdeadbeef));
+    assert_eq!(value_from_ascii_digits("ff", 16), Some(255));
+    assert_eq!(value_from_ascii_digits("0042", 10), Some(42));
+    // 数字でない文字・範囲外の数字・符号・空文字列
+    assert_eq!(value_from_ascii_digits("12x", 10), None);
+    assert_eq!(value_from_ascii_digits("12", 2), None);
+    assert_eq!(value_from_ascii_digits("-1", 10), None);
+    assert_eq!(value_from_ascii_digits("", 10), None);
+    // u64 に収まらない
+    assert_eq!(
+        value_from_ascii_digits("18446744073709551615", 10),
+        Some(u64::MAX)
+    );
+    assert_eq!(value_from_ascii_digits("18446744073709551616", 10), None);
+
+    assert_eq!(ascii_digits_from_value(0xdeadbeef, 16), "deadbeef");
+    assert_eq!(ascii_digits_from_value(0, 2), "0");
+    for n in [0, 1, 35, 36, 12345, u64::MAX] {
+        for base in [2, 10, 16, 36] {
+            assert_eq!(
+                value_from_ascii_digits(&ascii_digits_from_value(n, base), base),
+                Some(n)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_monotonic_digits() {
+    assert!(134468.has_increasing_digits());
+    assert!(!134468.has_decreasing_digits());
+    assert!(66420.has_decreasing_digits());
+    assert!(!66420.has_increasing_digits());
+    assert!(!155349.has_increasing_digits());
+    assert!(!155349.has_decreasing_digits());
+    // 1桁・ゾロ目は両方
+    for n in [0, 7, 5555] {
+        assert!(n.has_increasing_digits() && n.has_decreasing_digits());
+    }
+    assert!((-1359).has_increasing_digits());
+    assert!(0b0011.has_increasing_digits_radix(2));
+    assert!(!0b101.has_increasing_digits_radix(2));
+    assert!(0b1100.has_decreasing_digits_radix(2));
+    // 数字列で判定した結果と一致する
+    for n in 0..5000u32 {
+        let d = n.digits();
+        assert_eq!(
+            n.has_increasing_digits(),
+            d.windows(2).all(|w| w[0] <= w[1])
+        );
+        assert_eq!(
+            n.has_decreasing_digits(),
+            d.windows(2).all(|w| w[0] >= w[1])
+        );
+    }
+}
+
+#[test]
+fn test_is_stepping() {
+    assert!(4565.is_stepping());
+    assert!(7.is_stepping());
+    assert!(123.is_stepping());
+    assert!(321.is_stepping());
+    assert!(545.is_stepping());
+    assert!(!14.is_stepping());
+    assert!(!11.is_stepping());
+    assert!((-101).is_stepping());
+    assert!(0b1010.is_stepping_radix(2));
+    assert!(!0b110.is_stepping_radix(2));
+}
+
+#[test]
+fn test_stepping_numbers_in_range() {
+    use keta::stepping_numbers_in_range;
+
+    // 全数判定の結果と一致する
+    let expected: Vec<u64> = (50..=2000u64).filter(|n| n.is_stepping()).collect();
+    assert_eq!(stepping_numbers_in_range(50, 2000), expected);
+    assert_eq!(stepping_numbers_in_range(0, 0), vec![0]);
+    assert!(stepping_numbers_in_range(10, 5).is_empty());
+    // u64 の上限付近でもオーバーフローしない
+    let big = stepping_numbers_in_range(u64::MAX - 1_000_000_000_000_000_000, u64::MAX);
+    assert!(big.iter().all(|n| n.is_stepping()));
+    assert!(big.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn test_is_palindrome_padded() {
+    assert!(!10.is_palindrome_padded(4));
+    assert!(1001.is_palindrome_padded(4));
+    assert!(10.is_palindrome_padded(3));
+    assert!(0.is_palindrome_padded(5));
+    assert!((-1001).is_palindrome_padded(4));
+    // 幅が桁数と等しければ is_palindrome と一致する
+    for n in 0..2000u32 {
+        assert_eq!(
+            n.is_palindrome_padded(n.digits_len() as usize),
+            n.is_palindrome()
+        );
+    }
+    assert!(0b0110.is_palindrome_padded_radix(4, 2));
+    assert!(!0b0110.is_palindrome_padded_radix(5, 2));
+}
+
+#[test]
+#[should_panic(expected = "do not fit in width")]
+fn test_is_palindrome_padded_too_narrow() {
+    12345.is_palindrome_padded(4);
+}
+
+#[test]
+fn test_digit_prefix_sums() {
+    assert_eq!(123.digit_prefix_sums(), vec![1, 3, 6]);
+    assert_eq!(102.digit_prefix_sums(), vec![1, 1, 3]);
+    assert_eq!(0.digit_prefix_sums(), vec![0]);
+    assert_eq!((-123).digit_prefix_sums(), vec![1, 3, 6]);
+    assert_eq!(0b1011.digit_prefix_sums_radix(2), vec![1, 1, 2, 3]);
+    for n in [9u64, 1000, 987654321, u64::MAX] {
+        assert_eq!(*n.digit_prefix_sums().last().unwrap(), n.digit_sum());
+    }
+}
+
+#[test]
+fn test_ops_free_functions() {
+    use keta::ops;
+
+    for n in [0u64, 7, 1230, 987654321] {
+        assert_eq!(ops::digit_sum(n), n.digit_sum());
+        assert_eq!(ops::reverse(n), n.reverse());
+        assert_eq!(ops::digits_radix(n, 16), n.digits_radix(16));
+    }
+    assert_eq!(ops::make_max(-2019i32), (-2019i32).make_max());
+    assert_eq!(ops::from_digits::<u16>(&[6, 5, 5, 3, 5]), 65535);
+    assert_eq!(ops::sum_of_digit_sums([19u8, 28, 37]), 30);
+    assert_eq!(ops::from_gray(ops::to_gray(1234u32)), 1234);
+    let lens: Vec<u32> = [1i64, -10, 100].into_iter().map(ops::digits_len).collect();
+    assert_eq!(lens, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_checked_make_max_min() {
+    assert_eq!(1_000_000_002i32.checked_make_max(), Some(2_100_000_000));
+    assert_eq!(1_999_999_999i32.checked_make_max(), None);
+    assert_eq!(i32::MAX.checked_make_max(), None);
+    assert_eq!(i32::MAX.checked_make_min(), Some(1_234_446_778));
+    assert_eq!((-1_999_999_999i32).checked_make_min(), None);
+    assert_eq!(i32::MIN.checked_make_max(), Some(-1_234_446_788));
+    assert_eq!(i32::MIN.checked_make_min(), None);
+    assert_eq!(199u8.checked_make_max(), None);
+    assert_eq!(0u8.checked_make_max(), Some(0));
+    assert_eq!(250u8.checked_make_max_radix(256), Some(250));
+    // 溢れない範囲では make_max / make_min と一致する
+    for n in -3000i16..3000 {
+        assert_eq!(n.checked_make_max(), Some(n.make_max()));
+        assert_eq!(n.checked_make_min(), Some(n.make_min()));
+        assert_eq!(n.checked_make_max_radix(3), Some(n.make_max_radix(3)));
+    }
+}
+
+#[test]
+fn test_count_with_digit_sum() {
+    // 全数を数えた結果と一致する
+    for limit in [0u32, 9, 10, 100, 999, 1000, 4321] {
+        for target in 0..40 {
+            let brute = (0..=limit).filter(|n| n.digit_sum() == target).count() as u64;
+            assert_eq!(u32::count_with_digit_sum(limit, target), brute);
+        }
+    }
+    assert_eq!(i32::count_with_digit_sum(-5, 0), 0);
+    assert_eq!(i32::count_with_digit_sum(100, 1), 3);
+    assert_eq!(u64::count_with_digit_sum(u64::MAX, 1), 20);
+    assert_eq!(u64::count_with_digit_sum(u64::MAX, 1000), 0);
+    // 10^38 以下で和が 1 の数は 39 個
+    assert_eq!(u128::count_with_digit_sum(10u128.pow(38), 1), 39);
+}
+
+#[test]
+fn test_bcd() {
+    assert_eq!(12345.to_bcd(), vec![0x01, 0x23, 0x45]);
+    assert_eq!(u32::from_bcd(&[0x01, 0x23, 0x45]), 12345);
+    assert_eq!(0.to_bcd(), vec![0x00]);
+    assert_eq!((-99).to_bcd(), vec![0x99]);
+    assert_eq!(u64::MAX.to_bcd().len(), 10);
+    for n in [0u64, 7, 10, 99, 100, 2024, 12345, u64::MAX] {
+        assert_eq!(u64::from_bcd(&n.to_bcd()), n);
+    }
+}
+
+#[test]
+#[should_panic(expected = "invalid BCD nibble")]
+fn test_bcd_invalid_nibble() {
+    u32::from_bcd(&[0x1a]);
+}
+
+#[test]
+fn test_fold_digits() {
+    for n in [0u64, 5, 10, 1234, 9_876_543_210, u64::MAX] {
+        assert_eq!(n.fold_digits(0, |acc, d| acc + d as u64), n.digit_sum());
+        assert_eq!(n.fold_digits(0u64, |acc, d| acc * 10 + d as u64), n);
+        assert_eq!(
+            n.fold_digits(Vec::new(), |mut v, d| {
+                v.push(d);
+                v
+            }),
+            n.digits()
+        );
+        assert_eq!(
+            n.fold_digits_radix(0, |acc, d| acc + d as u64, 7),
+            n.digit_sum_radix(7)
+        );
+    }
+    assert_eq!((-1203i32).fold_digits(1u64, |acc, d| acc * d as u64), 0);
+    assert_eq!(
+        0xabcu32.fold_digits_radix(
+            Vec::new(),
+            |mut v, d| {
+                v.push(d);
+                v
+            },
+            16
+        ),
+        vec![10, 11, 12]
+    );
+}
+
+#[test]
+fn test_as_single_digit() {
+    assert_eq!(7.as_single_digit(), Some(7));
+    assert_eq!(0.as_single_digit(), Some(0));
+    assert_eq!(10.as_single_digit(), None);
+    assert_eq!((-9i8).as_single_digit(), Some(9));
+    assert_eq!(i8::MIN.as_single_digit(), None);
+    assert_eq!(255u8.as_single_digit_radix(256), Some(255));
+    assert_eq!(15u32.as_single_digit_radix(16), Some(15));
+    assert_eq!(16u32.as_single_digit_radix(16), None);
+    // digital_root の終端として使える
+    let mut n = 987_654_321u64;
+    let root = loop {
+        match n.as_single_digit() {
+            Some(d) => break d,
+            None => n = n.digit_sum(),
+        }
+    };
+    assert_eq!(root, 9);
+}
+
+#[test]
+fn test_factorial_digit_sum() {
+    assert_eq!(u64::factorial_digit_sum(0), 1);
+    assert_eq!(u64::factorial_digit_sum(1), 1);
+    assert_eq!(u64::factorial_digit_sum(10), 27);
+    assert_eq!(i32::factorial_digit_sum(100), 648);
+    // u128 に収まる範囲では直接計算した結果と一致する
+    let mut f = 1u128;
+    for n in 1..=34u32 {
+        f *= n as u128;
+        assert_eq!(u64::factorial_digit_sum(n), f.digit_sum());
+    }
+}
+
+#[test]
+fn test_add_digits() {
+    use keta::add_digits;
+
+    assert_eq!(add_digits(&[9, 9], &[1], 10), vec![1, 0, 0]);
+    assert_eq!(add_digits(&[1], &[9, 9], 10), vec![1, 0, 0]);
+    assert_eq!(add_digits(&[], &[], 10), vec![0]);
+    assert_eq!(add_digits(&[0, 0, 1], &[0, 2], 10), vec![3]);
+    assert_eq!(add_digits(&[255], &[255], 256), vec![1, 254]);
+    // 整数の足し算と一致する
+    for (x, y) in [(0u64, 0u64), (123, 987), (99_999, 1), (1 << 40, 12345)] {
+        assert_eq!(
+            add_digits(&x.digits_radix(7), &y.digits_radix(7), 7),
+            (x + y).digits_radix(7)
+        );
+    }
+    // u128 に収まらない和
+    let max = u128::MAX.digits();
+    let sum = add_digits(&max, &max, 10);
+    let expected: Vec<u8> = "680564733841876926926749214863536422910"
+        .bytes()
+        .map(|c| c - b'0')
+        .collect();
+    assert_eq!(sum, expected);
+}
+
+#[test]
+#[should_panic(expected = "digit out of range for base 10")]
+fn test_add_digits_invalid_digit() {
+    keta::add_digits(&[1, 10], &[1], 10);
+}
+
+#[test]
+fn test_is_bouncy() {
+    assert!(155349.is_bouncy());
+    assert!(!123456.is_bouncy());
+    assert!(!987654.is_bouncy());
+    assert!((-101).is_bouncy());
+    assert!((0..100).all(|n: u32| !n.is_bouncy()));
+    for n in 0..20000u32 {
+        assert_eq!(
+            n.is_bouncy(),
+            !n.has_increasing_digits() && !n.has_decreasing_digits()
+        );
+    }
+    // 21780 で初めて弾む数の割合がちょうど 90% になる (Project Euler 112)
+    assert_eq!((1..=21780u32).filter(|n| n.is_bouncy()).count(), 19602);
+    assert!(0b101.is_bouncy_radix(2));
+    assert!(!0b1100.is_bouncy_radix(2));
+}
+
+#[test]
+fn test_digit_view() {
+    for n in [0u64, 7, 1200, 987_654_321, u64::MAX] {
+        for base in [2, 10, 16, 256] {
+            let v = n.digit_view(base);
+            assert_eq!(v.len(), n.digits_len_radix(base));
+            assert_eq!(v.as_slice(), &n.digits_radix(base)[..]);
+            for i in 0..=v.len() {
+                assert_eq!(v.get(i), n.nth_digit_radix(i, base));
+                assert_eq!(v.get_from_right(i), n.nth_digit_from_right_radix(i, base));
+            }
+        }
+    }
+    let v = (-120i32).digit_view(10);
+    assert_eq!(
+        (v.base(), v.get(0), v.get_from_right(0)),
+        (10, Some(1), Some(0))
+    );
+}
+
+#[test]
+fn test_radix_string_with_alphabet() {
+    use keta::ParseKetaError;
+
+    let b58: Vec<char> = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
+        .chars()
+        .collect();
+    for n in [0u64, 57, 58, 1000, 0xdead_beef, u64::MAX] {
+        let s = n.to_radix_string_with(58, &b58);
+        assert_eq!(u64::from_radix_string_with(&s, 58, &b58), Ok(n));
+    }
+    assert_eq!(0u32.to_radix_string_with(58, &b58), "1");
+    assert_eq!(
+        u32::from_radix_string_with("0", 58, &b58),
+        Err(ParseKetaError::InvalidDigit('0'))
+    );
+    assert_eq!(
+        u32::from_radix_string_with("", 58, &b58),
+        Err(ParseKetaError::Empty)
+    );
+
+    // 基数より長いアルファベットは先頭だけ使う
+    let hex: Vec<char> = "0123456789ABCDEFGHIJ".chars().collect();
+    assert_eq!(255u8.to_radix_string_with(16, &hex), "FF");
+    assert_eq!(
+        u8::from_radix_string_with("G", 16, &hex),
+        Err(ParseKetaError::DigitOutOfRange('G'))
+    );
+    assert_eq!(
+        u8::from_radix_string_with("100", 16, &hex),
+        Err(ParseKetaError::Overflow)
+    );
+
+    // 符号付き、非 ASCII の文字集合
+    let kanji: Vec<char> = "〇一二三四五六七八九".chars().collect();
+    assert_eq!((-205i32).to_radix_string_with(10, &kanji), "-二〇五");
+    assert_eq!(i32::from_radix_string_with("-二〇五", 10, &kanji), Ok(-205));
+    assert_eq!(
+        i8::from_radix_string_with("-一二八", 10, &kanji),
+        Ok(i8::MIN)
+    );
+
+    // '-' が数字として含まれる場合は符号として扱わない
+    let dash: Vec<char> = "-+".chars().collect();
+    assert_eq!(u8::from_radix_string_with("+--", 2, &dash), Ok(4));
+    assert_eq!(i8::from_radix_string_with("-+", 2, &dash), Ok(1));
+
+    // 基数が型に収まらなくても1桁なら表せる
+    let wide: Vec<char> = (0..200u32)
+        .map(|i| char::from_u32(0x4e00 + i).unwrap())
+        .collect();
+    assert_eq!(
+        i8::from_radix_string_with(&wide[100].to_string(), 200, &wide),
+        Ok(100)
+    );
+    assert_eq!(
+        i8::from_radix_string_with(&wide[150].to_string(), 200, &wide),
+        Err(ParseKetaError::Overflow)
+    );
+    assert_eq!(
+        i8::from_radix_string_with(&format!("{}{}", wide[1], wide[0]), 200, &wide),
+        Err(ParseKetaError::Overflow)
+    );
+}
+
+#[test]
+#[should_panic(expected = "duplicate character 'a' in alphabet")]
+fn test_radix_string_with_duplicate_alphabet() {
+    10u32.to_radix_string_with(3, &['a', 'b', 'a']);
+}
+
+#[test]
+#[should_panic(expected = "alphabet must have at least 16 characters")]
+fn test_radix_string_with_short_alphabet() {
+    u32::from_radix_string_with("1", 16, &['0', '1']).ok();
+}
+
+#[test]
+fn test_arrangement_fixed() {
+    assert_eq!(2001.smallest_arrangement_fixed(), 1002);
+    assert_eq!(100.smallest_arrangement_fixed(), 100);
+    assert_eq!(0.smallest_arrangement_fixed(), 0);
+    assert_eq!(7.smallest_arrangement_fixed(), 7);
+    assert_eq!(9_876_543_210u64.smallest_arrangement_fixed(), 1_023_456_789);
+    assert_eq!((-2001).smallest_arrangement_fixed(), -2100);
+    assert_eq!(2001.largest_arrangement_fixed(), 2100);
+    assert_eq!((-2001).largest_arrangement_fixed(), -1002);
+    assert_eq!(0b1001u32.smallest_arrangement_fixed_radix(2), 0b1001);
+    assert_eq!(0xa0bu32.smallest_arrangement_fixed_radix(16), 0xa0b);
+    assert_eq!(0xb0au32.smallest_arrangement_fixed_radix(16), 0xa0b);
+    assert_eq!((-2001i32).checked_smallest_arrangement_fixed(), Some(-2100));
+    assert_eq!(2001u16.checked_smallest_arrangement_fixed(), Some(1002));
+    assert_eq!(
+        (-1_000_000_009i32).checked_smallest_arrangement_fixed(),
+        None
+    );
+    assert_eq!(i8::MIN.checked_smallest_arrangement_fixed(), None); // -821
+    assert_eq!(
+        (-0b1001i8).checked_smallest_arrangement_fixed_radix(2),
+        Some(-0b1100)
+    );
+    // 桁数と各数字の個数は変わらない
+    for n in 1..5000u32 {
+        let m = n.smallest_arrangement_fixed();
+        assert_eq!(m.digits_len(), n.digits_len());
+        assert_eq!(m.digit_counts(), n.digit_counts());
+        assert!(m <= n && m >= n.make_min());
+    }
+}
+
+#[test]
+#[should_panic(expected = "rearranged value overflows the type")]
+fn test_smallest_arrangement_fixed_overflow() {
+    let _ = (-1_000_000_009i32).smallest_arrangement_fixed();
+}
+
+#[test]
+fn test_checksum_mod() {
+    // ISBN-13
+    assert_eq!(9780306406157u64.checksum_mod(&[1, 3], 10), 0);
+    assert_eq!(9780306406158u64.checksum_mod(&[1, 3], 10), 1);
+    assert_ne!(9780316406157u64.checksum_mod(&[1, 3], 10), 0);
+    // ISBN-10 (0-306-40615-2) は一の位から 1, 2, ..., 10 の重みで 11 の倍数
+    assert_eq!(
+        306406152u64.checksum_mod(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10], 11),
+        0
+    );
+    // 負の重みでも余りは 0 以上
+    assert_eq!(12.checksum_mod(&[-1], 7), 4);
+    assert_eq!((-12).checksum_mod(&[1], 7), 3);
+    assert_eq!(0.checksum_mod(&[5], 3), 0);
+    // 重みが大きくても溢れない (u64::MAX の各桁の和は 87)
+    assert_eq!(
+        u64::MAX.checksum_mod(&[i64::MAX], 1_000_000_007),
+        (87 * i64::MAX as i128 % 1_000_000_007) as i64
+    );
+    assert_eq!(0b1011u8.checksum_mod_radix(&[1, 2], 5, 2), 0);
+}
+
+#[test]
+#[should_panic(expected = "weights must not be empty")]
+fn test_checksum_mod_empty_weights() {
+    123.checksum_mod(&[], 10);
+}
+
+#[cfg(feature = "proptest-helpers")]
+mod radix_roundtrip {
+    use keta::assert_radix_roundtrip;
+    use proptest::prelude::*;
+
+    macro_rules! roundtrip_props {
+        ($($name:ident: $t:ty),*) => {
+            proptest! {
+                $(
+                    #[test]
+                    fn $name(value in any::<$t>(), base in 2u32..=16) {
+                        assert_radix_roundtrip(value, base);
+                    }
+                )*
+            }
+        };
+    }
+
+    roundtrip_props!(
+        roundtrip_u8: u8, roundtrip_u16: u16, roundtrip_u32: u32,
+        roundtrip_u64: u64, roundtrip_u128: u128, roundtrip_usize: usize,
+        roundtrip_i8: i8, roundtrip_i16: i16, roundtrip_i32: i32,
+        roundtrip_i64: i64, roundtrip_i128: i128, roundtrip_isize: isize
+    );
+
+    #[test]
+    fn roundtrip_limits() {
+        for base in [2, 10, 16, 36, 127, 128, 200, 255, 256] {
+            assert_radix_roundtrip(0u8, base);
+            assert_radix_roundtrip(u8::MAX, base);
+            assert_radix_roundtrip(i8::MIN, base);
+            assert_radix_roundtrip(i128::MIN, base);
+            assert_radix_roundtrip(u128::MAX, base);
+        }
+    }
+}
+
+#[test]
+fn test_digits_radix_limits() {
+    assert_eq!(i8::MIN.digits(), vec![1, 2, 8]);
+    assert_eq!(i64::MIN.digits_radix(2).len(), 64);
+    assert_eq!(i8::MIN.digits_radix(128), vec![1, 0]);
+    assert_eq!(i8::MIN.digits_radix(200), vec![128]);
+    assert_eq!(255u8.digits_radix(256), vec![255]);
+    assert_eq!((-100i8).digits_radix(256), vec![100]);
+}
+
+#[test]
+fn test_is_undulating() {
+    assert!(8989.is_undulating());
+    assert!(121.is_undulating());
+    assert!(929.is_undulating());
+    assert!(1212.is_undulating());
+    assert!(101_010u32.is_undulating());
+    assert!(!111.is_undulating());
+    assert!(!12.is_undulating());
+    assert!(!7.is_undulating());
+    assert!(!1213.is_undulating());
+    assert!(!8988.is_undulating());
+    assert!((-8989).is_undulating());
+    assert!(0b10101.is_undulating_radix(2));
+    assert!(!0b10111.is_undulating_radix(2));
+    assert!(0xaba.is_undulating_radix(16));
+}
+
+#[test]
+fn test_digit_sum_table_matches_loop() {
+    fn naive(mut n: u128) -> u64 {
+        let mut sum = 0;
+        while n > 0 {
+            sum += (n % 10) as u64;
+            n /= 10;
+        }
+        sum
+    }
+    for n in 0..=1_000_000u64 {
+        assert_eq!(n.digit_sum(), naive(n as u128));
+    }
+    for n in [
+        u64::MAX as u128,
+        u64::MAX as u128 + 1,
+        10u128.pow(20) - 1,
+        // 3 桁ずつの区切りの境目
+        10u128.pow(21) - 1,
+        10u128.pow(21),
+        10u128.pow(38),
+        123_456_789_012_345_678_901_234_567_890,
+        u128::MAX,
+    ] {
+        assert_eq!(n.digit_sum(), naive(n));
+    }
+    assert_eq!(u8::MAX.digit_sum(), 12);
+    assert_eq!(i8::MIN.digit_sum(), 11);
+    assert_eq!(i128::MIN.digit_sum(), naive(i128::MIN.unsigned_abs()));
+    assert_eq!((-9_999i16).digit_sum(), 36);
+}
+
+#[test]
+fn test_digit_sum_newtype() {
+    use keta::DigitSum;
+
+    let nums = [0u64, 9, 1234, 987_654_321, u64::MAX];
+    let total: DigitSum = nums.iter().copied().map(DigitSum::from).sum();
+    assert_eq!(
+        total,
+        DigitSum(nums.iter().fold(0, |acc, n| acc + n.digit_sum()))
+    );
+    let sums: Vec<DigitSum> = [-12i32, 34].into_iter().map(Into::into).collect();
+    assert_eq!(sums.iter().sum::<DigitSum>(), DigitSum(10));
+    assert_eq!(
+        core::iter::empty::<DigitSum>().sum::<DigitSum>(),
+        DigitSum(0)
+    );
+    assert_eq!(DigitSum::from(255u8), DigitSum(12));
+}
+
+#[test]
+fn test_wrapping_concat_make_max() {
+    assert_eq!(12u8.wrapping_concat(34), (1234 % 256) as u8);
+    assert_eq!(
+        u64::MAX.wrapping_concat(9),
+        u64::MAX.wrapping_mul(10).wrapping_add(9)
+    );
+    assert_eq!(199u8.wrapping_make_max(), (991 % 256) as u8);
+    assert_eq!(i8::MIN.wrapping_concat(1), (-1281i32) as i8);
+    assert_eq!(1i8.wrapping_concat(i8::MIN), (1128i32) as i8);
+    assert_eq!(i32::MAX.wrapping_make_max(), 8_776_444_321i64 as i32);
+    assert_eq!(i32::MIN.wrapping_make_max(), -1_234_446_788);
+    assert_eq!(0xffu8.wrapping_concat_radix(0x1, 16), 0xf1);
+    assert_eq!(0b11u8.wrapping_make_max_radix(2), 0b11);
+    // 溢れない範囲では通常版と一致する
+    for n in -300i16..300 {
+        assert_eq!(n.wrapping_concat(37), n.concat(37));
+        assert_eq!(n.wrapping_make_max(), n.make_max());
+        assert_eq!(n.wrapping_concat_radix(5, 3), n.concat_radix(5, 3));
+    }
+}
+
+#[test]
+fn test_trailing_leading_zeros_radix() {
+    assert_eq!(1200.trailing_zeros_radix(10), 2);
+    assert_eq!(1200.trailing_zeros_10(), 2);
+    assert_eq!(1201.trailing_zeros_10(), 0);
+    assert_eq!(0.trailing_zeros_10(), 1);
+    assert_eq!((-1000).trailing_zeros_10(), 3);
+    assert_eq!(i64::MIN.trailing_zeros_radix(2), 63);
+    assert_eq!(0x1200u32.trailing_zeros_radix(16), 2);
+    assert_eq!(72u32.trailing_zeros_radix(6), 2); // 72 = 200 (6進数)
+                                                  // 2進数では整数型の trailing_zeros と一致する
+    for n in 1..1000u32 {
+        assert_eq!(n.trailing_zeros_radix(2), n.trailing_zeros());
+    }
+
+    assert_eq!(42.leading_zeros_radix(5, 10), 3);
+    assert_eq!(12345.leading_zeros_radix(3, 10), 0);
+    assert_eq!(0.leading_zeros_radix(4, 10), 3);
+    assert_eq!(0b101u8.leading_zeros_radix(8, 2), 5);
+}
+
+#[test]
+fn test_reverse_digits() {
+    assert_eq!(1200.reverse_digits(), (vec![0, 0, 2, 1], false));
+    assert_eq!(0.reverse_digits(), (vec![0], false));
+    assert_eq!((-120).reverse_digits(), (vec![0, 2, 1], true));
+    assert_eq!(0b1100u8.reverse_digits_radix(2), (vec![0, 0, 1, 1], false));
+    // 2回反転すると元の数字列に戻る
+    for n in [-1200i64, 0, 7, 100, 987_654_321, i64::MIN] {
+        let (mut d, neg) = n.reverse_digits();
+        d.reverse();
+        assert_eq!((neg, d), n.signed_digits());
+    }
+}
+
+#[test]
+fn test_max_digits() {
+    assert_eq!(u8::MAX_DIGITS, 3);
+    assert_eq!(u64::MAX_DIGITS, 20);
+    assert_eq!(i8::MAX_DIGITS, 3);
+    assert_eq!(u128::MAX_DIGITS, 39);
+    macro_rules! check {
+        ($($t:ty),*) => {$(
+            assert_eq!(<$t>::MAX_DIGITS, <$t>::MAX.digits_len());
+            assert_eq!(<$t>::MAX_DIGITS, <$t>::max_digits_radix(10));
+            for base in [2, 3, 10, 16, 36, 256] {
+                let longest = <$t>::MIN.digits_radix(base).len().max(<$t>::MAX.digits_radix(base).len());
+                assert_eq!(<$t>::max_digits_radix(base) as usize, longest);
+            }
+        )*};
+    }
+    check!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    assert_eq!(i8::max_digits_radix(2), 8); // -128 = -10000000
+    assert_eq!(u8::max_digits_radix(2), 8);
+}
+
+#[test]
+fn test_is_pdi() {
+    assert!(4150.is_pdi(5));
+    assert!(4151.is_pdi(5)); // 4^5 + 1^5 + 5^5 + 1^5
+    assert!(!4152.is_pdi(5));
+    assert!(1634.is_pdi(4));
+    assert!(!1634.is_pdi(3));
+    assert!((-4150).is_pdi(5));
+    assert!(!u64::MAX.is_pdi(40));
+    // 桁数乗なら is_armstrong と一致する
+    for n in 1..20000u32 {
+        assert_eq!(n.is_pdi(n.digits_len()), n.is_armstrong());
+    }
+    assert!(!0b11u32.is_pdi_radix(1, 2));
+    assert!(0b1u32.is_pdi_radix(7, 2));
+}
+
+#[test]
+fn test_palindromes_in_range() {
+    use keta::palindromes_in_range;
+
+    for base in [2, 3, 10, 16] {
+        for (low, high) in [(0u64, 2000u64), (17, 17), (500, 5000), (10, 5)] {
+            let brute: Vec<u64> = (low..=high)
+                .filter(|n| n.is_palindrome_radix(base))
+                .collect();
+            assert_eq!(
+                palindromes_in_range(low, high, base).collect::<Vec<_>>(),
+                brute
+            );
+        }
+    }
+    // 大きな範囲でも先頭から生成できる
+    let big: Vec<u64> =
+        palindromes_in_range(10u64.pow(18), 10u64.pow(18) + 10u64.pow(10), 10).collect();
+    assert_eq!(big.len(), 10);
+    assert!(big.iter().all(|n| n.is_palindrome()));
+    // u64::MAX 付近の 20 桁の回文は u64 を超えるものまで作らない
+    assert_eq!(
+        palindromes_in_range(u64::MAX - 10u64.pow(9), u64::MAX, 10).count(),
+        0
+    );
+    assert_eq!(palindromes_in_range(0, u64::MAX, 10).nth(100), Some(919));
+    // 2進数でも10進数でも回文 (Project Euler 36)
+    let both: u64 = palindromes_in_range(1, 999_999, 10)
+        .filter(|n| n.is_palindrome_radix(2))
+        .sum();
+    assert_eq!(both, 872_187);
+}
+
+#[test]
+fn test_add_with_carry() {
+    // 3桁同士の和が4桁目に繰り上がる
+    assert_eq!(500u32.add_with_carry(600, 3, 10), Some((100, true)));
+    assert_eq!(999u32.add_with_carry(1, 3, 10), Some((0, true)));
+    assert_eq!(123u32.add_with_carry(456, 3, 10), Some((579, false)));
+    assert_eq!(0u32.add_with_carry(0, 3, 10), Some((0, false)));
+    assert_eq!(0b11u8.add_with_carry(0b01, 2, 2), Some((0b00, true)));
+    assert_eq!(0xffu16.add_with_carry(0x01, 2, 16), Some((0x00, true)));
+    assert_eq!(45i32.add_with_carry(55, 2, 10), Some((0, true)));
+    // 桁幅は引数で決まり、オペランドの桁数には依らない
+    assert_eq!(50u32.add_with_carry(60, 2, 10), Some((10, true)));
+    assert_eq!(50u32.add_with_carry(60, 3, 10), Some((110, false)));
+    // width 桁に収まらないオペランド
+    assert_eq!(1000u32.add_with_carry(1, 3, 10), None);
+    assert_eq!(1u32.add_with_carry(0, 0, 10), None);
+    assert_eq!(0u32.add_with_carry(0, 0, 10), Some((0, false)));
+
+    assert_eq!(579u32.sub_with_borrow(456, 3, 10), Some((123, false)));
+    assert_eq!(100u32.sub_with_borrow(1, 3, 10), Some((99, false)));
+    // 1 - 100 は 1000 を借りて 901
+    assert_eq!(1u32.sub_with_borrow(100, 3, 10), Some((901, true)));
+    assert_eq!(0b10u8.sub_with_borrow(0b11, 2, 2), Some((0b11, true)));
+    assert_eq!(7i64.sub_with_borrow(7, 1, 10), Some((0, false)));
+
+    // 桁ごとの計算は整数演算を 10^width で割った余りと一致する
+    for a in 0u32..300 {
+        for b in 0u32..300 {
+            let width = a.digits_len().max(b.digits_len());
+            let w = 10u32.pow(width);
+            assert_eq!(
+                a.add_with_carry(b, width, 10),
+                Some(((a + b) % w, a + b >= w))
+            );
+            assert_eq!(
+                a.sub_with_borrow(b, width, 10),
+                Some(((w + a - b) % w, a < b))
+            );
+        }
+    }
+}
+
+#[test]
+fn test_add_with_carry_overflow() {
+    // 結果が型に収まらない場合はパニックせず None
+    assert_eq!(u8::MAX.add_with_carry(u8::MAX, 3, 10), None); // 510
+    assert_eq!(250u8.add_with_carry(9, 3, 10), None); // 259
+    assert_eq!(200u8.add_with_carry(55, 3, 10), Some((255, false)));
+    assert_eq!(u8::MAX.add_with_carry(u8::MAX, 8, 2), Some((254, true)));
+    assert_eq!(0u8.sub_with_borrow(1, 3, 10), None); // 999
+    assert_eq!(0u8.sub_with_borrow(1, 2, 10), Some((99, true)));
+    assert_eq!(
+        u16::MAX.add_with_carry(u16::MAX, 5, 10),
+        Some((31070, true))
+    );
+    assert_eq!(u16::MAX.add_with_carry(1, 5, 10), None); // 65536
+    assert_eq!(0u16.sub_with_borrow(1, 5, 10), None); // 99999
+    assert_eq!(i8::MAX.add_with_carry(1, 3, 10), None); // 128
+    assert_eq!(
+        u128::MAX.add_with_carry(u128::MAX, 128, 2),
+        Some((u128::MAX - 1, true))
+    );
+    assert_eq!(u128::MAX.add_with_carry(1, 39, 10), None); // 2^128
+    assert_eq!(0u128.sub_with_borrow(1, 128, 2), Some((u128::MAX, true)));
+    assert_eq!(0u128.sub_with_borrow(1, 129, 2), None);
+    // 桁幅が大きくても借りの連鎖は u128 を超えた時点で打ち切る
+    assert_eq!(0u8.sub_with_borrow(1, u32::MAX, 10), None);
+}
+
+#[test]
+fn test_add_with_carry_negative() {
+    assert_eq!((-1i32).add_with_carry(1, 3, 10), None);
+    assert_eq!(1i32.sub_with_borrow(-1, 3, 10), None);
+    assert_eq!(i32::MIN.add_with_carry(0, 10, 10), None);
+}
+
+#[test]
+fn test_most_common_digit() {
+    assert_eq!(1223334444.most_common_digit(), 4);
+    // 同数なら小さい数字
+    assert_eq!(112233.most_common_digit(), 1);
+    assert_eq!(332211.most_common_digit(), 1);
+    assert_eq!(0.most_common_digit(), 0);
+    assert_eq!(1000.most_common_digit(), 0);
+    assert_eq!((-9881i32).most_common_digit(), 8);
+
+    assert_eq!(1223334444.least_common_digit(), 1);
+    assert_eq!(4443332.least_common_digit(), 2);
+    assert_eq!(112233.least_common_digit(), 1);
+    // 現れない数字は対象外
+    assert_eq!(9.least_common_digit(), 9);
+    assert_eq!(0.least_common_digit(), 0);
+
+    assert_eq!(0b1011u8.most_common_digit_radix(2), 1);
+    assert_eq!(0b1011u8.least_common_digit_radix(2), 0);
+    assert_eq!(0xffau32.most_common_digit_radix(16), 15);
+    assert_eq!(0xffau32.least_common_digit_radix(16), 10);
+    assert_eq!(0u64.least_common_digit_radix(7), 0);
+    assert_eq!(i16::MIN.most_common_digit_radix(2), 0);
+    for n in 0u32..2000 {
+        assert_eq!(n.most_common_digit_radix(10), n.most_common_digit());
+        assert_eq!(n.least_common_digit_radix(10), n.least_common_digit());
+    }
+    assert_eq!(i32::MIN.most_common_digit(), 4); // 2147483648
+    assert_eq!(i32::MIN.least_common_digit(), 1);
+    assert_eq!(i8::MIN.most_common_digit(), 1);
+}
+
+#[test]
+fn test_slice_ops() {
+    use keta::ops::{slice_digit_sum, slice_is_palindrome, slice_make_max, slice_reverse};
+
+    for n in (0u64..3000).chain([12321, 1_000_000_007, 12_345_678_987_654_321]) {
+        let d = n.digits();
+        assert_eq!(slice_digit_sum(&d), n.digit_sum());
+        assert_eq!(slice_is_palindrome(&d), n.is_palindrome());
+
+        // 並べ替えた結果が u64 に収まる範囲で比べる
+        if n < 10u64.pow(18) {
+            let mut r = d.clone();
+            slice_reverse(&mut r);
+            assert_eq!(u64::from_digits(&r), n.reverse());
+
+            let mut m = d.clone();
+            slice_make_max(&mut m);
+            assert_eq!(u64::from_digits(&m), n.make_max());
+        }
+    }
+
+    // 整数に収まらない長さでも扱える
+    let mut long = [9u8; 40];
+    long[0] = 1;
+    assert_eq!(slice_digit_sum(&long), 1 + 9 * 39);
+    assert!(!slice_is_palindrome(&long));
+    slice_make_max(&mut long);
+    assert_eq!(long[39], 1);
+    slice_reverse(&mut long);
+    assert_eq!(long[0], 1);
+    assert!(slice_is_palindrome(&[]));
+}
+
+#[test]
+fn test_digit_trajectory() {
+    assert_eq!(
+        199u32.digit_sum_trajectory().collect::<Vec<_>>(),
+        [199, 19, 10, 1]
+    );
+    assert_eq!(0u32.digit_sum_trajectory().collect::<Vec<_>>(), [0]);
+    assert_eq!(
+        (-199i32).digit_sum_trajectory().collect::<Vec<_>>(),
+        [199, 19, 10, 1]
+    );
+    // |i64::MIN| = 9223372036854775808 -> 89 -> 17 -> 8
+    assert_eq!(i64::MIN.digit_sum_trajectory().last(), Some(8));
+    assert_eq!(
+        39u32.digit_product_trajectory().collect::<Vec<_>>(),
+        [39, 27, 14, 4]
+    );
+    assert_eq!(
+        10u32.digit_product_trajectory().collect::<Vec<_>>(),
+        [10, 0]
+    );
+    assert_eq!(5u8.digit_product_trajectory().collect::<Vec<_>>(), [5]);
+
+    for n in 0u32..5000 {
+        assert_eq!(
+            n.digit_sum_trajectory().count() as u32 - 1,
+            n.additive_persistence()
+        );
+        let root = if n == 0 { 0 } else { 1 + (n as u64 - 1) % 9 };
+        assert_eq!(n.digit_sum_trajectory().last(), Some(root));
+        assert_eq!(
+            n.digit_product_trajectory().count() as u32 - 1,
+            n.multiplicative_persistence()
+        );
+    }
+    assert_eq!(
+        u128::from(u64::MAX).digit_sum_trajectory().next(),
+        Some(u64::MAX)
+    );
+}
+
+#[test]
+#[should_panic(expected = "does not fit in u64")]
+fn test_digit_trajectory_too_large() {
+    let _ = u128::MAX.digit_sum_trajectory();
+}
+
+#[test]
+fn test_from_grouped_str() {
+    use keta::ParseKetaError;
+
+    assert_eq!(u32::from_grouped_str("1,234,567", 10), Ok(1_234_567));
+    assert_eq!(u32::from_grouped_str("1_000", 10), Ok(1000));
+    assert_eq!(u64::from_grouped_str("12 345 678", 10), Ok(12_345_678));
+    assert_eq!(u32::from_grouped_str("dead_beef", 16), Ok(0xdead_beef));
+    assert_eq!(u8::from_grouped_str("+1111_0000", 2), Ok(0b1111_0000));
+    assert_eq!(i32::from_grouped_str("-2,147,483,648", 10), Ok(i32::MIN));
+    assert_eq!(i32::from_grouped_str("42", 10), Ok(42));
+    // 同じ文字列を group_digits で作って戻せる
+    for n in [0i64, 7, -1234, 1_000_000, i64::MAX, i64::MIN] {
+        assert_eq!(i64::from_grouped_str(&n.group_digits(',', 3), 10), Ok(n));
+    }
+
+    // 区切り文字は数字と数字の間だけ
+    assert_eq!(
+        u32::from_grouped_str(",100", 10),
+        Err(ParseKetaError::InvalidDigit(','))
+    );
+    assert_eq!(
+        u32::from_grouped_str("100_", 10),
+        Err(ParseKetaError::InvalidDigit('_'))
+    );
+    assert_eq!(
+        u32::from_grouped_str("1,,000", 10),
+        Err(ParseKetaError::InvalidDigit(','))
+    );
+    assert_eq!(
+        i32::from_grouped_str("-_1", 10),
+        Err(ParseKetaError::InvalidDigit('_'))
+    );
+    assert_eq!(
+        u32::from_grouped_str("_", 10),
+        Err(ParseKetaError::InvalidDigit('_'))
+    );
+    assert_eq!(
+        u32::from_grouped_str("-", 10),
+        Err(ParseKetaError::InvalidDigit('-'))
+    );
+    assert_eq!(i32::from_grouped_str("-", 10), Err(ParseKetaError::Empty));
+    assert_eq!(u32::from_grouped_str("", 10), Err(ParseKetaError::Empty));
+    assert_eq!(
+        u32::from_grouped_str("1.000", 10),
+        Err(ParseKetaError::InvalidDigit('.'))
+    );
+    assert_eq!(
+        u8::from_grouped_str("2_5_6", 10),
+        Err(ParseKetaError::Overflow)
+    );
+}
+
+#[test]
+fn test_is_digit_reverse_of() {
+    assert!(123.is_digit_reverse_of(321));
+    assert!(120.is_digit_reverse_of(21));
+    assert!(!120.is_digit_reverse_of(210));
+    assert!((-12).is_digit_reverse_of(-21));
+    assert!(!(-12).is_digit_reverse_of(21));
+    // 反転が型の範囲を超える場合
+    assert!(!255u8.is_digit_reverse_of(255));
+    assert!(0.is_digit_reverse_of(0));
+
+    assert!(123.is_digit_reverse_of_strict(321));
+    assert!(!120.is_digit_reverse_of_strict(21));
+    assert!(0.is_digit_reverse_of_strict(0));
+    assert!(0b110u8.is_digit_reverse_of_radix(0b11, 2));
+    assert!(!0b110u8.is_digit_reverse_of_strict_radix(0b11, 2));
+    assert!(0b1101u8.is_digit_reverse_of_strict_radix(0b1011, 2));
+}
+
+#[test]
+fn test_is_emirp() {
+    assert!(13.is_emirp());
+    assert!(31u8.is_emirp());
+    assert!(17u64.is_emirp());
+    assert!(!11.is_emirp()); // 回文素数
+    assert!(!15.is_emirp()); // 合成数
+    assert!(!19.is_emirp()); // 91 = 7 * 13
+    assert!(!(-13).is_emirp());
+    assert!(!0.is_emirp());
+    assert!(!2.is_emirp());
+    // 反転 (991) が型に収まらなくても判定できる
+    assert!(199u8.is_emirp());
+    let emirps: Vec<u32> = (0u32..200).filter(|n| n.is_emirp()).collect();
+    assert_eq!(
+        emirps,
+        [13, 17, 31, 37, 71, 73, 79, 97, 107, 113, 149, 157, 167, 179, 199]
+    );
+}
+
+#[test]
+fn test_vampire() {
+    assert!(1260.is_vampire());
+    assert_eq!(1260.vampire_fangs(), Some((21, 60)));
+    assert_eq!(1395u16.vampire_fangs(), Some((15, 93)));
+    assert_eq!(125460u32.vampire_fangs(), Some((204, 615)));
+    assert!(!1234.is_vampire());
+    // 牙が両方 0 で終わる 126000 = 210 * 600 は除く
+    assert!(!126000.is_vampire());
+    // 桁数が奇数
+    assert!(!126.is_vampire());
+    assert!(!0.is_vampire());
+    assert!(!(-1260).is_vampire());
+
+    let vampires: Vec<u32> = (0u32..10_000).filter(|n| n.is_vampire()).collect();
+    assert_eq!(vampires, [1260, 1395, 1435, 1530, 1827, 2187, 6880]);
+
+    // 牙の下限 ceil(n / (10^half - 1)) から探すので、上限近くの数でもすぐ見つかる
+    assert_eq!(96098800u32.vampire_fangs(), Some((9800, 9806)));
+    assert_eq!(999039197938u64.vampire_fangs(), Some((999338, 999701)));
+    assert_eq!(102510u32.vampire_fangs(), Some((201, 510)));
+    // 6桁の吸血鬼数は 148 個 (OEIS A014575)
+    assert_eq!(
+        (100_000u32..1_000_000).filter(|n| n.is_vampire()).count(),
+        148
+    );
+
+    // 絞り込みは素朴な全探索と一致する
+    let naive = |n: u32| {
+        let half = n.digits_len() / 2;
+        (10u32.pow(half - 1)..)
+            .take_while(|&x| x * x <= n)
+            .filter(|&x| n.is_multiple_of(x))
+            .map(|x| (x, n / x))
+            .find(|&(x, y)| {
+                y.digits_len() == half
+                    && !(x.is_multiple_of(10) && y.is_multiple_of(10))
+                    && (0..10)
+                        .all(|d| x.digit_counts()[d] + y.digit_counts()[d] == n.digit_counts()[d])
+            })
+    };
+    for n in (100_000u32..1_000_000).step_by(97) {
+        assert_eq!(n.vampire_fangs(), naive(n), "n = {}", n);
+    }
+}
+
+#[test]
+fn test_concat_many() {
+    assert_eq!(u32::concat_many_10(&[12, 34, 5]), 12345);
+    assert_eq!(u32::concat_many_10(&[]), 0);
+    assert_eq!(u32::concat_many_10(&[7]), 7);
+    assert_eq!(u64::concat_many_10(&[1, 0, 0, 7]), 1007);
+    assert_eq!(u8::concat_many(&[0b1, 0b10, 0b11], 2), 0b11011);
+    assert_eq!(u32::concat_many(&[0xab, 0xcd], 16), 0xabcd);
+    // 符号は concat と同じく先頭の数に従う
+    assert_eq!(i32::concat_many_10(&[-1, 20, 3]), -1203);
+    assert_eq!(i32::concat_many_10(&[-1, -20, 3]), -1203);
+    assert_eq!(i32::concat_many_10(&[1, -20, 3]), 1203);
+    assert_eq!(i32::concat_many_10(&[0, -5]), 5);
+    for parts in [[12i64, -3, 45], [-12, 3, -45], [0, 0, 9], [-9, 0, 0]] {
+        let folded = parts[1..].iter().fold(parts[0], |acc, &p| acc.concat(p));
+        assert_eq!(i64::concat_many_10(&parts), folded);
+        assert_eq!(i64::checked_concat_many_10(&parts), Some(folded));
+    }
+    assert_eq!(i8::checked_concat_many_10(&[-1, 2, 8]), Some(-128));
+    assert_eq!(i8::checked_concat_many_10(&[-1, 2, 9]), None);
+
+    // u16 は 65535 まで
+    assert_eq!(u16::checked_concat_many_10(&[6, 55, 35]), Some(65535));
+    assert_eq!(u16::checked_concat_many_10(&[6, 55, 36]), None);
+    assert_eq!(u16::checked_concat_many_10(&[1, 2, 3, 4, 5, 6]), None);
+    assert_eq!(u16::checked_concat_many_10(&[]), Some(0));
+    assert_eq!(u8::checked_concat_many(&[1, 1], 300), None);
+}
+
+#[test]
+fn test_map_digits() {
+    // 9の補数
+    assert_eq!(123.map_digits(|d| 9 - d), 876);
+    assert_eq!(900u32.map_digits(|d| 9 - d), 99);
+    assert_eq!(0u32.map_digits(|d| d + 4), 4);
+    assert_eq!(1962.map_digits(|d| d.min(5)), 1552);
+    assert_eq!((-123).map_digits(|d| 9 - d), -876);
+    assert_eq!(i8::MIN.map_digits(|d| d), i8::MIN);
+    assert_eq!(i8::MIN.map_digits(|d| d.saturating_sub(1)), -17);
+    // 1の補数 (2進数)
+    assert_eq!(0b1011u8.map_digits_radix(|d| 1 - d, 2), 0b0100);
+    assert_eq!(0xf0u32.map_digits_radix(|d| 15 - d, 16), 0x0f);
+    for n in 0u32..1000 {
+        assert_eq!(n.map_digits(|d| d), n);
+    }
+}
+
+#[test]
+#[should_panic(expected = "mapped digit 10 out of range for base 10")]
+fn test_map_digits_out_of_range() {
+    let _ = 19u32.map_digits(|d| d + 1);
+}
+
+#[test]
+fn test_checked_map_digits() {
+    assert_eq!(12u8.checked_map_digits(|d| 9 - d), Some(87));
+    assert_eq!(255u8.checked_map_digits(|d| 9 - d), None); // 744
+    assert_eq!(u64::MAX.checked_map_digits(|d| d), Some(u64::MAX));
+    assert_eq!(u64::MAX.checked_map_digits(|d| d.max(2)), None);
+    assert_eq!(i8::MIN.checked_map_digits(|d| d), Some(i8::MIN));
+    assert_eq!(i8::MIN.checked_map_digits(|d| d + 1), None); // -239
+    assert_eq!(127i8.checked_map_digits(|d| 9 - d), None); // 872
+    assert_eq!(i8::MIN.checked_map_digits_radix(|d| d, 2), Some(i8::MIN));
+    assert_eq!(
+        i8::MIN.checked_map_digits_radix(|d| 1 - d, 2),
+        Some(-0b0111_1111)
+    );
+    assert_eq!(0x80u8.checked_map_digits_radix(|d| 15 - d, 16), Some(0x7f));
+}
+
+#[test]
+#[should_panic(expected = "mapped value overflows the type")]
+fn test_map_digits_overflow() {
+    let _ = 255u8.map_digits_radix(|d| 9 - d, 10);
+}
+
+#[test]
+fn test_digit_sum_range() {
+    use keta::digit_sum_range;
+
+    for (low, high) in [
+        (0, 0),
+        (0, 9),
+        (1, 10),
+        (5, 5),
+        (0, 1000),
+        (123, 4567),
+        (999, 1001),
+    ] {
+        let brute: u64 = (low..=high).map(|n: u64| n.digit_sum()).sum();
+        assert_eq!(digit_sum_range(low, high), brute, "{}..={}", low, high);
+    }
+    let mut acc = 0;
+    for n in 0u64..3000 {
+        acc += n.digit_sum();
+        assert_eq!(digit_sum_range(0, n), acc);
+    }
+    assert_eq!(digit_sum_range(10, 5), 0);
+    // 0..10^k の総和は 45 * k * 10^(k-1)
+    assert_eq!(
+        digit_sum_range(0, 10u64.pow(12) - 1),
+        45 * 12 * 10u64.pow(11)
+    );
+    // 1 + 2 + ... + 10
+    assert_eq!(digit_sum_range(10u64.pow(12), 10u64.pow(12) + 9), 55);
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[allow(clippy::approx_constant)]
+fn test_float_digits() {
+    use keta::KetaFloat;
+
+    assert_eq!(3.14159.frac_digits(10, 3), [1, 4, 1]);
+    assert_eq!(3.14159.digits_of_int_part(), [3]);
+    assert_eq!((-12.5).digits_of_int_part(), [1, 2]);
+    assert_eq!((-12.5).frac_digits(10, 2), [5, 0]);
+    assert_eq!(0.25.digits_of_int_part(), [0]);
+    assert_eq!(0.3.frac_digits(10, 4), [3, 0, 0, 0]);
+    assert_eq!(0.57.frac_digits(10, 3), [5, 7, 0]);
+    assert_eq!(0.1.frac_digits(10, 20), [&[1][..], &[0; 19]].concat());
+    assert_eq!(1e-7.frac_digits(10, 8), [0, 0, 0, 0, 0, 0, 1, 0]);
+    assert_eq!(0.3f32.frac_digits(10, 3), [3, 0, 0]);
+    assert_eq!(0.57f32.frac_digits(10, 3), [5, 7, 0]);
+    // 10進数以外は2進数の値を誤差なく展開する
+    assert_eq!(0.1.frac_digits(2, 9), [0, 0, 0, 1, 1, 0, 0, 1, 1]);
+    assert_eq!(0.5.frac_digits(3, 5), [1, 1, 1, 1, 1]);
+    assert_eq!(f64::MIN_POSITIVE.frac_digits(2, 3), [0, 0, 0]);
+    assert_eq!(
+        (1.0 - f64::EPSILON / 2.0).frac_digits(16, 14),
+        [&[15; 13][..], &[8]].concat()
+    );
+    assert_eq!(0.75.frac_digits(2, 3), [1, 1, 0]);
+    assert_eq!(255.5.digits_of_int_part_radix(16), [15, 15]);
+    assert_eq!(255.5.frac_digits(16, 1), [8]);
+    assert_eq!(2.5f32.frac_digits(10, 1), [5]);
+    assert_eq!(1234.0f32.digits_of_int_part(), [1, 2, 3, 4]);
+    assert_eq!(7.0.frac_digits(10, 3), [0, 0, 0]);
+
+    // 整数部分は u128 を超えても誤差なく分解する
+    let mut d = vec![1];
+    d.extend([0; 20]);
+    assert_eq!(1e20.digits_of_int_part(), d);
+    assert_eq!(2f64.powi(130).digits_of_int_part_radix(2).len(), 131);
+    let expected: Vec<u8> = "1361129467683753853853498429727072845824"
+        .bytes()
+        .map(|c| c - b'0')
+        .collect();
+    assert_eq!(2f64.powi(130).digits_of_int_part(), expected);
+    assert_eq!(f64::MAX.digits_of_int_part().len(), 309);
+    assert_eq!(2f64.powi(200).digits_of_int_part_radix(16)[0], 1);
+
+    for x in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+        assert!(x.digits_of_int_part().is_empty());
+        assert!(x.frac_digits(10, 3).is_empty());
+    }
+}
+
+#[test]
+fn test_digits_builder() {
+    use keta::Digits;
+
+    let mut d = Digits::new(10);
+    assert!(d.is_empty());
+    assert_eq!(d.build::<u32>(), Some(0));
+    d.push(1);
+    d.push(2);
+    d.push(3);
+    assert_eq!(d.len(), 3);
+    assert_eq!(d.build::<u32>(), Some(123));
+    d.push_front(4);
+    assert_eq!(d.as_slice(), [4, 1, 2, 3]);
+    assert_eq!(d.build::<u32>(), Some(4123));
+    assert_eq!(d.build::<i16>(), Some(4123));
+    // u8 に収まらない
+    assert_eq!(d.build::<u8>(), None);
+    assert_eq!(d.pop(), Some(3));
+    assert_eq!(d.build::<u16>(), Some(412));
+    d.push_front(0);
+    assert_eq!(d.build::<u16>(), Some(412));
+
+    let mut b = Digits::new(2);
+    for bit in [1, 0, 1, 1] {
+        b.push(bit);
+    }
+    assert_eq!(b.base(), 2);
+    assert_eq!(b.build::<u8>(), Some(0b1011));
+    let mut e = Digits::new(16);
+    assert_eq!(e.pop(), None);
+    e.push(0xf);
+    e.push(0xf);
+    assert_eq!(e.build::<u8>(), Some(255));
+    e.push(0);
+    assert_eq!(e.build::<u8>(), None);
+}
+
+#[test]
+#[should_panic(expected = "digit 2 out of range for base 2")]
+fn test_digits_builder_invalid_digit() {
+    keta::Digits::new(2).push(2);
+}
+
+#[test]
+fn test_min_max_rotation() {
+    for n in [312, 123, 231] {
+        assert_eq!(n.min_rotation(), 123);
+        assert_eq!(n.max_rotation(), 312);
+    }
+    // 先頭に 0 が来る回転も含む
+    assert_eq!(120.min_rotation(), 12);
+    assert_eq!(1020.max_rotation(), 2010);
+    assert_eq!(7.min_rotation(), 7);
+    assert_eq!(0.max_rotation(), 0);
+    // 並び替えではなく回転だけ
+    assert_eq!(1324.min_rotation(), 1324);
+    assert_ne!(1324.min_rotation(), 1324.make_min());
+    // 負の数は値として比べる
+    assert_eq!((-123).min_rotation(), -312);
+    assert_eq!((-312).max_rotation(), -123);
+    // 他の回転が型の範囲を超えても最小は求まる
+    assert_eq!(199u8.min_rotation(), 199);
+    assert_eq!(125u8.max_rotation(), 251); // 512 は範囲外
+    assert_eq!(129u8.max_rotation(), 129); // 291, 912 は範囲外
+    assert_eq!(255u8.min_rotation(), 255);
+    assert_eq!(i8::MIN.min_rotation(), -128); // -281, -812 は範囲外
+    assert_eq!(i8::MIN.max_rotation(), -128);
+    assert_eq!((-129i16).min_rotation(), -912);
+    assert_eq!(0b0110u8.min_rotation_radix(2), 0b011);
+    assert_eq!(0b0110u8.max_rotation_radix(2), 0b110);
+    assert_eq!(0x1f2u32.max_rotation_radix(16), 0xf21);
+
+    for n in 0u32..3000 {
+        let rots: Vec<u32> = (0..n.digits_len())
+            .map(|k| n.rotate_digits_left(k))
+            .collect();
+        assert_eq!(n.min_rotation(), *rots.iter().min().unwrap());
+        assert_eq!(n.max_rotation(), *rots.iter().max().unwrap());
+    }
+}
+
+#[test]
+fn test_reverse_bits_width() {
+    use keta::KetaUnsigned;
+
+    assert_eq!(0b001u8.reverse_bits_width(3), 0b100);
+    assert_eq!(0b110u8.reverse_bits_width(3), 0b011);
+    assert_eq!(0b1u32.reverse_bits_width(32), 1 << 31);
+    assert_eq!(0x0fu8.reverse_bits_width(8), 0xf0);
+    assert_eq!(5u64.reverse_bits_width(0), 0);
+    // width より上のビットは無視する
+    assert_eq!(0b1000_0001u8.reverse_bits_width(4), 0b1000);
+    // FFT のビット反転順 (8 点)
+    let order: Vec<u16> = (0u16..8).map(|i| i.reverse_bits_width(3)).collect();
+    assert_eq!(order, [0, 4, 2, 6, 1, 5, 3, 7]);
+    // 先頭の 0 を落とす reverse_radix(2) とは異なる
+    assert_ne!(0b0011u8.reverse_bits_width(4), 0b0011u8.reverse_radix(2));
+    for n in 0u16..1024 {
+        assert_eq!(n.reverse_bits_width(10).reverse_bits_width(10), n);
+    }
+    assert_eq!(u128::MAX.reverse_bits_width(128), u128::MAX);
+}
+
+#[test]
+#[should_panic(expected = "width 9 exceeds bit size 8")]
+fn test_reverse_bits_width_too_wide() {
+    use keta::KetaUnsigned;
+    let _ = 1u8.reverse_bits_width(9);
+}
+
+#[test]
+fn test_digit_signature() {
+    assert_eq!(123.digit_signature(), 321.digit_signature());
+    assert_eq!(123.digit_signature(), 231.digit_signature());
+    assert_ne!(123.digit_signature(), 122.digit_signature());
+    // 0 の個数も区別する
+    assert_ne!(12.digit_signature(), 102.digit_signature());
+    assert_eq!((-321i32).digit_signature(), 123u8.digit_signature());
+    assert_ne!(u128::MAX.digit_signature(), 0u128.digit_signature());
+
+    // シグネチャが等しいことと、数字の出現回数が等しいことは同値
+    let mut seen = std::collections::HashMap::new();
+    for n in 0u32..100_000 {
+        let counts = n.digit_counts();
+        let prev = seen.entry(n.digit_signature()).or_insert(counts);
+        assert_eq!(*prev, counts, "collision at {}", n);
+    }
+    // 同じ数字が 38 個並んでも 6 ビットに収まり、隣の数字の欄に溢れない
+    let nines = u128::from_digits(&[9; 38]);
+    assert_eq!(nines.digit_signature(), 38);
+    assert_ne!(nines.digit_signature(), (nines / 10).digit_signature());
+    assert_eq!(i8::MIN.digit_signature(), 812u16.digit_signature());
+    assert_eq!(
+        i64::MIN.digit_signature(),
+        i64::MIN.unsigned_abs().digit_signature()
+    );
+}
+
+#[test]
+fn test_digits_iter() {
+    assert!(12345u32.digits_iter().eq([1, 2, 3, 4, 5]));
+    assert!(0u8.digits_iter().eq([0]));
+    assert!(1000u16.digits_iter().eq([1, 0, 0, 0]));
+    assert!((-120i32).digits_iter().eq([1, 2, 0]));
+    assert!(i8::MIN.digits_iter().eq([1, 2, 8]));
+    assert!(i8::MIN.digits_iter_radix(2).eq([1, 0, 0, 0, 0, 0, 0, 0]));
+    assert!(0xbeefu32.digits_iter_radix(16).eq([0xb, 0xe, 0xe, 0xf]));
+    // 基数が型に収まらない場合は1桁
+    assert!(200u8.digits_iter_radix(256).eq([200]));
+    // 途中で打ち切れる
+    assert_eq!(190u32.digits_iter().position(|d| d == 9), Some(1));
+
+    for n in (0u64..2000).chain([u64::MAX, u64::MAX - 1, 10u64.pow(19)]) {
+        assert_eq!(n.digits_iter().collect::<Vec<_>>(), n.digits());
+        assert_eq!(n.digits_iter().map(u64::from).sum::<u64>(), n.digit_sum());
+        for base in [2, 3, 7, 16, 36] {
+            assert_eq!(
+                n.digits_iter_radix(base).collect::<Vec<_>>(),
+                n.digits_radix(base)
+            );
+        }
+    }
+    assert!(u128::MAX.digits_iter().eq(u128::MAX.digits()));
+    assert!(i128::MIN.digits_iter().eq(i128::MIN.digits()));
+}